@@ -9,10 +9,11 @@ use raft::eraftpb::{ConfChange, ConfChangeType};
 
 use crate::proto::indexpb_grpc::IndexClient;
 use crate::proto::indexrpcpb::{
-    ApplyReq, CommitReq, CommitResp, ConfChangeReq, DeleteReq, DeleteResp, GetReq, GetResp,
-    MergeReq, MergeResp, MetricsReq, MetricsResp, PeersReq, PeersResp, ProbeReq, ProbeResp, PutReq,
-    PutResp, RaftDone, ReqType, RespErr, RollbackReq, RollbackResp, SchemaReq, SchemaResp,
-    SearchReq, SearchResp,
+    ApplyReq, CommitReq, CommitResp, ConfChangeReq, DeleteByQueryReq, DeleteByQueryResp, DeleteReq,
+    DeleteResp, GetReq, GetResp, MergeReq, MergeResp, MetricsReq, MetricsResp, MultiGetReq,
+    MultiGetResp, PeersReq, PeersResp, ProbeReq, ProbeResp, PutReq, PutResp, RaftDone, ReqType,
+    RespErr, RollbackReq, RollbackResp, SchemaReq, SchemaResp, SearchReq, SearchResp, SuggestReq,
+    SuggestResp, UpdateReq, UpdateResp,
 };
 
 pub fn create_client(addr: &str) -> IndexClient {
@@ -64,7 +65,7 @@ impl Clerk {
             match reply.err {
                 RespErr::OK => return,
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return,
+                _ => return,
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
@@ -91,7 +92,7 @@ impl Clerk {
             match reply.err {
                 RespErr::OK => return,
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return,
+                _ => return,
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
@@ -115,7 +116,7 @@ impl Clerk {
             match reply.err {
                 RespErr::OK => return reply.value,
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return String::from(""),
+                _ => return String::from(""),
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
@@ -139,7 +140,7 @@ impl Clerk {
             match reply.err {
                 RespErr::OK => return reply.value,
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return String::from(""),
+                _ => return String::from(""),
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
@@ -163,14 +164,14 @@ impl Clerk {
             match reply.err {
                 RespErr::OK => return reply.value,
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return String::from(""),
+                _ => return String::from(""),
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
         }
     }
 
-    pub fn get(&mut self, doc_id: &str) -> String {
+    pub fn get(&mut self, doc_id: &str) -> Result<String, (RespErr, String)> {
         let mut req = GetReq::new();
         req.set_client_id(self.client_id);
         req.set_seq(self.request_seq);
@@ -184,21 +185,56 @@ impl Clerk {
                 resp
             });
             match reply.err {
-                RespErr::OK => return reply.value,
+                RespErr::OK => return Ok(reply.value),
+                RespErr::ErrWrongLeader => (),
+                _ => return Err((reply.err, reply.value)),
+            }
+            self.leader_id = (self.leader_id + 1) % self.servers.len();
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    pub fn multi_get(&mut self, doc_ids: Vec<String>) -> Result<String, (RespErr, String)> {
+        let mut req = MultiGetReq::new();
+        req.set_client_id(self.client_id);
+        req.set_seq(self.request_seq);
+        req.set_doc_ids(RepeatedField::from_vec(doc_ids));
+        self.request_seq += 1;
+
+        loop {
+            let reply = self.servers[self.leader_id]
+                .multi_get(&req)
+                .unwrap_or_else(|_e| {
+                    let mut resp = MultiGetResp::new();
+                    resp.set_err(RespErr::ErrWrongLeader);
+                    resp
+                });
+            match reply.err {
+                RespErr::OK => return Ok(reply.value),
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return String::from(""),
+                _ => return Err((reply.err, reply.value)),
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
         }
     }
 
-    pub fn put(&mut self, doc_id: &str, fields: &str) -> String {
+    pub fn put(
+        &mut self,
+        doc_id: &str,
+        fields: &str,
+        version: u64,
+        if_version: u64,
+        if_absent: bool,
+    ) -> Result<String, (RespErr, String)> {
         let mut put_req = PutReq::new();
         put_req.set_client_id(self.client_id);
         put_req.set_seq(self.request_seq);
         put_req.set_doc_id(doc_id.to_owned());
         put_req.set_fields(fields.to_owned());
+        put_req.set_version(version);
+        put_req.set_if_version(if_version);
+        put_req.set_if_absent(if_absent);
 
         let mut req = ApplyReq::new();
         req.set_client_id(self.client_id);
@@ -214,9 +250,9 @@ impl Clerk {
                 resp
             });
             match reply.err {
-                RespErr::OK => return reply.value,
+                RespErr::OK => return Ok(reply.value),
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return String::from(""),
+                _ => return Err((reply.err, reply.value)),
             }
             debug!("put redo: {}", self.leader_id);
             self.leader_id = (self.leader_id + 1) % self.servers.len();
@@ -224,11 +260,52 @@ impl Clerk {
         }
     }
 
-    pub fn delete(&mut self, doc_id: &str) -> String {
+    pub fn update(
+        &mut self,
+        doc_id: &str,
+        set: &str,
+        append: &str,
+        remove: &str,
+    ) -> Result<String, (RespErr, String)> {
+        let mut update_req = UpdateReq::new();
+        update_req.set_client_id(self.client_id);
+        update_req.set_seq(self.request_seq);
+        update_req.set_doc_id(doc_id.to_owned());
+        update_req.set_set(set.to_owned());
+        update_req.set_append(append.to_owned());
+        update_req.set_remove(remove.to_owned());
+
+        let mut req = ApplyReq::new();
+        req.set_client_id(self.client_id);
+        req.set_req_type(ReqType::Update);
+        req.set_update_req(update_req);
+
+        self.request_seq += 1;
+
+        loop {
+            let reply = self.servers[self.leader_id]
+                .update(&req)
+                .unwrap_or_else(|_e| {
+                    let mut resp = UpdateResp::new();
+                    resp.set_err(RespErr::ErrWrongLeader);
+                    resp
+                });
+            match reply.err {
+                RespErr::OK => return Ok(reply.value),
+                RespErr::ErrWrongLeader => (),
+                _ => return Err((reply.err, reply.value)),
+            }
+            self.leader_id = (self.leader_id + 1) % self.servers.len();
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    pub fn delete(&mut self, doc_id: &str, if_version: u64) -> Result<String, (RespErr, String)> {
         let mut delete_req = DeleteReq::new();
         delete_req.set_client_id(self.client_id);
         delete_req.set_seq(self.request_seq);
         delete_req.set_doc_id(doc_id.to_owned());
+        delete_req.set_if_version(if_version);
 
         let mut req = ApplyReq::new();
         req.set_client_id(self.client_id);
@@ -246,9 +323,40 @@ impl Clerk {
                     resp
                 });
             match reply.err {
-                RespErr::OK => return reply.value,
+                RespErr::OK => return Ok(reply.value),
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return reply.value,
+                _ => return Err((reply.err, reply.value)),
+            }
+            self.leader_id = (self.leader_id + 1) % self.servers.len();
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    pub fn delete_by_query(&mut self, query: &str) -> Result<String, (RespErr, String)> {
+        let mut delete_by_query_req = DeleteByQueryReq::new();
+        delete_by_query_req.set_client_id(self.client_id);
+        delete_by_query_req.set_seq(self.request_seq);
+        delete_by_query_req.set_query(query.to_owned());
+
+        let mut req = ApplyReq::new();
+        req.set_client_id(self.client_id);
+        req.set_req_type(ReqType::DeleteByQuery);
+        req.set_delete_by_query_req(delete_by_query_req);
+
+        self.request_seq += 1;
+
+        loop {
+            let reply = self.servers[self.leader_id]
+                .delete_by_query(&req)
+                .unwrap_or_else(|_e| {
+                    let mut resp = DeleteByQueryResp::new();
+                    resp.set_err(RespErr::ErrWrongLeader);
+                    resp
+                });
+            match reply.err {
+                RespErr::OK => return Ok(reply.value),
+                RespErr::ErrWrongLeader => (),
+                _ => return Err((reply.err, reply.value)),
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
@@ -278,7 +386,7 @@ impl Clerk {
             match reply.err {
                 RespErr::OK => return reply.value,
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return reply.value,
+                _ => return reply.value,
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
@@ -308,7 +416,7 @@ impl Clerk {
             match reply.err {
                 RespErr::OK => return reply.value,
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return reply.value,
+                _ => return reply.value,
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
@@ -338,49 +446,64 @@ impl Clerk {
             match reply.err {
                 RespErr::OK => return reply.value,
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return reply.value,
+                _ => return reply.value,
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
         }
     }
 
-    pub fn search(
+    pub fn search(&mut self, mut req: SearchReq) -> Result<String, (RespErr, String)> {
+        req.set_client_id(self.client_id);
+        req.set_seq(self.request_seq);
+        self.request_seq += 1;
+
+        loop {
+            let reply = self.servers[self.leader_id]
+                .search(&req)
+                .unwrap_or_else(|_e| {
+                    let mut resp = SearchResp::new();
+                    resp.set_err(RespErr::ErrWrongLeader);
+                    resp
+                });
+            match reply.err {
+                RespErr::OK => return Ok(reply.value),
+                RespErr::ErrWrongLeader => (),
+                _ => return Err((reply.err, reply.value)),
+            }
+            self.leader_id = (self.leader_id + 1) % self.servers.len();
+            thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    pub fn suggest(
         &mut self,
-        query: &str,
-        from: u64,
+        field: &str,
+        prefix: &str,
         limit: u64,
-        exclude_count: bool,
-        exclude_docs: bool,
-        facet_field: &str,
-        facet_prefixes: Vec<String>,
-//        facets: Vec<String>,
-    ) -> String {
-        let mut req = SearchReq::new();
+        weight_field: &str,
+    ) -> Result<String, (RespErr, String)> {
+        let mut req = SuggestReq::new();
         req.set_client_id(self.client_id);
         req.set_seq(self.request_seq);
-        req.set_query(query.to_owned());
-        req.set_from(from);
+        req.set_field(field.to_string());
+        req.set_prefix(prefix.to_string());
         req.set_limit(limit);
-        req.set_exclude_count(exclude_count);
-        req.set_exclude_docs(exclude_docs);
-        req.set_facet_field(facet_field.to_string());
-        req.set_facet_prefixes(RepeatedField::from_vec(facet_prefixes));
-//        req.set_facets(RepeatedField::from_vec(facets));
+        req.set_weight_field(weight_field.to_string());
         self.request_seq += 1;
 
         loop {
             let reply = self.servers[self.leader_id]
-                .search(&req)
+                .suggest(&req)
                 .unwrap_or_else(|_e| {
-                    let mut resp = SearchResp::new();
+                    let mut resp = SuggestResp::new();
                     resp.set_err(RespErr::ErrWrongLeader);
                     resp
                 });
             match reply.err {
-                RespErr::OK => return reply.value,
+                RespErr::OK => return Ok(reply.value),
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return String::from(""),
+                _ => return Err((reply.err, reply.value)),
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
@@ -404,7 +527,7 @@ impl Clerk {
             match reply.err {
                 RespErr::OK => return reply.value,
                 RespErr::ErrWrongLeader => (),
-                RespErr::ErrNoKey => return String::from(""),
+                _ => return String::from(""),
             }
             self.leader_id = (self.leader_id + 1) % self.servers.len();
             thread::sleep(Duration::from_millis(100));
@@ -1,5 +1,6 @@
 pub mod commit;
 pub mod delete;
+pub mod delete_by_query;
 pub mod gateway;
 pub mod get;
 pub mod leave;
@@ -13,3 +14,5 @@ pub mod schedule;
 pub mod schema;
 pub mod search;
 pub mod serve;
+pub mod suggest;
+pub mod update;
@@ -12,12 +12,20 @@ pub fn run_delete_cli(matches: &ArgMatches) -> Result<(), String> {
         .map(|addr| create_client(addr))
         .collect();
     let doc_id = matches.value_of("DOC_ID").unwrap();
+    let if_version = matches
+        .value_of("IF_VERSION")
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
 
     let client_id = rand::random();
 
     let mut client = Clerk::new(&servers, client_id);
-    let value = client.delete(doc_id);
-    print!("{}", value);
-
-    Ok(())
+    match client.delete(doc_id, if_version) {
+        Ok(value) => {
+            print!("{}", value);
+            Ok(())
+        }
+        Err((_, value)) => Err(value),
+    }
 }
@@ -0,0 +1,26 @@
+use clap::ArgMatches;
+
+use crate::client::client::{create_client, Clerk};
+use crate::util::log::set_logger;
+
+pub fn run_delete_by_query_cli(matches: &ArgMatches) -> Result<(), String> {
+    set_logger();
+
+    let servers: Vec<_> = matches
+        .values_of("SERVERS")
+        .unwrap()
+        .map(|addr| create_client(addr))
+        .collect();
+    let query = matches.value_of("QUERY").unwrap();
+
+    let client_id = rand::random();
+
+    let mut client = Clerk::new(&servers, client_id);
+    match client.delete_by_query(query) {
+        Ok(value) => {
+            print!("{}", value);
+            Ok(())
+        }
+        Err((_, value)) => Err(value),
+    }
+}
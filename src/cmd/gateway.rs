@@ -1,16 +1,22 @@
+use std::collections::HashMap;
 use std::io::Read;
 
 use clap::ArgMatches;
 use iron::{Chain, Iron, IronResult, Request, Response, status};
 use iron::headers::ContentType;
+use iron::method::Method;
 use iron::prelude::*;
 use iron::typemap::Key;
 use logger::Logger;
 use persistent::Write;
+use protobuf::RepeatedField;
 use router::Router;
+use serde::Deserialize;
+use serde_json::{Map, Value};
 use urlencoded::UrlEncodedQuery;
 
 use crate::client::client::{Clerk, create_client};
+use crate::proto::indexrpcpb::{RespErr, SearchReq};
 use crate::util::log::set_http_logger;
 
 #[derive(Copy, Clone)]
@@ -20,6 +26,49 @@ impl Key for Client {
     type Value = Clerk;
 }
 
+fn error_response(err: RespErr, value: String) -> Response {
+    let status = match err {
+        RespErr::ErrNoKey => status::NotFound,
+        RespErr::ErrConflict => status::Conflict,
+        RespErr::ErrInvalidQuery | RespErr::ErrInvalidDocument | RespErr::ErrUnknownField => {
+            status::BadRequest
+        }
+        RespErr::ErrSchemaMismatch => status::UnprocessableEntity,
+        _ => status::InternalServerError,
+    };
+
+    Response::with((ContentType::json().0, status, value))
+}
+
+fn bad_request(message: String) -> Response {
+    let mut ret = HashMap::new();
+    ret.insert("error", message);
+
+    Response::with((
+        ContentType::json().0,
+        status::BadRequest,
+        serde_json::to_string(&ret).unwrap(),
+    ))
+}
+
+fn query_value(req: &mut Request, name: &str) -> Option<String> {
+    req.get_ref::<UrlEncodedQuery>()
+        .ok()
+        .and_then(|map| map.get(name))
+        .and_then(|values| values.get(0))
+        .cloned()
+}
+
+fn query_values(req: &mut Request, name: &str) -> RepeatedField<String> {
+    RepeatedField::from_vec(
+        req.get_ref::<UrlEncodedQuery>()
+            .ok()
+            .and_then(|map| map.get(name))
+            .cloned()
+            .unwrap_or_default(),
+    )
+}
+
 fn probe(req: &mut Request) -> IronResult<Response> {
     let client_arc = req.get::<Write<Client>>().unwrap();
     let mut client = client_arc.lock().unwrap();
@@ -63,9 +112,33 @@ fn get(req: &mut Request) -> IronResult<Response> {
 
     let client_arc = req.get::<Write<Client>>().unwrap();
     let mut client = client_arc.lock().unwrap();
-    let value = client.get(&doc_id);
+    match client.get(&doc_id) {
+        Ok(value) => Ok(Response::with((ContentType::json().0, status::Ok, value))),
+        Err((err, value)) => Ok(error_response(err, value)),
+    }
+}
 
-    Ok(Response::with((ContentType::json().0, status::Ok, value)))
+#[derive(Deserialize)]
+struct MultiGetBody {
+    ids: Vec<String>,
+}
+
+fn multi_get(req: &mut Request) -> IronResult<Response> {
+    let mut body = String::new();
+    req.body
+        .read_to_string(&mut body)
+        .expect("Failed to read line");
+    let doc_ids = match serde_json::from_str::<MultiGetBody>(&body) {
+        Ok(multi_get_body) => multi_get_body.ids,
+        Err(e) => return Ok(bad_request(format!("invalid request body: {}", e))),
+    };
+
+    let client_arc = req.get::<Write<Client>>().unwrap();
+    let mut client = client_arc.lock().unwrap();
+    match client.multi_get(doc_ids) {
+        Ok(value) => Ok(Response::with((ContentType::json().0, status::Ok, value))),
+        Err((err, value)) => Ok(error_response(err, value)),
+    }
 }
 
 fn put(req: &mut Request) -> IronResult<Response> {
@@ -80,12 +153,67 @@ fn put(req: &mut Request) -> IronResult<Response> {
     req.body
         .read_to_string(&mut body)
         .expect("Failed to read line");
+    let version = match query_value(req, "version").map(|v| v.parse::<u64>()) {
+        Some(Ok(version)) => version,
+        Some(Err(e)) => return Ok(bad_request(format!("invalid version: {}", e))),
+        None => 0,
+    };
+    let if_version = match query_value(req, "if_version").map(|v| v.parse::<u64>()) {
+        Some(Ok(if_version)) => if_version,
+        Some(Err(e)) => return Ok(bad_request(format!("invalid if_version: {}", e))),
+        None => 0,
+    };
+    let if_absent = req
+        .get_ref::<UrlEncodedQuery>()
+        .map(|map| map.contains_key("if_absent"))
+        .unwrap_or(false);
 
     let client_arc = req.get::<Write<Client>>().unwrap();
     let mut client = client_arc.lock().unwrap();
-    let value = client.put(&doc_id, &body);
+    match client.put(&doc_id, &body, version, if_version, if_absent) {
+        Ok(value) => Ok(Response::with((ContentType::json().0, status::Ok, value))),
+        Err((err, value)) => Ok(error_response(err, value)),
+    }
+}
 
-    Ok(Response::with((ContentType::json().0, status::Ok, value)))
+#[derive(Deserialize)]
+struct UpdateBody {
+    set: Option<Map<String, Value>>,
+    append: Option<Map<String, Value>>,
+    remove: Option<Map<String, Value>>,
+}
+
+fn update(req: &mut Request) -> IronResult<Response> {
+    let doc_id = req
+        .extensions
+        .get::<Router>()
+        .unwrap()
+        .find("doc_id")
+        .unwrap_or("")
+        .to_owned();
+    let mut body = String::new();
+    req.body
+        .read_to_string(&mut body)
+        .expect("Failed to read line");
+    let update_body = match serde_json::from_str::<UpdateBody>(&body) {
+        Ok(update_body) => update_body,
+        Err(e) => return Ok(bad_request(format!("invalid request body: {}", e))),
+    };
+    let to_string = |fields: Option<Map<String, Value>>| {
+        fields
+            .map(|f| serde_json::to_string(&f).unwrap())
+            .unwrap_or_default()
+    };
+    let set = to_string(update_body.set);
+    let append = to_string(update_body.append);
+    let remove = to_string(update_body.remove);
+
+    let client_arc = req.get::<Write<Client>>().unwrap();
+    let mut client = client_arc.lock().unwrap();
+    match client.update(&doc_id, &set, &append, &remove) {
+        Ok(value) => Ok(Response::with((ContentType::json().0, status::Ok, value))),
+        Err((err, value)) => Ok(error_response(err, value)),
+    }
 }
 
 fn delete(req: &mut Request) -> IronResult<Response> {
@@ -96,12 +224,41 @@ fn delete(req: &mut Request) -> IronResult<Response> {
         .find("doc_id")
         .unwrap_or("")
         .to_owned();
+    let if_version = match query_value(req, "if_version").map(|v| v.parse::<u64>()) {
+        Some(Ok(if_version)) => if_version,
+        Some(Err(e)) => return Ok(bad_request(format!("invalid if_version: {}", e))),
+        None => 0,
+    };
 
     let client_arc = req.get::<Write<Client>>().unwrap();
     let mut client = client_arc.lock().unwrap();
-    let value = client.delete(&doc_id);
+    match client.delete(&doc_id, if_version) {
+        Ok(value) => Ok(Response::with((ContentType::json().0, status::Ok, value))),
+        Err((err, value)) => Ok(error_response(err, value)),
+    }
+}
 
-    Ok(Response::with((ContentType::json().0, status::Ok, value)))
+#[derive(Deserialize)]
+struct DeleteByQueryBody {
+    query: String,
+}
+
+fn delete_by_query(req: &mut Request) -> IronResult<Response> {
+    let mut body = String::new();
+    req.body
+        .read_to_string(&mut body)
+        .expect("Failed to read line");
+    let query = match serde_json::from_str::<DeleteByQueryBody>(&body) {
+        Ok(delete_by_query_body) => delete_by_query_body.query,
+        Err(e) => return Ok(bad_request(format!("invalid request body: {}", e))),
+    };
+
+    let client_arc = req.get::<Write<Client>>().unwrap();
+    let mut client = client_arc.lock().unwrap();
+    match client.delete_by_query(&query) {
+        Ok(value) => Ok(Response::with((ContentType::json().0, status::Ok, value))),
+        Err((err, value)) => Ok(error_response(err, value)),
+    }
 }
 
 fn commit(req: &mut Request) -> IronResult<Response> {
@@ -128,9 +285,37 @@ fn merge(req: &mut Request) -> IronResult<Response> {
     Ok(Response::with((ContentType::json().0, status::Ok, value)))
 }
 
-fn search(req: &mut Request) -> IronResult<Response> {
-    let map = req.get_ref::<UrlEncodedQuery>().unwrap().to_owned();
-    let query = map.get("query").unwrap().get(0).unwrap();
+#[derive(Deserialize)]
+struct SearchBody {
+    query: Value,
+}
+
+// Builds the search request from the parameters and the body. Returns the response to send if
+// they are invalid.
+fn search_request(req: &mut Request) -> Result<SearchReq, Response> {
+    // the query DSL is given in the body of POST requests
+    let mut query_dsl = String::new();
+    if req.method == Method::Post {
+        let mut body = String::new();
+        req.body
+            .read_to_string(&mut body)
+            .expect("Failed to read line");
+        query_dsl = match serde_json::from_str::<SearchBody>(&body) {
+            Ok(search_body) => search_body.query.to_string(),
+            Err(e) => return Err(bad_request(format!("invalid request body: {}", e))),
+        };
+    }
+
+    // POST requests may have no query string at all
+    let map = req
+        .get_ref::<UrlEncodedQuery>()
+        .ok()
+        .cloned()
+        .unwrap_or_default();
+    let mut query: &str = "";
+    if map.contains_key("query") {
+        query = map.get("query").unwrap().get(0).unwrap();
+    }
 
     let mut from = 0;
     if map.contains_key("from") {
@@ -162,6 +347,16 @@ fn search(req: &mut Request) -> IronResult<Response> {
 //            .parse::<bool>()
 //            .unwrap();
 //    }
+    let timeout = match query_value(req, "timeout").map(|v| v.parse::<u64>()) {
+        Some(Ok(timeout)) => timeout,
+        Some(Err(e)) => return Err(bad_request(format!("invalid timeout: {}", e))),
+        None => 0,
+    };
+    let terminate_after = match query_value(req, "terminate_after").map(|v| v.parse::<u64>()) {
+        Some(Ok(terminate_after)) => terminate_after,
+        Some(Err(e)) => return Err(bad_request(format!("invalid terminate_after: {}", e))),
+        None => 0,
+    };
     let exclude_docs = map.contains_key("exclude_docs");
 //    if map.contains_key("exclude_docs") {
 //        exclude_docs = map
@@ -172,26 +367,82 @@ fn search(req: &mut Request) -> IronResult<Response> {
 //            .parse::<bool>()
 //            .unwrap();
 //    }
-    let mut facet_field: &str = "";
-    if map.contains_key("facet_field") {
-        facet_field = map
-            .get("facet_field")
-            .unwrap()
-            .get(0)
-            .unwrap();
-    }
-    let mut facet_prefixes = Vec::new();
-    if map.contains_key("facet_prefix") {
-        facet_prefixes = map
-            .get("facet_prefix").cloned().unwrap();
-    }
 
+    let mut search_req = SearchReq::new();
+    search_req.set_query(query.to_string());
+    search_req.set_from(from);
+    search_req.set_limit(limit);
+    search_req.set_exclude_count(exclude_count);
+    search_req.set_exclude_docs(exclude_docs);
+    search_req.set_facet_field(query_value(req, "facet_field").unwrap_or_default());
+    search_req.set_facet_prefixes(query_values(req, "facet_prefix"));
+    search_req.set_facets(query_values(req, "facet"));
+    search_req.set_facet_limits(query_values(req, "facet_limit"));
+    search_req.set_facet_sorts(query_values(req, "facet_sort"));
+    search_req.set_post_filter(query_value(req, "post_filter").unwrap_or_default());
+    search_req.set_more_like_this_doc_id(
+        query_value(req, "more_like_this_doc_id").unwrap_or_default(),
+    );
+    search_req.set_more_like_this_text(
+        query_value(req, "more_like_this_text").unwrap_or_default(),
+    );
+    search_req.set_did_you_mean(map.contains_key("did_you_mean"));
+    search_req.set_geo_field(query_value(req, "geo_field").unwrap_or_default());
+    search_req.set_geo_origin(query_value(req, "geo_origin").unwrap_or_default());
+    search_req.set_geo_distance(query_value(req, "geo_distance").unwrap_or_default());
+    search_req.set_geo_bounding_box(query_value(req, "geo_bounding_box").unwrap_or_default());
+    search_req.set_geo_sort(map.contains_key("geo_sort"));
+    search_req.set_date_field(query_value(req, "date_field").unwrap_or_default());
+    search_req.set_date_from(query_value(req, "date_from").unwrap_or_default());
+    search_req.set_date_to(query_value(req, "date_to").unwrap_or_default());
+    search_req.set_time_zone(query_value(req, "time_zone").unwrap_or_default());
+    search_req.set_function_score(query_value(req, "function_score").unwrap_or_default());
+    search_req.set_collapse_field(query_value(req, "collapse_field").unwrap_or_default());
+    search_req.set_timeout(timeout);
+    search_req.set_terminate_after(terminate_after);
+    search_req.set_sort(query_values(req, "sort"));
+    search_req.set_aggregations(query_value(req, "aggregations").unwrap_or_default());
+    search_req.set_highlight(query_value(req, "highlight").unwrap_or_default());
+    search_req.set_query_dsl(query_dsl);
+    search_req.set_typo_tolerance(map.contains_key("typo_tolerance"));
+    search_req.set_default_fields(query_value(req, "default_fields").unwrap_or_default());
+
+    Ok(search_req)
+}
+
+fn search(req: &mut Request) -> IronResult<Response> {
+    let search_req = match search_request(req) {
+        Ok(search_req) => search_req,
+        Err(response) => return Ok(response),
+    };
 
     let client_arc = req.get::<Write<Client>>().unwrap();
     let mut client = client_arc.lock().unwrap();
-    let value = client.search(query, from, limit, exclude_count, exclude_docs, facet_field, facet_prefixes);
+    match client.search(search_req) {
+        Ok(value) => Ok(Response::with((ContentType::json().0, status::Ok, value))),
+        Err((err, value)) => Ok(error_response(err, value)),
+    }
+}
 
-    Ok(Response::with((ContentType::json().0, status::Ok, value)))
+fn suggest(req: &mut Request) -> IronResult<Response> {
+    let field = match query_value(req, "field") {
+        Some(field) => field,
+        None => return Ok(bad_request(String::from("field is required"))),
+    };
+    let prefix = query_value(req, "prefix").unwrap_or_default();
+    let limit = match query_value(req, "limit").map(|v| v.parse::<u64>()) {
+        Some(Ok(limit)) => limit,
+        Some(Err(e)) => return Ok(bad_request(format!("invalid limit: {}", e))),
+        None => 10,
+    };
+    let weight_field = query_value(req, "weight_field").unwrap_or_default();
+
+    let client_arc = req.get::<Write<Client>>().unwrap();
+    let mut client = client_arc.lock().unwrap();
+    match client.suggest(&field, &prefix, limit, &weight_field) {
+        Ok(value) => Ok(Response::with((ContentType::json().0, status::Ok, value))),
+        Err((err, value)) => Ok(error_response(err, value)),
+    }
 }
 
 fn schema(req: &mut Request) -> IronResult<Response> {
@@ -226,9 +477,14 @@ pub fn run_gateway_cli(matches: &ArgMatches) -> Result<(), String> {
     router.get("/peers", peers, "peers");
     router.get("/metrics", metrics, "metrics");
     router.get("/index/docs/:doc_id", get, "get");
+    router.post("/index/_mget", multi_get, "multi_get");
     router.put("/index/docs/:doc_id", put, "put");
+    router.patch("/index/docs/:doc_id", update, "update");
     router.delete("/index/docs/:doc_id", delete, "delete");
+    router.post("/index/_delete_by_query", delete_by_query, "delete_by_query");
     router.get("/index/search", search, "search");
+    router.post("/index/search", search, "search_dsl");
+    router.get("/index/suggest", suggest, "suggest");
     router.get("/index/commit", commit, "commit");
     router.get("/index/rollback", rollback, "rollback");
     router.get("/index/merge", merge, "merge");
@@ -243,3 +499,82 @@ pub fn run_gateway_cli(matches: &ArgMatches) -> Result<(), String> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use iron::Headers;
+    use iron_test::{request, response};
+
+    use super::*;
+
+    // Answers with the parts of the search request that the tests check.
+    fn search_request_handler(req: &mut Request) -> IronResult<Response> {
+        match search_request(req) {
+            Ok(search_req) => Ok(Response::with((
+                status::Ok,
+                format!(
+                    "{} {} {} {}",
+                    search_req.get_query(),
+                    search_req.get_from(),
+                    search_req.get_limit(),
+                    search_req.get_query_dsl()
+                ),
+            ))),
+            Err(response) => Ok(response),
+        }
+    }
+
+    #[test]
+    fn test_search_request_with_body_only() {
+        let res = request::post(
+            "http://localhost:8000/index/search",
+            Headers::new(),
+            r#"{"query": {"term": {"field": "title", "value": "rust"}}}"#,
+            &search_request_handler,
+        )
+        .unwrap();
+        assert_eq!(res.status, Some(status::Ok));
+        assert_eq!(
+            response::extract_body_to_string(res),
+            r#" 0 10 {"term":{"field":"title","value":"rust"}}"#
+        );
+    }
+
+    #[test]
+    fn test_search_request_with_body_and_query_string() {
+        let res = request::post(
+            "http://localhost:8000/index/search?from=5&limit=20",
+            Headers::new(),
+            r#"{"query": {"match_all": {}}}"#,
+            &search_request_handler,
+        )
+        .unwrap();
+        assert_eq!(
+            response::extract_body_to_string(res),
+            r#" 5 20 {"match_all":{}}"#
+        );
+    }
+
+    #[test]
+    fn test_search_request_with_query_string_only() {
+        let res = request::get(
+            "http://localhost:8000/index/search?query=rust",
+            Headers::new(),
+            &search_request_handler,
+        )
+        .unwrap();
+        assert_eq!(response::extract_body_to_string(res), "rust 0 10 ");
+    }
+
+    #[test]
+    fn test_search_request_with_invalid_body() {
+        let res = request::post(
+            "http://localhost:8000/index/search",
+            Headers::new(),
+            "{",
+            &search_request_handler,
+        )
+        .unwrap();
+        assert_eq!(res.status, Some(status::BadRequest));
+    }
+}
@@ -16,8 +16,11 @@ pub fn run_get_cli(matches: &ArgMatches) -> Result<(), String> {
     let client_id = rand::random();
 
     let mut client = Clerk::new(&servers, client_id);
-    let value = client.get(doc_id);
-    print!("{}", value);
-
-    Ok(())
+    match client.get(doc_id) {
+        Ok(value) => {
+            print!("{}", value);
+            Ok(())
+        }
+        Err((_, value)) => Err(value),
+    }
 }
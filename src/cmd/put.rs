@@ -13,12 +13,26 @@ pub fn run_put_cli(matches: &ArgMatches) -> Result<(), String> {
         .collect();
     let doc_id = matches.value_of("DOC_ID").unwrap();
     let fields = matches.value_of("FIELDS").unwrap();
+    let version = matches
+        .value_of("EXTERNAL_VERSION")
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+    let if_version = matches
+        .value_of("IF_VERSION")
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+    let if_absent = matches.is_present("IF_ABSENT");
 
     let client_id = rand::random();
 
     let mut client = Clerk::new(&servers, client_id);
-    let value = client.put(doc_id, fields);
-    print!("{}", value);
-
-    Ok(())
+    match client.put(doc_id, fields, version, if_version, if_absent) {
+        Ok(value) => {
+            print!("{}", value);
+            Ok(())
+        }
+        Err((_, value)) => Err(value),
+    }
 }
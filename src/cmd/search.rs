@@ -1,8 +1,19 @@
 use clap::ArgMatches;
+use protobuf::RepeatedField;
 
 use crate::client::client::{Clerk, create_client};
+use crate::proto::indexrpcpb::SearchReq;
 use crate::util::log::set_logger;
 
+fn values(matches: &ArgMatches, name: &str) -> RepeatedField<String> {
+    RepeatedField::from_vec(
+        matches
+            .values_of(name)
+            .map(|values| values.map(|s| s.to_string()).collect())
+            .unwrap_or_default(),
+    )
+}
+
 pub fn run_search_cli(matches: &ArgMatches) -> Result<(), String> {
     set_logger();
 
@@ -11,34 +22,66 @@ pub fn run_search_cli(matches: &ArgMatches) -> Result<(), String> {
         .unwrap()
         .map(|addr| create_client(addr))
         .collect();
-    let from = matches.value_of("FROM").unwrap().parse::<u64>().unwrap();
-    let limit = matches.value_of("LIMIT").unwrap().parse::<u64>().unwrap();
-    let exclude_count = matches.is_present("EXCLUDE_COUNT");
-    let exclude_docs = matches.is_present("EXCLUDE_DOCS");
-    let facet_field = matches.value_of("FACET_FIELD").unwrap();
-    let mut facet_prefixes: Vec<String> = Vec::new();
-    if let Some(_facet_prefixes) = matches.values_of("FACET_PREFIX") {
-        _facet_prefixes.map(|s| {
-            facet_prefixes.push(s.to_string())
-        }
-        ).count();
-    }
-//    let mut facets: Vec<String> = Vec::new();
-//    if let Some(_facets) = matches.values_of("FACET") {
-//        _facets.map(|s| {
-//            facets.push(s.to_string())
-//        }
-//        ).count();
-//    }
 
-    let query = matches.value_of("QUERY").unwrap();
+    let mut req = SearchReq::new();
+    req.set_query(matches.value_of("QUERY").unwrap_or("").to_string());
+    req.set_from(matches.value_of("FROM").unwrap().parse::<u64>().unwrap());
+    req.set_limit(matches.value_of("LIMIT").unwrap().parse::<u64>().unwrap());
+    req.set_exclude_count(matches.is_present("EXCLUDE_COUNT"));
+    req.set_exclude_docs(matches.is_present("EXCLUDE_DOCS"));
+    req.set_facet_field(matches.value_of("FACET_FIELD").unwrap().to_string());
+    req.set_facet_prefixes(values(matches, "FACET_PREFIX"));
+    req.set_facets(values(matches, "FACET"));
+    req.set_facet_limits(values(matches, "FACET_LIMIT"));
+    req.set_facet_sorts(values(matches, "FACET_SORT"));
+    req.set_post_filter(matches.value_of("POST_FILTER").unwrap().to_string());
+    req.set_more_like_this_doc_id(
+        matches
+            .value_of("MORE_LIKE_THIS_DOC_ID")
+            .unwrap_or("")
+            .to_string(),
+    );
+    req.set_more_like_this_text(
+        matches
+            .value_of("MORE_LIKE_THIS_TEXT")
+            .unwrap_or("")
+            .to_string(),
+    );
+    req.set_did_you_mean(matches.is_present("DID_YOU_MEAN"));
+    req.set_geo_field(matches.value_of("GEO_FIELD").unwrap().to_string());
+    req.set_geo_origin(matches.value_of("GEO_ORIGIN").unwrap().to_string());
+    req.set_geo_distance(matches.value_of("GEO_DISTANCE").unwrap().to_string());
+    req.set_geo_bounding_box(matches.value_of("GEO_BOUNDING_BOX").unwrap().to_string());
+    req.set_geo_sort(matches.is_present("GEO_SORT"));
+    req.set_date_field(matches.value_of("DATE_FIELD").unwrap().to_string());
+    req.set_date_from(matches.value_of("DATE_FROM").unwrap().to_string());
+    req.set_date_to(matches.value_of("DATE_TO").unwrap().to_string());
+    req.set_time_zone(matches.value_of("TIME_ZONE").unwrap().to_string());
+    req.set_function_score(matches.value_of("FUNCTION_SCORE").unwrap().to_string());
+    req.set_collapse_field(matches.value_of("COLLAPSE_FIELD").unwrap().to_string());
+    req.set_timeout(matches.value_of("TIMEOUT").unwrap().parse::<u64>().unwrap());
+    req.set_terminate_after(
+        matches
+            .value_of("TERMINATE_AFTER")
+            .unwrap()
+            .parse::<u64>()
+            .unwrap(),
+    );
+    req.set_sort(values(matches, "SORT"));
+    req.set_aggregations(matches.value_of("AGGREGATIONS").unwrap().to_string());
+    req.set_highlight(matches.value_of("HIGHLIGHT").unwrap().to_string());
+    req.set_query_dsl(matches.value_of("QUERY_DSL").unwrap().to_string());
+    req.set_typo_tolerance(matches.is_present("TYPO_TOLERANCE"));
+    req.set_default_fields(matches.value_of("DEFAULT_FIELDS").unwrap().to_string());
 
     let client_id = rand::random();
 
     let mut client = Clerk::new(&servers, client_id);
-    let value = client.search(query, from, limit, exclude_count, exclude_docs, facet_field, facet_prefixes);
-//    let value = client.search(query, from, limit, exclude_count, exclude_docs, facets);
-    print!("{}", value);
-
-    Ok(())
+    match client.search(req) {
+        Ok(value) => {
+            print!("{}", value);
+            Ok(())
+        }
+        Err((_, value)) => Err(value),
+    }
 }
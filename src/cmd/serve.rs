@@ -25,6 +25,14 @@ pub fn run_serve_cli(matches: &ArgMatches) -> Result<(), String> {
     let data_directory = matches.value_of("DATA_DIRECTORY").unwrap();
     let schema_file = matches.value_of("SCHEMA_FILE").unwrap();
     let unique_key_field_name = matches.value_of("UNIQUE_KEY_FIELD_NAME").unwrap();
+    let analyzer_file = matches.value_of("ANALYZER_FILE").unwrap_or("");
+    let search_timeout = matches.value_of("SEARCH_TIMEOUT").unwrap().parse::<u64>().unwrap();
+    let search_cache_size = matches
+        .value_of("SEARCH_CACHE_SIZE")
+        .unwrap()
+        .parse::<usize>()
+        .unwrap();
+    let default_fields = matches.value_of("DEFAULT_FIELDS").unwrap();
 
     IndexServer::start_server(
         id,
@@ -34,6 +42,10 @@ pub fn run_serve_cli(matches: &ArgMatches) -> Result<(), String> {
         data_directory,
         schema_file,
         unique_key_field_name,
+        analyzer_file,
+        search_timeout,
+        search_cache_size,
+        default_fields,
     );
 
     Ok(())
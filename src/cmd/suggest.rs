@@ -0,0 +1,29 @@
+use clap::ArgMatches;
+
+use crate::client::client::{create_client, Clerk};
+use crate::util::log::set_logger;
+
+pub fn run_suggest_cli(matches: &ArgMatches) -> Result<(), String> {
+    set_logger();
+
+    let servers: Vec<_> = matches
+        .values_of("SERVERS")
+        .unwrap()
+        .map(|addr| create_client(addr))
+        .collect();
+    let field = matches.value_of("FIELD").unwrap();
+    let limit = matches.value_of("LIMIT").unwrap().parse::<u64>().unwrap();
+    let weight_field = matches.value_of("WEIGHT_FIELD").unwrap();
+    let prefix = matches.value_of("PREFIX").unwrap();
+
+    let client_id = rand::random();
+
+    let mut client = Clerk::new(&servers, client_id);
+    match client.suggest(field, prefix, limit, weight_field) {
+        Ok(value) => {
+            print!("{}", value);
+            Ok(())
+        }
+        Err((_, value)) => Err(value),
+    }
+}
@@ -0,0 +1,29 @@
+use clap::ArgMatches;
+
+use crate::client::client::{create_client, Clerk};
+use crate::util::log::set_logger;
+
+pub fn run_update_cli(matches: &ArgMatches) -> Result<(), String> {
+    set_logger();
+
+    let servers: Vec<_> = matches
+        .values_of("SERVERS")
+        .unwrap()
+        .map(|addr| create_client(addr))
+        .collect();
+    let doc_id = matches.value_of("DOC_ID").unwrap();
+    let set = matches.value_of("SET").unwrap_or("");
+    let append = matches.value_of("APPEND").unwrap_or("");
+    let remove = matches.value_of("REMOVE").unwrap_or("");
+
+    let client_id = rand::random();
+
+    let mut client = Clerk::new(&servers, client_id);
+    match client.update(doc_id, set, append, remove) {
+        Ok(value) => {
+            print!("{}", value);
+            Ok(())
+        }
+        Err((_, value)) => Err(value),
+    }
+}
@@ -8,6 +8,7 @@ use clap::{App, AppSettings, Arg, SubCommand};
 
 use bayard::cmd::commit::run_commit_cli;
 use bayard::cmd::delete::run_delete_cli;
+use bayard::cmd::delete_by_query::run_delete_by_query_cli;
 use bayard::cmd::gateway::run_gateway_cli;
 use bayard::cmd::get::run_get_cli;
 use bayard::cmd::leave::run_leave_cli;
@@ -21,6 +22,8 @@ use bayard::cmd::schedule::run_schedule_cli;
 use bayard::cmd::schema::run_schema_cli;
 use bayard::cmd::search::run_search_cli;
 use bayard::cmd::serve::run_serve_cli;
+use bayard::cmd::suggest::run_suggest_cli;
+use bayard::cmd::update::run_update_cli;
 
 fn main() {
     let app = App::new(crate_name!())
@@ -109,6 +112,41 @@ fn main() {
                         .default_value("id")
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("ANALYZER_FILE")
+                        .help("Analyzer file. Defines named analyzers built from a tokenizer and filters, which can be referenced as tokenizers in the schema. If not specified, the analyzers last copied into the index directory are used, if any.")
+                        .short("a")
+                        .long("analyzer-file")
+                        .value_name("ANALYZER_FILE")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("SEARCH_TIMEOUT")
+                        .help("Default search timeout in milliseconds. A search that exceeds it returns the documents collected so far. 0 means no timeout.")
+                        .short("T")
+                        .long("search-timeout")
+                        .value_name("SEARCH_TIMEOUT")
+                        .default_value("0")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("SEARCH_CACHE_SIZE")
+                        .help("Maximum number of search results to cache. The cache is cleared whenever the index changes. Searches with date math relative to now are not cached. 0 disables the cache.")
+                        .short("c")
+                        .long("search-cache-size")
+                        .value_name("SEARCH_CACHE_SIZE")
+                        .default_value("0")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("DEFAULT_FIELDS")
+                        .help("Default fields of query strings with boosts, such as \"title^3 description\". Searches may override them. If not specified, use all indexed text fields with no boost.")
+                        .short("f")
+                        .long("default-fields")
+                        .value_name("DEFAULT_FIELDS")
+                        .default_value("")
+                        .takes_value(true),
+                )
         )
         .subcommand(
             SubCommand::with_name("probe")
@@ -238,6 +276,30 @@ fn main() {
                         .value_delimiter(",")
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("EXTERNAL_VERSION")
+                        .help("External version of the document. Must be greater than the current version. If not specified, the version is incremented automatically.")
+                        .short("e")
+                        .long("external-version")
+                        .value_name("VERSION")
+                        .default_value("0")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("IF_VERSION")
+                        .help("Put the document only if the current version matches the specified version.")
+                        .short("i")
+                        .long("if-version")
+                        .value_name("VERSION")
+                        .default_value("0")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("IF_ABSENT")
+                        .help("A flag indicating whether or not to put the document only if the ID does not exist.")
+                        .short("a")
+                        .long("if-absent"),
+                )
                 .arg(
                     Arg::with_name("DOC_ID")
                         .help("A unique value that identifies the document in the index. If specify an existing ID, the existing document in the index is overwritten.")
@@ -284,6 +346,61 @@ fn main() {
                         .takes_value(true),
                 )
         )
+        .subcommand(
+            SubCommand::with_name("update")
+                .name("update")
+                .setting(AppSettings::DeriveDisplayOrder)
+                .version(crate_version!())
+                .author(crate_authors!())
+                .about("The `bayard update` CLI partially updates a document with the specified ID. The stored fields of the existing document are modified and the document is re-indexed.")
+                .help_message("Prints help information.")
+                .version_message("Prints version information.")
+                .version_short("v")
+                .arg(
+                    Arg::with_name("SERVERS")
+                        .help("Server addresses in an existing cluster separated by \",\". If not specified, use default servers.")
+                        .short("s")
+                        .long("servers")
+                        .value_name("IP:PORT")
+                        .default_value("127.0.0.1:5000")
+                        .multiple(true)
+                        .use_delimiter(true)
+                        .require_delimiter(true)
+                        .value_delimiter(",")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("SET")
+                        .help("Document fields expressed in JSON format. The values of these fields replace the existing values.")
+                        .short("S")
+                        .long("set")
+                        .value_name("FIELDS")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("APPEND")
+                        .help("Document fields expressed in JSON format. These values are appended to the existing values.")
+                        .short("A")
+                        .long("append")
+                        .value_name("FIELDS")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("REMOVE")
+                        .help("Document fields expressed in JSON format. These values are removed from the existing values. If the value is null, the field is removed.")
+                        .short("R")
+                        .long("remove")
+                        .value_name("FIELDS")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("DOC_ID")
+                        .help("A unique value that identifies the document in the index.")
+                        .value_name("DOC_ID")
+                        .required(true)
+                        .takes_value(true),
+                )
+        )
         .subcommand(
             SubCommand::with_name("delete")
                 .name("delete")
@@ -307,6 +424,15 @@ fn main() {
                         .value_delimiter(",")
                         .takes_value(true),
                 )
+                .arg(
+                    Arg::with_name("IF_VERSION")
+                        .help("Delete the document only if the current version matches the specified version.")
+                        .short("i")
+                        .long("if-version")
+                        .value_name("VERSION")
+                        .default_value("0")
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("DOC_ID")
                         .help("A unique value that identifies the document in the index.")
@@ -315,6 +441,37 @@ fn main() {
                         .takes_value(true),
                 )
         )
+        .subcommand(
+            SubCommand::with_name("delete-by-query")
+                .name("delete-by-query")
+                .setting(AppSettings::DeriveDisplayOrder)
+                .version(crate_version!())
+                .author(crate_authors!())
+                .about("The `bayard delete-by-query` CLI deletes all committed documents that match the specified query.")
+                .help_message("Prints help information.")
+                .version_message("Prints version information.")
+                .version_short("v")
+                .arg(
+                    Arg::with_name("SERVERS")
+                        .help("Server addresses in an existing cluster separated by \",\". If not specified, use default servers.")
+                        .short("s")
+                        .long("servers")
+                        .value_name("IP:PORT")
+                        .default_value("127.0.0.1:5000")
+                        .multiple(true)
+                        .use_delimiter(true)
+                        .require_delimiter(true)
+                        .value_delimiter(",")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("QUERY")
+                        .help("Query string to select the documents to be deleted.")
+                        .value_name("QUERY")
+                        .required(true)
+                        .takes_value(true),
+                )
+        )
         .subcommand(
             SubCommand::with_name("commit")
                 .name("commit")
@@ -440,6 +597,24 @@ fn main() {
                         .short("d")
                         .long("exclude-docs"),
                 )
+                .arg(
+                    Arg::with_name("DID_YOU_MEAN")
+                        .help("A flag indicating whether or not to include spelling suggestions when few documents are hit.")
+                        .short("D")
+                        .long("did-you-mean"),
+                )
+                .arg(
+                    Arg::with_name("TYPO_TOLERANCE")
+                        .help("A flag indicating whether or not to match the terms of the query string within an edit distance chosen by the term length.")
+                        .short("y")
+                        .long("typo-tolerance"),
+                )
+                .arg(
+                    Arg::with_name("GEO_SORT")
+                        .help("A flag indicating whether or not to sort hit documents by distance from the geo origin.")
+                        .short("S")
+                        .long("geo-sort"),
+                )
                 .arg(
                     Arg::with_name("FACET_FIELD")
                         .help("Hierarchical facet field name.")
@@ -459,20 +634,276 @@ fn main() {
                         .number_of_values(1)
                         .takes_value(true),
                 )
-//                .arg(
-//                    Arg::with_name("FACET")
-//                        .help("Hierarchical facet.")
-//                        .short("F")
-//                        .long("facet")
-//                        .value_name("FIELD_NAME:FIELD_VALUE")
-//                        .multiple(true)
-//                        .number_of_values(1)
-//                        .takes_value(true),
-//                )
+                .arg(
+                    Arg::with_name("FACET")
+                        .help("Hierarchical facet field name and value prefix to count, such as \"category:/category\". Can be specified for any number of fields.")
+                        .short("k")
+                        .long("facet")
+                        .value_name("FIELD_NAME:FIELD_VALUE")
+                        .multiple(true)
+                        .number_of_values(1)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("FACET_LIMIT")
+                        .help("Maximum number of facet values to return for a field, such as \"category:10\".")
+                        .short("N")
+                        .long("facet-limit")
+                        .value_name("FIELD_NAME:LIMIT")
+                        .multiple(true)
+                        .number_of_values(1)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("FACET_SORT")
+                        .help("Sort order of facet values for a field, such as \"category:count desc\" or \"category:value asc\".")
+                        .short("R")
+                        .long("facet-sort")
+                        .value_name("FIELD_NAME:ORDER")
+                        .multiple(true)
+                        .number_of_values(1)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("POST_FILTER")
+                        .help("Query string to filter the returned documents. The facet counts are not affected by this filter.")
+                        .short("P")
+                        .long("post-filter")
+                        .value_name("POST_FILTER")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("GEO_FIELD")
+                        .help("Geo point field name used by the geo options.")
+                        .short("g")
+                        .long("geo-field")
+                        .value_name("GEO_FIELD")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("GEO_ORIGIN")
+                        .help("Point to measure distances from, in \"lat,lon\" format.")
+                        .short("o")
+                        .long("geo-origin")
+                        .value_name("LAT,LON")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("GEO_DISTANCE")
+                        .help("Maximum distance from the geo origin, such as \"10km\". The units m, km and mi are available.")
+                        .short("r")
+                        .long("geo-distance")
+                        .value_name("DISTANCE")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("GEO_BOUNDING_BOX")
+                        .help("Bounding box to filter hit documents, in \"top,left,bottom,right\" format.")
+                        .short("b")
+                        .long("geo-bounding-box")
+                        .value_name("TOP,LEFT,BOTTOM,RIGHT")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("DATE_FIELD")
+                        .help("Date field name to filter hit documents by.")
+                        .short("a")
+                        .long("date-field")
+                        .value_name("DATE_FIELD")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("DATE_FROM")
+                        .help("Inclusive lower bound of the date range, such as \"2020-01-01T00:00:00Z\" or \"now-7d/d\".")
+                        .short("A")
+                        .long("date-from")
+                        .value_name("DATE_FROM")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("DATE_TO")
+                        .help("Exclusive upper bound of the date range, such as \"2020-02-01\" or \"now/d\".")
+                        .short("B")
+                        .long("date-to")
+                        .value_name("DATE_TO")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("TIME_ZONE")
+                        .help("Time zone offset such as \"+09:00\", used for dates without an offset and for rounding. If not specified, use UTC.")
+                        .short("z")
+                        .long("time-zone")
+                        .value_name("TIME_ZONE")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("FUNCTION_SCORE")
+                        .help("Scoring functions in JSON format that combine the score with the values of fast fields.")
+                        .short("x")
+                        .long("function-score")
+                        .value_name("FUNCTION_SCORE")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("COLLAPSE_FIELD")
+                        .help("Indexed fast field or hierarchical facet field name to collapse hit documents by. Only the top document of each distinct value is returned. Documents without a value are not returned.")
+                        .short("C")
+                        .long("collapse-field")
+                        .value_name("COLLAPSE_FIELD")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("AGGREGATIONS")
+                        .help("Named aggregations in JSON format, such as range buckets, histograms, date histograms and stats over fast fields.")
+                        .short("G")
+                        .long("aggregations")
+                        .value_name("AGGREGATIONS")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("HIGHLIGHT")
+                        .help("Highlight setting in JSON format with the text fields to highlight, the fragment size and the tags around the matched terms.")
+                        .short("H")
+                        .long("highlight")
+                        .value_name("HIGHLIGHT")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("QUERY_DSL")
+                        .help("Query in JSON format, such as bool, term, terms, phrase, range, prefix and exists queries. If QUERY is also specified, hit documents must match both.")
+                        .short("j")
+                        .long("query-dsl")
+                        .value_name("QUERY_DSL")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("DEFAULT_FIELDS")
+                        .help("Default fields of the query string with boosts, such as \"title^3 description\". If not specified, use the server's default fields.")
+                        .short("W")
+                        .long("default-fields")
+                        .value_name("DEFAULT_FIELDS")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("SORT")
+                        .help("Fast fields to sort hit documents by, such as \"popularity desc\" or \"timestamp asc\". Later fields break ties of earlier ones, and the score breaks the remaining ties.")
+                        .short("O")
+                        .long("sort")
+                        .value_name("FIELD [asc|desc]")
+                        .multiple(true)
+                        .number_of_values(1)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("TIMEOUT")
+                        .help("Search timeout in milliseconds. A search that exceeds it returns the documents collected so far. If not specified, use the server's default timeout.")
+                        .short("T")
+                        .long("timeout")
+                        .value_name("TIMEOUT")
+                        .default_value("0")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("TERMINATE_AFTER")
+                        .help("Maximum number of documents to collect. The search stops early once it is reached. 0 means no limit.")
+                        .short("e")
+                        .long("terminate-after")
+                        .value_name("TERMINATE_AFTER")
+                        .default_value("0")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("MORE_LIKE_THIS_DOC_ID")
+                        .help("Document ID to find similar documents to. The document itself is excluded from the results.")
+                        .short("m")
+                        .long("more-like-this-doc-id")
+                        .value_name("DOC_ID")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("MORE_LIKE_THIS_TEXT")
+                        .help("Text to find similar documents to.")
+                        .short("t")
+                        .long("more-like-this-text")
+                        .value_name("TEXT")
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("QUERY")
-                        .help("Query string to search the index.")
+                        .help("Query string to search the index. If not specified, all documents are matched.")
                         .value_name("QUERY")
+                        .takes_value(true),
+                )
+        )
+        .subcommand(
+            SubCommand::with_name("suggest")
+                .name("suggest")
+                .setting(AppSettings::DeriveDisplayOrder)
+                .version(crate_version!())
+                .author(crate_authors!())
+                .about("The `bayard suggest` CLI completes a prefix from the terms of a field.")
+                .help_message("Prints help information.")
+                .version_message("Prints version information.")
+                .version_short("v")
+                .arg(
+                    Arg::with_name("SERVERS")
+                        .help("Server addresses in an existing cluster separated by \",\". If not specified, use default servers.")
+                        .short("s")
+                        .long("servers")
+                        .value_name("IP:PORT")
+                        .default_value("127.0.0.1:5000")
+                        .multiple(true)
+                        .use_delimiter(true)
+                        .require_delimiter(true)
+                        .value_delimiter(",")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("FIELD")
+                        .help("Text field name to complete the prefix against.")
+                        .short("f")
+                        .long("field")
+                        .value_name("FIELD")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("LIMIT")
+                        .help("Limitation of amount that suggestions to be returned. If not specified, use default value.")
+                        .short("l")
+                        .long("limit")
+                        .value_name("LIMIT")
+                        .default_value("10")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("WEIGHT_FIELD")
+                        .help("Unsigned integer fast field used to rank suggestions. If not specified, suggestions are ranked by document frequency.")
+                        .short("w")
+                        .long("weight-field")
+                        .value_name("WEIGHT_FIELD")
+                        .default_value("")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("PREFIX")
+                        .help("Prefix to complete.")
+                        .value_name("PREFIX")
                         .required(true)
                         .takes_value(true),
                 )
@@ -597,11 +1028,14 @@ fn main() {
         "leave" => run_leave_cli,
         "put" => run_put_cli,
         "get" => run_get_cli,
+        "update" => run_update_cli,
         "delete" => run_delete_cli,
+        "delete-by-query" => run_delete_by_query_cli,
         "commit" => run_commit_cli,
         "rollback" => run_rollback_cli,
         "merge" => run_merge_cli,
         "search" => run_search_cli,
+        "suggest" => run_suggest_cli,
         "schema" => run_schema_cli,
         "schedule" => run_schedule_cli,
         "gateway" => run_gateway_cli,
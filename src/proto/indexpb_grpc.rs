@@ -60,6 +60,13 @@ const METHOD_INDEX_GET: ::grpcio::Method<super::indexrpcpb::GetReq, super::index
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_INDEX_MULTI_GET: ::grpcio::Method<super::indexrpcpb::MultiGetReq, super::indexrpcpb::MultiGetResp> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/indexpb.Index/MultiGet",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_INDEX_PUT: ::grpcio::Method<super::indexrpcpb::ApplyReq, super::indexrpcpb::PutResp> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/indexpb.Index/Put",
@@ -67,6 +74,13 @@ const METHOD_INDEX_PUT: ::grpcio::Method<super::indexrpcpb::ApplyReq, super::ind
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_INDEX_UPDATE: ::grpcio::Method<super::indexrpcpb::ApplyReq, super::indexrpcpb::UpdateResp> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/indexpb.Index/Update",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_INDEX_DELETE: ::grpcio::Method<super::indexrpcpb::ApplyReq, super::indexrpcpb::DeleteResp> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/indexpb.Index/Delete",
@@ -74,6 +88,13 @@ const METHOD_INDEX_DELETE: ::grpcio::Method<super::indexrpcpb::ApplyReq, super::
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_INDEX_DELETE_BY_QUERY: ::grpcio::Method<super::indexrpcpb::ApplyReq, super::indexrpcpb::DeleteByQueryResp> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/indexpb.Index/DeleteByQuery",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_INDEX_COMMIT: ::grpcio::Method<super::indexrpcpb::ApplyReq, super::indexrpcpb::CommitResp> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/indexpb.Index/Commit",
@@ -102,6 +123,13 @@ const METHOD_INDEX_SEARCH: ::grpcio::Method<super::indexrpcpb::SearchReq, super:
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_INDEX_SUGGEST: ::grpcio::Method<super::indexrpcpb::SuggestReq, super::indexrpcpb::SuggestResp> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/indexpb.Index/Suggest",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_INDEX_SCHEMA: ::grpcio::Method<super::indexrpcpb::SchemaReq, super::indexrpcpb::SchemaResp> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/indexpb.Index/Schema",
@@ -217,6 +245,22 @@ impl IndexClient {
         self.get_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn multi_get_opt(&self, req: &super::indexrpcpb::MultiGetReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::indexrpcpb::MultiGetResp> {
+        self.client.unary_call(&METHOD_INDEX_MULTI_GET, req, opt)
+    }
+
+    pub fn multi_get(&self, req: &super::indexrpcpb::MultiGetReq) -> ::grpcio::Result<super::indexrpcpb::MultiGetResp> {
+        self.multi_get_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn multi_get_async_opt(&self, req: &super::indexrpcpb::MultiGetReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::indexrpcpb::MultiGetResp>> {
+        self.client.unary_call_async(&METHOD_INDEX_MULTI_GET, req, opt)
+    }
+
+    pub fn multi_get_async(&self, req: &super::indexrpcpb::MultiGetReq) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::indexrpcpb::MultiGetResp>> {
+        self.multi_get_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn put_opt(&self, req: &super::indexrpcpb::ApplyReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::indexrpcpb::PutResp> {
         self.client.unary_call(&METHOD_INDEX_PUT, req, opt)
     }
@@ -233,6 +277,22 @@ impl IndexClient {
         self.put_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn update_opt(&self, req: &super::indexrpcpb::ApplyReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::indexrpcpb::UpdateResp> {
+        self.client.unary_call(&METHOD_INDEX_UPDATE, req, opt)
+    }
+
+    pub fn update(&self, req: &super::indexrpcpb::ApplyReq) -> ::grpcio::Result<super::indexrpcpb::UpdateResp> {
+        self.update_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn update_async_opt(&self, req: &super::indexrpcpb::ApplyReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::indexrpcpb::UpdateResp>> {
+        self.client.unary_call_async(&METHOD_INDEX_UPDATE, req, opt)
+    }
+
+    pub fn update_async(&self, req: &super::indexrpcpb::ApplyReq) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::indexrpcpb::UpdateResp>> {
+        self.update_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn delete_opt(&self, req: &super::indexrpcpb::ApplyReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::indexrpcpb::DeleteResp> {
         self.client.unary_call(&METHOD_INDEX_DELETE, req, opt)
     }
@@ -249,6 +309,22 @@ impl IndexClient {
         self.delete_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn delete_by_query_opt(&self, req: &super::indexrpcpb::ApplyReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::indexrpcpb::DeleteByQueryResp> {
+        self.client.unary_call(&METHOD_INDEX_DELETE_BY_QUERY, req, opt)
+    }
+
+    pub fn delete_by_query(&self, req: &super::indexrpcpb::ApplyReq) -> ::grpcio::Result<super::indexrpcpb::DeleteByQueryResp> {
+        self.delete_by_query_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn delete_by_query_async_opt(&self, req: &super::indexrpcpb::ApplyReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::indexrpcpb::DeleteByQueryResp>> {
+        self.client.unary_call_async(&METHOD_INDEX_DELETE_BY_QUERY, req, opt)
+    }
+
+    pub fn delete_by_query_async(&self, req: &super::indexrpcpb::ApplyReq) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::indexrpcpb::DeleteByQueryResp>> {
+        self.delete_by_query_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn commit_opt(&self, req: &super::indexrpcpb::ApplyReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::indexrpcpb::CommitResp> {
         self.client.unary_call(&METHOD_INDEX_COMMIT, req, opt)
     }
@@ -313,6 +389,22 @@ impl IndexClient {
         self.search_async_opt(req, ::grpcio::CallOption::default())
     }
 
+    pub fn suggest_opt(&self, req: &super::indexrpcpb::SuggestReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::indexrpcpb::SuggestResp> {
+        self.client.unary_call(&METHOD_INDEX_SUGGEST, req, opt)
+    }
+
+    pub fn suggest(&self, req: &super::indexrpcpb::SuggestReq) -> ::grpcio::Result<super::indexrpcpb::SuggestResp> {
+        self.suggest_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn suggest_async_opt(&self, req: &super::indexrpcpb::SuggestReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::indexrpcpb::SuggestResp>> {
+        self.client.unary_call_async(&METHOD_INDEX_SUGGEST, req, opt)
+    }
+
+    pub fn suggest_async(&self, req: &super::indexrpcpb::SuggestReq) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::indexrpcpb::SuggestResp>> {
+        self.suggest_async_opt(req, ::grpcio::CallOption::default())
+    }
+
     pub fn schema_opt(&self, req: &super::indexrpcpb::SchemaReq, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::indexrpcpb::SchemaResp> {
         self.client.unary_call(&METHOD_INDEX_SCHEMA, req, opt)
     }
@@ -340,12 +432,16 @@ pub trait Index {
     fn peers(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::PeersReq, sink: ::grpcio::UnarySink<super::indexrpcpb::PeersResp>);
     fn metrics(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::MetricsReq, sink: ::grpcio::UnarySink<super::indexrpcpb::MetricsResp>);
     fn get(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::GetReq, sink: ::grpcio::UnarySink<super::indexrpcpb::GetResp>);
+    fn multi_get(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::MultiGetReq, sink: ::grpcio::UnarySink<super::indexrpcpb::MultiGetResp>);
     fn put(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::ApplyReq, sink: ::grpcio::UnarySink<super::indexrpcpb::PutResp>);
+    fn update(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::ApplyReq, sink: ::grpcio::UnarySink<super::indexrpcpb::UpdateResp>);
     fn delete(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::ApplyReq, sink: ::grpcio::UnarySink<super::indexrpcpb::DeleteResp>);
+    fn delete_by_query(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::ApplyReq, sink: ::grpcio::UnarySink<super::indexrpcpb::DeleteByQueryResp>);
     fn commit(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::ApplyReq, sink: ::grpcio::UnarySink<super::indexrpcpb::CommitResp>);
     fn rollback(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::ApplyReq, sink: ::grpcio::UnarySink<super::indexrpcpb::RollbackResp>);
     fn merge(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::ApplyReq, sink: ::grpcio::UnarySink<super::indexrpcpb::MergeResp>);
     fn search(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::SearchReq, sink: ::grpcio::UnarySink<super::indexrpcpb::SearchResp>);
+    fn suggest(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::SuggestReq, sink: ::grpcio::UnarySink<super::indexrpcpb::SuggestResp>);
     fn schema(&mut self, ctx: ::grpcio::RpcContext, req: super::indexrpcpb::SchemaReq, sink: ::grpcio::UnarySink<super::indexrpcpb::SchemaResp>);
 }
 
@@ -376,14 +472,26 @@ pub fn create_index<S: Index + Send + Clone + 'static>(s: S) -> ::grpcio::Servic
         instance.get(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_INDEX_MULTI_GET, move |ctx, req, resp| {
+        instance.multi_get(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_INDEX_PUT, move |ctx, req, resp| {
         instance.put(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_INDEX_UPDATE, move |ctx, req, resp| {
+        instance.update(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_INDEX_DELETE, move |ctx, req, resp| {
         instance.delete(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_INDEX_DELETE_BY_QUERY, move |ctx, req, resp| {
+        instance.delete_by_query(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_INDEX_COMMIT, move |ctx, req, resp| {
         instance.commit(ctx, req, resp)
     });
@@ -400,6 +508,10 @@ pub fn create_index<S: Index + Send + Clone + 'static>(s: S) -> ::grpcio::Servic
         instance.search(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_INDEX_SUGGEST, move |ctx, req, resp| {
+        instance.suggest(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_INDEX_SCHEMA, move |ctx, req, resp| {
         instance.schema(ctx, req, resp)
     });
@@ -38,6 +38,8 @@ pub struct ApplyReq {
     pub commit_req: ::protobuf::SingularPtrField<CommitReq>,
     pub rollback_req: ::protobuf::SingularPtrField<RollbackReq>,
     pub merge_req: ::protobuf::SingularPtrField<MergeReq>,
+    pub update_req: ::protobuf::SingularPtrField<UpdateReq>,
+    pub delete_by_query_req: ::protobuf::SingularPtrField<DeleteByQueryReq>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -314,6 +316,72 @@ impl ApplyReq {
     pub fn take_merge_req(&mut self) -> MergeReq {
         self.merge_req.take().unwrap_or_else(|| MergeReq::new())
     }
+
+    // .indexrpcpb.UpdateReq update_req = 10;
+
+
+    pub fn get_update_req(&self) -> &UpdateReq {
+        self.update_req.as_ref().unwrap_or_else(|| UpdateReq::default_instance())
+    }
+    pub fn clear_update_req(&mut self) {
+        self.update_req.clear();
+    }
+
+    pub fn has_update_req(&self) -> bool {
+        self.update_req.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_update_req(&mut self, v: UpdateReq) {
+        self.update_req = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_update_req(&mut self) -> &mut UpdateReq {
+        if self.update_req.is_none() {
+            self.update_req.set_default();
+        }
+        self.update_req.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_update_req(&mut self) -> UpdateReq {
+        self.update_req.take().unwrap_or_else(|| UpdateReq::new())
+    }
+
+    // .indexrpcpb.DeleteByQueryReq delete_by_query_req = 11;
+
+
+    pub fn get_delete_by_query_req(&self) -> &DeleteByQueryReq {
+        self.delete_by_query_req.as_ref().unwrap_or_else(|| DeleteByQueryReq::default_instance())
+    }
+    pub fn clear_delete_by_query_req(&mut self) {
+        self.delete_by_query_req.clear();
+    }
+
+    pub fn has_delete_by_query_req(&self) -> bool {
+        self.delete_by_query_req.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delete_by_query_req(&mut self, v: DeleteByQueryReq) {
+        self.delete_by_query_req = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_delete_by_query_req(&mut self) -> &mut DeleteByQueryReq {
+        if self.delete_by_query_req.is_none() {
+            self.delete_by_query_req.set_default();
+        }
+        self.delete_by_query_req.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_delete_by_query_req(&mut self) -> DeleteByQueryReq {
+        self.delete_by_query_req.take().unwrap_or_else(|| DeleteByQueryReq::new())
+    }
 }
 
 impl ::protobuf::Message for ApplyReq {
@@ -353,6 +421,16 @@ impl ::protobuf::Message for ApplyReq {
                 return false;
             }
         };
+        for v in &self.update_req {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.delete_by_query_req {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -391,6 +469,12 @@ impl ::protobuf::Message for ApplyReq {
                 9 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.merge_req)?;
                 },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.update_req)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.delete_by_query_req)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -437,6 +521,14 @@ impl ::protobuf::Message for ApplyReq {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let Some(ref v) = self.update_req.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.delete_by_query_req.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -484,6 +576,16 @@ impl ::protobuf::Message for ApplyReq {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let Some(ref v) = self.update_req.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.delete_by_query_req.as_ref() {
+            os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -571,6 +673,16 @@ impl ::protobuf::Message for ApplyReq {
                     |m: &ApplyReq| { &m.merge_req },
                     |m: &mut ApplyReq| { &mut m.merge_req },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UpdateReq>>(
+                    "update_req",
+                    |m: &ApplyReq| { &m.update_req },
+                    |m: &mut ApplyReq| { &mut m.update_req },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DeleteByQueryReq>>(
+                    "delete_by_query_req",
+                    |m: &ApplyReq| { &m.delete_by_query_req },
+                    |m: &mut ApplyReq| { &mut m.delete_by_query_req },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<ApplyReq>(
                     "ApplyReq",
                     fields,
@@ -602,6 +714,8 @@ impl ::protobuf::Clear for ApplyReq {
         self.commit_req.clear();
         self.rollback_req.clear();
         self.merge_req.clear();
+        self.update_req.clear();
+        self.delete_by_query_req.clear();
         self.unknown_fields.clear();
     }
 }
@@ -3146,25 +3260,24 @@ impl ::protobuf::reflect::ProtobufValue for GetResp {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct PutReq {
+pub struct MultiGetReq {
     // message fields
     pub client_id: u64,
     pub seq: u64,
-    pub doc_id: ::std::string::String,
-    pub fields: ::std::string::String,
+    pub doc_ids: ::protobuf::RepeatedField<::std::string::String>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a PutReq {
-    fn default() -> &'a PutReq {
-        <PutReq as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a MultiGetReq {
+    fn default() -> &'a MultiGetReq {
+        <MultiGetReq as ::protobuf::Message>::default_instance()
     }
 }
 
-impl PutReq {
-    pub fn new() -> PutReq {
+impl MultiGetReq {
+    pub fn new() -> MultiGetReq {
         ::std::default::Default::default()
     }
 
@@ -3198,60 +3311,33 @@ impl PutReq {
         self.seq = v;
     }
 
-    // string doc_id = 3;
-
-
-    pub fn get_doc_id(&self) -> &str {
-        &self.doc_id
-    }
-    pub fn clear_doc_id(&mut self) {
-        self.doc_id.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_doc_id(&mut self, v: ::std::string::String) {
-        self.doc_id = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
-        &mut self.doc_id
-    }
-
-    // Take field
-    pub fn take_doc_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
-    }
-
-    // string fields = 4;
+    // repeated string doc_ids = 3;
 
 
-    pub fn get_fields(&self) -> &str {
-        &self.fields
+    pub fn get_doc_ids(&self) -> &[::std::string::String] {
+        &self.doc_ids
     }
-    pub fn clear_fields(&mut self) {
-        self.fields.clear();
+    pub fn clear_doc_ids(&mut self) {
+        self.doc_ids.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_fields(&mut self, v: ::std::string::String) {
-        self.fields = v;
+    pub fn set_doc_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.doc_ids = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_fields(&mut self) -> &mut ::std::string::String {
-        &mut self.fields
+    pub fn mut_doc_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.doc_ids
     }
 
     // Take field
-    pub fn take_fields(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.fields, ::std::string::String::new())
+    pub fn take_doc_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.doc_ids, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for PutReq {
+impl ::protobuf::Message for MultiGetReq {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3275,10 +3361,7 @@ impl ::protobuf::Message for PutReq {
                     self.seq = tmp;
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fields)?;
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.doc_ids)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3298,12 +3381,9 @@ impl ::protobuf::Message for PutReq {
         if self.seq != 0 {
             my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.doc_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.doc_id);
-        }
-        if !self.fields.is_empty() {
-            my_size += ::protobuf::rt::string_size(4, &self.fields);
-        }
+        for value in &self.doc_ids {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3316,12 +3396,9 @@ impl ::protobuf::Message for PutReq {
         if self.seq != 0 {
             os.write_uint64(2, self.seq)?;
         }
-        if !self.doc_id.is_empty() {
-            os.write_string(3, &self.doc_id)?;
-        }
-        if !self.fields.is_empty() {
-            os.write_string(4, &self.fields)?;
-        }
+        for v in &self.doc_ids {
+            os.write_string(3, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3352,8 +3429,8 @@ impl ::protobuf::Message for PutReq {
         Self::descriptor_static()
     }
 
-    fn new() -> PutReq {
-        PutReq::new()
+    fn new() -> MultiGetReq {
+        MultiGetReq::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3366,26 +3443,21 @@ impl ::protobuf::Message for PutReq {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "client_id",
-                    |m: &PutReq| { &m.client_id },
-                    |m: &mut PutReq| { &mut m.client_id },
+                    |m: &MultiGetReq| { &m.client_id },
+                    |m: &mut MultiGetReq| { &mut m.client_id },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "seq",
-                    |m: &PutReq| { &m.seq },
-                    |m: &mut PutReq| { &mut m.seq },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "doc_id",
-                    |m: &PutReq| { &m.doc_id },
-                    |m: &mut PutReq| { &mut m.doc_id },
+                    |m: &MultiGetReq| { &m.seq },
+                    |m: &mut MultiGetReq| { &mut m.seq },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "fields",
-                    |m: &PutReq| { &m.fields },
-                    |m: &mut PutReq| { &mut m.fields },
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "doc_ids",
+                    |m: &MultiGetReq| { &m.doc_ids },
+                    |m: &mut MultiGetReq| { &mut m.doc_ids },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PutReq>(
-                    "PutReq",
+                ::protobuf::reflect::MessageDescriptor::new::<MultiGetReq>(
+                    "MultiGetReq",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3393,41 +3465,40 @@ impl ::protobuf::Message for PutReq {
         }
     }
 
-    fn default_instance() -> &'static PutReq {
-        static mut instance: ::protobuf::lazy::Lazy<PutReq> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static MultiGetReq {
+        static mut instance: ::protobuf::lazy::Lazy<MultiGetReq> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PutReq,
+            ptr: 0 as *const MultiGetReq,
         };
         unsafe {
-            instance.get(PutReq::new)
+            instance.get(MultiGetReq::new)
         }
     }
 }
 
-impl ::protobuf::Clear for PutReq {
+impl ::protobuf::Clear for MultiGetReq {
     fn clear(&mut self) {
         self.client_id = 0;
         self.seq = 0;
-        self.doc_id.clear();
-        self.fields.clear();
+        self.doc_ids.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for PutReq {
+impl ::std::fmt::Debug for MultiGetReq {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PutReq {
+impl ::protobuf::reflect::ProtobufValue for MultiGetReq {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct PutResp {
+pub struct MultiGetResp {
     // message fields
     pub value: ::std::string::String,
     pub err: RespErr,
@@ -3436,14 +3507,14 @@ pub struct PutResp {
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a PutResp {
-    fn default() -> &'a PutResp {
-        <PutResp as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a MultiGetResp {
+    fn default() -> &'a MultiGetResp {
+        <MultiGetResp as ::protobuf::Message>::default_instance()
     }
 }
 
-impl PutResp {
-    pub fn new() -> PutResp {
+impl MultiGetResp {
+    pub fn new() -> MultiGetResp {
         ::std::default::Default::default()
     }
 
@@ -3489,7 +3560,7 @@ impl PutResp {
     }
 }
 
-impl ::protobuf::Message for PutResp {
+impl ::protobuf::Message for MultiGetResp {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3564,8 +3635,8 @@ impl ::protobuf::Message for PutResp {
         Self::descriptor_static()
     }
 
-    fn new() -> PutResp {
-        PutResp::new()
+    fn new() -> MultiGetResp {
+        MultiGetResp::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3578,16 +3649,16 @@ impl ::protobuf::Message for PutResp {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "value",
-                    |m: &PutResp| { &m.value },
-                    |m: &mut PutResp| { &mut m.value },
+                    |m: &MultiGetResp| { &m.value },
+                    |m: &mut MultiGetResp| { &mut m.value },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
                     "err",
-                    |m: &PutResp| { &m.err },
-                    |m: &mut PutResp| { &mut m.err },
+                    |m: &MultiGetResp| { &m.err },
+                    |m: &mut MultiGetResp| { &mut m.err },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PutResp>(
-                    "PutResp",
+                ::protobuf::reflect::MessageDescriptor::new::<MultiGetResp>(
+                    "MultiGetResp",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3595,18 +3666,18 @@ impl ::protobuf::Message for PutResp {
         }
     }
 
-    fn default_instance() -> &'static PutResp {
-        static mut instance: ::protobuf::lazy::Lazy<PutResp> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static MultiGetResp {
+        static mut instance: ::protobuf::lazy::Lazy<MultiGetResp> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PutResp,
+            ptr: 0 as *const MultiGetResp,
         };
         unsafe {
-            instance.get(PutResp::new)
+            instance.get(MultiGetResp::new)
         }
     }
 }
 
-impl ::protobuf::Clear for PutResp {
+impl ::protobuf::Clear for MultiGetResp {
     fn clear(&mut self) {
         self.value.clear();
         self.err = RespErr::OK;
@@ -3614,37 +3685,41 @@ impl ::protobuf::Clear for PutResp {
     }
 }
 
-impl ::std::fmt::Debug for PutResp {
+impl ::std::fmt::Debug for MultiGetResp {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PutResp {
+impl ::protobuf::reflect::ProtobufValue for MultiGetResp {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct DeleteReq {
+pub struct PutReq {
     // message fields
     pub client_id: u64,
     pub seq: u64,
     pub doc_id: ::std::string::String,
+    pub fields: ::std::string::String,
+    pub version: u64,
+    pub if_version: u64,
+    pub if_absent: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a DeleteReq {
-    fn default() -> &'a DeleteReq {
-        <DeleteReq as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a PutReq {
+    fn default() -> &'a PutReq {
+        <PutReq as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DeleteReq {
-    pub fn new() -> DeleteReq {
+impl PutReq {
+    pub fn new() -> PutReq {
         ::std::default::Default::default()
     }
 
@@ -3703,9 +3778,80 @@ impl DeleteReq {
     pub fn take_doc_id(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
     }
+
+    // string fields = 4;
+
+
+    pub fn get_fields(&self) -> &str {
+        &self.fields
+    }
+    pub fn clear_fields(&mut self) {
+        self.fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fields(&mut self, v: ::std::string::String) {
+        self.fields = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fields(&mut self) -> &mut ::std::string::String {
+        &mut self.fields
+    }
+
+    // Take field
+    pub fn take_fields(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.fields, ::std::string::String::new())
+    }
+
+    // uint64 version = 5;
+
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
+
+    // uint64 if_version = 6;
+
+
+    pub fn get_if_version(&self) -> u64 {
+        self.if_version
+    }
+    pub fn clear_if_version(&mut self) {
+        self.if_version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_if_version(&mut self, v: u64) {
+        self.if_version = v;
+    }
+
+    // bool if_absent = 7;
+
+
+    pub fn get_if_absent(&self) -> bool {
+        self.if_absent
+    }
+    pub fn clear_if_absent(&mut self) {
+        self.if_absent = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_if_absent(&mut self, v: bool) {
+        self.if_absent = v;
+    }
 }
 
-impl ::protobuf::Message for DeleteReq {
+impl ::protobuf::Message for PutReq {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3731,27 +3877,63 @@ impl ::protobuf::Message for DeleteReq {
                 3 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
                 },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fields)?;
                 },
-            };
-        }
-        ::std::result::Result::Ok(())
-    }
-
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if self.client_id != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.client_id, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.seq != 0 {
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.if_version = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.if_absent = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.client_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.client_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
             my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
         }
         if !self.doc_id.is_empty() {
             my_size += ::protobuf::rt::string_size(3, &self.doc_id);
         }
+        if !self.fields.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.fields);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.if_version != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.if_version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.if_absent != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3767,6 +3949,18 @@ impl ::protobuf::Message for DeleteReq {
         if !self.doc_id.is_empty() {
             os.write_string(3, &self.doc_id)?;
         }
+        if !self.fields.is_empty() {
+            os.write_string(4, &self.fields)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(5, self.version)?;
+        }
+        if self.if_version != 0 {
+            os.write_uint64(6, self.if_version)?;
+        }
+        if self.if_absent != false {
+            os.write_bool(7, self.if_absent)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3797,8 +3991,8 @@ impl ::protobuf::Message for DeleteReq {
         Self::descriptor_static()
     }
 
-    fn new() -> DeleteReq {
-        DeleteReq::new()
+    fn new() -> PutReq {
+        PutReq::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3811,21 +4005,41 @@ impl ::protobuf::Message for DeleteReq {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "client_id",
-                    |m: &DeleteReq| { &m.client_id },
-                    |m: &mut DeleteReq| { &mut m.client_id },
+                    |m: &PutReq| { &m.client_id },
+                    |m: &mut PutReq| { &mut m.client_id },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "seq",
-                    |m: &DeleteReq| { &m.seq },
-                    |m: &mut DeleteReq| { &mut m.seq },
+                    |m: &PutReq| { &m.seq },
+                    |m: &mut PutReq| { &mut m.seq },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "doc_id",
-                    |m: &DeleteReq| { &m.doc_id },
-                    |m: &mut DeleteReq| { &mut m.doc_id },
+                    |m: &PutReq| { &m.doc_id },
+                    |m: &mut PutReq| { &mut m.doc_id },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<DeleteReq>(
-                    "DeleteReq",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "fields",
+                    |m: &PutReq| { &m.fields },
+                    |m: &mut PutReq| { &mut m.fields },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "version",
+                    |m: &PutReq| { &m.version },
+                    |m: &mut PutReq| { &mut m.version },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "if_version",
+                    |m: &PutReq| { &m.if_version },
+                    |m: &mut PutReq| { &mut m.if_version },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "if_absent",
+                    |m: &PutReq| { &m.if_absent },
+                    |m: &mut PutReq| { &mut m.if_absent },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PutReq>(
+                    "PutReq",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3833,40 +4047,44 @@ impl ::protobuf::Message for DeleteReq {
         }
     }
 
-    fn default_instance() -> &'static DeleteReq {
-        static mut instance: ::protobuf::lazy::Lazy<DeleteReq> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static PutReq {
+        static mut instance: ::protobuf::lazy::Lazy<PutReq> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const DeleteReq,
+            ptr: 0 as *const PutReq,
         };
         unsafe {
-            instance.get(DeleteReq::new)
+            instance.get(PutReq::new)
         }
     }
 }
 
-impl ::protobuf::Clear for DeleteReq {
+impl ::protobuf::Clear for PutReq {
     fn clear(&mut self) {
         self.client_id = 0;
         self.seq = 0;
         self.doc_id.clear();
+        self.fields.clear();
+        self.version = 0;
+        self.if_version = 0;
+        self.if_absent = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for DeleteReq {
+impl ::std::fmt::Debug for PutReq {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DeleteReq {
+impl ::protobuf::reflect::ProtobufValue for PutReq {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct DeleteResp {
+pub struct PutResp {
     // message fields
     pub value: ::std::string::String,
     pub err: RespErr,
@@ -3875,14 +4093,14 @@ pub struct DeleteResp {
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a DeleteResp {
-    fn default() -> &'a DeleteResp {
-        <DeleteResp as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a PutResp {
+    fn default() -> &'a PutResp {
+        <PutResp as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DeleteResp {
-    pub fn new() -> DeleteResp {
+impl PutResp {
+    pub fn new() -> PutResp {
         ::std::default::Default::default()
     }
 
@@ -3928,7 +4146,7 @@ impl DeleteResp {
     }
 }
 
-impl ::protobuf::Message for DeleteResp {
+impl ::protobuf::Message for PutResp {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4003,8 +4221,8 @@ impl ::protobuf::Message for DeleteResp {
         Self::descriptor_static()
     }
 
-    fn new() -> DeleteResp {
-        DeleteResp::new()
+    fn new() -> PutResp {
+        PutResp::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4017,16 +4235,16 @@ impl ::protobuf::Message for DeleteResp {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "value",
-                    |m: &DeleteResp| { &m.value },
-                    |m: &mut DeleteResp| { &mut m.value },
+                    |m: &PutResp| { &m.value },
+                    |m: &mut PutResp| { &mut m.value },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
                     "err",
-                    |m: &DeleteResp| { &m.err },
-                    |m: &mut DeleteResp| { &mut m.err },
+                    |m: &PutResp| { &m.err },
+                    |m: &mut PutResp| { &mut m.err },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<DeleteResp>(
-                    "DeleteResp",
+                ::protobuf::reflect::MessageDescriptor::new::<PutResp>(
+                    "PutResp",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4034,18 +4252,18 @@ impl ::protobuf::Message for DeleteResp {
         }
     }
 
-    fn default_instance() -> &'static DeleteResp {
-        static mut instance: ::protobuf::lazy::Lazy<DeleteResp> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static PutResp {
+        static mut instance: ::protobuf::lazy::Lazy<PutResp> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const DeleteResp,
+            ptr: 0 as *const PutResp,
         };
         unsafe {
-            instance.get(DeleteResp::new)
+            instance.get(PutResp::new)
         }
     }
 }
 
-impl ::protobuf::Clear for DeleteResp {
+impl ::protobuf::Clear for PutResp {
     fn clear(&mut self) {
         self.value.clear();
         self.err = RespErr::OK;
@@ -4053,36 +4271,40 @@ impl ::protobuf::Clear for DeleteResp {
     }
 }
 
-impl ::std::fmt::Debug for DeleteResp {
+impl ::std::fmt::Debug for PutResp {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for DeleteResp {
+impl ::protobuf::reflect::ProtobufValue for PutResp {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CommitReq {
+pub struct UpdateReq {
     // message fields
     pub client_id: u64,
     pub seq: u64,
+    pub doc_id: ::std::string::String,
+    pub set: ::std::string::String,
+    pub append: ::std::string::String,
+    pub remove: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CommitReq {
-    fn default() -> &'a CommitReq {
-        <CommitReq as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a UpdateReq {
+    fn default() -> &'a UpdateReq {
+        <UpdateReq as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CommitReq {
-    pub fn new() -> CommitReq {
+impl UpdateReq {
+    pub fn new() -> UpdateReq {
         ::std::default::Default::default()
     }
 
@@ -4115,9 +4337,113 @@ impl CommitReq {
     pub fn set_seq(&mut self, v: u64) {
         self.seq = v;
     }
+
+    // string doc_id = 3;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string set = 4;
+
+
+    pub fn get_set(&self) -> &str {
+        &self.set
+    }
+    pub fn clear_set(&mut self) {
+        self.set.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_set(&mut self, v: ::std::string::String) {
+        self.set = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_set(&mut self) -> &mut ::std::string::String {
+        &mut self.set
+    }
+
+    // Take field
+    pub fn take_set(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.set, ::std::string::String::new())
+    }
+
+    // string append = 5;
+
+
+    pub fn get_append(&self) -> &str {
+        &self.append
+    }
+    pub fn clear_append(&mut self) {
+        self.append.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_append(&mut self, v: ::std::string::String) {
+        self.append = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_append(&mut self) -> &mut ::std::string::String {
+        &mut self.append
+    }
+
+    // Take field
+    pub fn take_append(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.append, ::std::string::String::new())
+    }
+
+    // string remove = 6;
+
+
+    pub fn get_remove(&self) -> &str {
+        &self.remove
+    }
+    pub fn clear_remove(&mut self) {
+        self.remove.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remove(&mut self, v: ::std::string::String) {
+        self.remove = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_remove(&mut self) -> &mut ::std::string::String {
+        &mut self.remove
+    }
+
+    // Take field
+    pub fn take_remove(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.remove, ::std::string::String::new())
+    }
 }
 
-impl ::protobuf::Message for CommitReq {
+impl ::protobuf::Message for UpdateReq {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4140,6 +4466,18 @@ impl ::protobuf::Message for CommitReq {
                     let tmp = is.read_uint64()?;
                     self.seq = tmp;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.set)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.append)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.remove)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4158,6 +4496,18 @@ impl ::protobuf::Message for CommitReq {
         if self.seq != 0 {
             my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
         }
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.doc_id);
+        }
+        if !self.set.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.set);
+        }
+        if !self.append.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.append);
+        }
+        if !self.remove.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.remove);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4170,6 +4520,18 @@ impl ::protobuf::Message for CommitReq {
         if self.seq != 0 {
             os.write_uint64(2, self.seq)?;
         }
+        if !self.doc_id.is_empty() {
+            os.write_string(3, &self.doc_id)?;
+        }
+        if !self.set.is_empty() {
+            os.write_string(4, &self.set)?;
+        }
+        if !self.append.is_empty() {
+            os.write_string(5, &self.append)?;
+        }
+        if !self.remove.is_empty() {
+            os.write_string(6, &self.remove)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4200,8 +4562,8 @@ impl ::protobuf::Message for CommitReq {
         Self::descriptor_static()
     }
 
-    fn new() -> CommitReq {
-        CommitReq::new()
+    fn new() -> UpdateReq {
+        UpdateReq::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4214,16 +4576,36 @@ impl ::protobuf::Message for CommitReq {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "client_id",
-                    |m: &CommitReq| { &m.client_id },
-                    |m: &mut CommitReq| { &mut m.client_id },
+                    |m: &UpdateReq| { &m.client_id },
+                    |m: &mut UpdateReq| { &mut m.client_id },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "seq",
-                    |m: &CommitReq| { &m.seq },
-                    |m: &mut CommitReq| { &mut m.seq },
+                    |m: &UpdateReq| { &m.seq },
+                    |m: &mut UpdateReq| { &mut m.seq },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<CommitReq>(
-                    "CommitReq",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "doc_id",
+                    |m: &UpdateReq| { &m.doc_id },
+                    |m: &mut UpdateReq| { &mut m.doc_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "set",
+                    |m: &UpdateReq| { &m.set },
+                    |m: &mut UpdateReq| { &mut m.set },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "append",
+                    |m: &UpdateReq| { &m.append },
+                    |m: &mut UpdateReq| { &mut m.append },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "remove",
+                    |m: &UpdateReq| { &m.remove },
+                    |m: &mut UpdateReq| { &mut m.remove },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<UpdateReq>(
+                    "UpdateReq",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4231,39 +4613,43 @@ impl ::protobuf::Message for CommitReq {
         }
     }
 
-    fn default_instance() -> &'static CommitReq {
-        static mut instance: ::protobuf::lazy::Lazy<CommitReq> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static UpdateReq {
+        static mut instance: ::protobuf::lazy::Lazy<UpdateReq> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CommitReq,
+            ptr: 0 as *const UpdateReq,
         };
         unsafe {
-            instance.get(CommitReq::new)
+            instance.get(UpdateReq::new)
         }
     }
 }
 
-impl ::protobuf::Clear for CommitReq {
+impl ::protobuf::Clear for UpdateReq {
     fn clear(&mut self) {
         self.client_id = 0;
         self.seq = 0;
+        self.doc_id.clear();
+        self.set.clear();
+        self.append.clear();
+        self.remove.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CommitReq {
+impl ::std::fmt::Debug for UpdateReq {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CommitReq {
+impl ::protobuf::reflect::ProtobufValue for UpdateReq {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CommitResp {
+pub struct UpdateResp {
     // message fields
     pub value: ::std::string::String,
     pub err: RespErr,
@@ -4272,14 +4658,14 @@ pub struct CommitResp {
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CommitResp {
-    fn default() -> &'a CommitResp {
-        <CommitResp as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a UpdateResp {
+    fn default() -> &'a UpdateResp {
+        <UpdateResp as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CommitResp {
-    pub fn new() -> CommitResp {
+impl UpdateResp {
+    pub fn new() -> UpdateResp {
         ::std::default::Default::default()
     }
 
@@ -4325,7 +4711,7 @@ impl CommitResp {
     }
 }
 
-impl ::protobuf::Message for CommitResp {
+impl ::protobuf::Message for UpdateResp {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4400,8 +4786,8 @@ impl ::protobuf::Message for CommitResp {
         Self::descriptor_static()
     }
 
-    fn new() -> CommitResp {
-        CommitResp::new()
+    fn new() -> UpdateResp {
+        UpdateResp::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4414,16 +4800,16 @@ impl ::protobuf::Message for CommitResp {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "value",
-                    |m: &CommitResp| { &m.value },
-                    |m: &mut CommitResp| { &mut m.value },
+                    |m: &UpdateResp| { &m.value },
+                    |m: &mut UpdateResp| { &mut m.value },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
                     "err",
-                    |m: &CommitResp| { &m.err },
-                    |m: &mut CommitResp| { &mut m.err },
+                    |m: &UpdateResp| { &m.err },
+                    |m: &mut UpdateResp| { &mut m.err },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<CommitResp>(
-                    "CommitResp",
+                ::protobuf::reflect::MessageDescriptor::new::<UpdateResp>(
+                    "UpdateResp",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4431,18 +4817,18 @@ impl ::protobuf::Message for CommitResp {
         }
     }
 
-    fn default_instance() -> &'static CommitResp {
-        static mut instance: ::protobuf::lazy::Lazy<CommitResp> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static UpdateResp {
+        static mut instance: ::protobuf::lazy::Lazy<UpdateResp> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CommitResp,
+            ptr: 0 as *const UpdateResp,
         };
         unsafe {
-            instance.get(CommitResp::new)
+            instance.get(UpdateResp::new)
         }
     }
 }
 
-impl ::protobuf::Clear for CommitResp {
+impl ::protobuf::Clear for UpdateResp {
     fn clear(&mut self) {
         self.value.clear();
         self.err = RespErr::OK;
@@ -4450,36 +4836,38 @@ impl ::protobuf::Clear for CommitResp {
     }
 }
 
-impl ::std::fmt::Debug for CommitResp {
+impl ::std::fmt::Debug for UpdateResp {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CommitResp {
+impl ::protobuf::reflect::ProtobufValue for UpdateResp {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct RollbackReq {
+pub struct DeleteReq {
     // message fields
     pub client_id: u64,
     pub seq: u64,
+    pub doc_id: ::std::string::String,
+    pub if_version: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RollbackReq {
-    fn default() -> &'a RollbackReq {
-        <RollbackReq as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a DeleteReq {
+    fn default() -> &'a DeleteReq {
+        <DeleteReq as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RollbackReq {
-    pub fn new() -> RollbackReq {
+impl DeleteReq {
+    pub fn new() -> DeleteReq {
         ::std::default::Default::default()
     }
 
@@ -4512,9 +4900,50 @@ impl RollbackReq {
     pub fn set_seq(&mut self, v: u64) {
         self.seq = v;
     }
+
+    // string doc_id = 3;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // uint64 if_version = 4;
+
+
+    pub fn get_if_version(&self) -> u64 {
+        self.if_version
+    }
+    pub fn clear_if_version(&mut self) {
+        self.if_version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_if_version(&mut self, v: u64) {
+        self.if_version = v;
+    }
 }
 
-impl ::protobuf::Message for RollbackReq {
+impl ::protobuf::Message for DeleteReq {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4537,6 +4966,16 @@ impl ::protobuf::Message for RollbackReq {
                     let tmp = is.read_uint64()?;
                     self.seq = tmp;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.if_version = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4555,6 +4994,12 @@ impl ::protobuf::Message for RollbackReq {
         if self.seq != 0 {
             my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
         }
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.doc_id);
+        }
+        if self.if_version != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.if_version, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4567,6 +5012,12 @@ impl ::protobuf::Message for RollbackReq {
         if self.seq != 0 {
             os.write_uint64(2, self.seq)?;
         }
+        if !self.doc_id.is_empty() {
+            os.write_string(3, &self.doc_id)?;
+        }
+        if self.if_version != 0 {
+            os.write_uint64(4, self.if_version)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4597,8 +5048,8 @@ impl ::protobuf::Message for RollbackReq {
         Self::descriptor_static()
     }
 
-    fn new() -> RollbackReq {
-        RollbackReq::new()
+    fn new() -> DeleteReq {
+        DeleteReq::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4611,16 +5062,26 @@ impl ::protobuf::Message for RollbackReq {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "client_id",
-                    |m: &RollbackReq| { &m.client_id },
-                    |m: &mut RollbackReq| { &mut m.client_id },
+                    |m: &DeleteReq| { &m.client_id },
+                    |m: &mut DeleteReq| { &mut m.client_id },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "seq",
-                    |m: &RollbackReq| { &m.seq },
-                    |m: &mut RollbackReq| { &mut m.seq },
+                    |m: &DeleteReq| { &m.seq },
+                    |m: &mut DeleteReq| { &mut m.seq },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<RollbackReq>(
-                    "RollbackReq",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "doc_id",
+                    |m: &DeleteReq| { &m.doc_id },
+                    |m: &mut DeleteReq| { &mut m.doc_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "if_version",
+                    |m: &DeleteReq| { &m.if_version },
+                    |m: &mut DeleteReq| { &mut m.if_version },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DeleteReq>(
+                    "DeleteReq",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4628,39 +5089,41 @@ impl ::protobuf::Message for RollbackReq {
         }
     }
 
-    fn default_instance() -> &'static RollbackReq {
-        static mut instance: ::protobuf::lazy::Lazy<RollbackReq> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static DeleteReq {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteReq> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const RollbackReq,
+            ptr: 0 as *const DeleteReq,
         };
         unsafe {
-            instance.get(RollbackReq::new)
+            instance.get(DeleteReq::new)
         }
     }
 }
 
-impl ::protobuf::Clear for RollbackReq {
+impl ::protobuf::Clear for DeleteReq {
     fn clear(&mut self) {
         self.client_id = 0;
         self.seq = 0;
+        self.doc_id.clear();
+        self.if_version = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for RollbackReq {
+impl ::std::fmt::Debug for DeleteReq {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RollbackReq {
+impl ::protobuf::reflect::ProtobufValue for DeleteReq {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct RollbackResp {
+pub struct DeleteResp {
     // message fields
     pub value: ::std::string::String,
     pub err: RespErr,
@@ -4669,14 +5132,14 @@ pub struct RollbackResp {
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RollbackResp {
-    fn default() -> &'a RollbackResp {
-        <RollbackResp as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a DeleteResp {
+    fn default() -> &'a DeleteResp {
+        <DeleteResp as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RollbackResp {
-    pub fn new() -> RollbackResp {
+impl DeleteResp {
+    pub fn new() -> DeleteResp {
         ::std::default::Default::default()
     }
 
@@ -4722,7 +5185,7 @@ impl RollbackResp {
     }
 }
 
-impl ::protobuf::Message for RollbackResp {
+impl ::protobuf::Message for DeleteResp {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4797,8 +5260,8 @@ impl ::protobuf::Message for RollbackResp {
         Self::descriptor_static()
     }
 
-    fn new() -> RollbackResp {
-        RollbackResp::new()
+    fn new() -> DeleteResp {
+        DeleteResp::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4811,16 +5274,16 @@ impl ::protobuf::Message for RollbackResp {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "value",
-                    |m: &RollbackResp| { &m.value },
-                    |m: &mut RollbackResp| { &mut m.value },
+                    |m: &DeleteResp| { &m.value },
+                    |m: &mut DeleteResp| { &mut m.value },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
                     "err",
-                    |m: &RollbackResp| { &m.err },
-                    |m: &mut RollbackResp| { &mut m.err },
+                    |m: &DeleteResp| { &m.err },
+                    |m: &mut DeleteResp| { &mut m.err },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<RollbackResp>(
-                    "RollbackResp",
+                ::protobuf::reflect::MessageDescriptor::new::<DeleteResp>(
+                    "DeleteResp",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4828,18 +5291,18 @@ impl ::protobuf::Message for RollbackResp {
         }
     }
 
-    fn default_instance() -> &'static RollbackResp {
-        static mut instance: ::protobuf::lazy::Lazy<RollbackResp> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static DeleteResp {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteResp> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const RollbackResp,
+            ptr: 0 as *const DeleteResp,
         };
         unsafe {
-            instance.get(RollbackResp::new)
+            instance.get(DeleteResp::new)
         }
     }
 }
 
-impl ::protobuf::Clear for RollbackResp {
+impl ::protobuf::Clear for DeleteResp {
     fn clear(&mut self) {
         self.value.clear();
         self.err = RespErr::OK;
@@ -4847,36 +5310,37 @@ impl ::protobuf::Clear for RollbackResp {
     }
 }
 
-impl ::std::fmt::Debug for RollbackResp {
+impl ::std::fmt::Debug for DeleteResp {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for RollbackResp {
+impl ::protobuf::reflect::ProtobufValue for DeleteResp {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct MergeReq {
+pub struct DeleteByQueryReq {
     // message fields
     pub client_id: u64,
     pub seq: u64,
+    pub query: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MergeReq {
-    fn default() -> &'a MergeReq {
-        <MergeReq as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a DeleteByQueryReq {
+    fn default() -> &'a DeleteByQueryReq {
+        <DeleteByQueryReq as ::protobuf::Message>::default_instance()
     }
 }
 
-impl MergeReq {
-    pub fn new() -> MergeReq {
+impl DeleteByQueryReq {
+    pub fn new() -> DeleteByQueryReq {
         ::std::default::Default::default()
     }
 
@@ -4909,9 +5373,35 @@ impl MergeReq {
     pub fn set_seq(&mut self, v: u64) {
         self.seq = v;
     }
+
+    // string query = 3;
+
+
+    pub fn get_query(&self) -> &str {
+        &self.query
+    }
+    pub fn clear_query(&mut self) {
+        self.query.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query(&mut self, v: ::std::string::String) {
+        self.query = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_query(&mut self) -> &mut ::std::string::String {
+        &mut self.query
+    }
+
+    // Take field
+    pub fn take_query(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.query, ::std::string::String::new())
+    }
 }
 
-impl ::protobuf::Message for MergeReq {
+impl ::protobuf::Message for DeleteByQueryReq {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4934,6 +5424,9 @@ impl ::protobuf::Message for MergeReq {
                     let tmp = is.read_uint64()?;
                     self.seq = tmp;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.query)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4952,6 +5445,9 @@ impl ::protobuf::Message for MergeReq {
         if self.seq != 0 {
             my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
         }
+        if !self.query.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.query);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4964,6 +5460,9 @@ impl ::protobuf::Message for MergeReq {
         if self.seq != 0 {
             os.write_uint64(2, self.seq)?;
         }
+        if !self.query.is_empty() {
+            os.write_string(3, &self.query)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4994,8 +5493,8 @@ impl ::protobuf::Message for MergeReq {
         Self::descriptor_static()
     }
 
-    fn new() -> MergeReq {
-        MergeReq::new()
+    fn new() -> DeleteByQueryReq {
+        DeleteByQueryReq::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -5008,16 +5507,21 @@ impl ::protobuf::Message for MergeReq {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "client_id",
-                    |m: &MergeReq| { &m.client_id },
-                    |m: &mut MergeReq| { &mut m.client_id },
+                    |m: &DeleteByQueryReq| { &m.client_id },
+                    |m: &mut DeleteByQueryReq| { &mut m.client_id },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "seq",
-                    |m: &MergeReq| { &m.seq },
-                    |m: &mut MergeReq| { &mut m.seq },
+                    |m: &DeleteByQueryReq| { &m.seq },
+                    |m: &mut DeleteByQueryReq| { &mut m.seq },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<MergeReq>(
-                    "MergeReq",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "query",
+                    |m: &DeleteByQueryReq| { &m.query },
+                    |m: &mut DeleteByQueryReq| { &mut m.query },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DeleteByQueryReq>(
+                    "DeleteByQueryReq",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5025,39 +5529,40 @@ impl ::protobuf::Message for MergeReq {
         }
     }
 
-    fn default_instance() -> &'static MergeReq {
-        static mut instance: ::protobuf::lazy::Lazy<MergeReq> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static DeleteByQueryReq {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteByQueryReq> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const MergeReq,
+            ptr: 0 as *const DeleteByQueryReq,
         };
         unsafe {
-            instance.get(MergeReq::new)
+            instance.get(DeleteByQueryReq::new)
         }
     }
 }
 
-impl ::protobuf::Clear for MergeReq {
+impl ::protobuf::Clear for DeleteByQueryReq {
     fn clear(&mut self) {
         self.client_id = 0;
         self.seq = 0;
+        self.query.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for MergeReq {
+impl ::std::fmt::Debug for DeleteByQueryReq {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MergeReq {
+impl ::protobuf::reflect::ProtobufValue for DeleteByQueryReq {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct MergeResp {
+pub struct DeleteByQueryResp {
     // message fields
     pub value: ::std::string::String,
     pub err: RespErr,
@@ -5066,14 +5571,14 @@ pub struct MergeResp {
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MergeResp {
-    fn default() -> &'a MergeResp {
-        <MergeResp as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a DeleteByQueryResp {
+    fn default() -> &'a DeleteByQueryResp {
+        <DeleteByQueryResp as ::protobuf::Message>::default_instance()
     }
 }
 
-impl MergeResp {
-    pub fn new() -> MergeResp {
+impl DeleteByQueryResp {
+    pub fn new() -> DeleteByQueryResp {
         ::std::default::Default::default()
     }
 
@@ -5119,7 +5624,7 @@ impl MergeResp {
     }
 }
 
-impl ::protobuf::Message for MergeResp {
+impl ::protobuf::Message for DeleteByQueryResp {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5194,8 +5699,8 @@ impl ::protobuf::Message for MergeResp {
         Self::descriptor_static()
     }
 
-    fn new() -> MergeResp {
-        MergeResp::new()
+    fn new() -> DeleteByQueryResp {
+        DeleteByQueryResp::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -5208,16 +5713,2922 @@ impl ::protobuf::Message for MergeResp {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "value",
-                    |m: &MergeResp| { &m.value },
-                    |m: &mut MergeResp| { &mut m.value },
+                    |m: &DeleteByQueryResp| { &m.value },
+                    |m: &mut DeleteByQueryResp| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
+                    "err",
+                    |m: &DeleteByQueryResp| { &m.err },
+                    |m: &mut DeleteByQueryResp| { &mut m.err },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DeleteByQueryResp>(
+                    "DeleteByQueryResp",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static DeleteByQueryResp {
+        static mut instance: ::protobuf::lazy::Lazy<DeleteByQueryResp> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const DeleteByQueryResp,
+        };
+        unsafe {
+            instance.get(DeleteByQueryResp::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for DeleteByQueryResp {
+    fn clear(&mut self) {
+        self.value.clear();
+        self.err = RespErr::OK;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DeleteByQueryResp {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DeleteByQueryResp {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CommitReq {
+    // message fields
+    pub client_id: u64,
+    pub seq: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CommitReq {
+    fn default() -> &'a CommitReq {
+        <CommitReq as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommitReq {
+    pub fn new() -> CommitReq {
+        ::std::default::Default::default()
+    }
+
+    // uint64 client_id = 1;
+
+
+    pub fn get_client_id(&self) -> u64 {
+        self.client_id
+    }
+    pub fn clear_client_id(&mut self) {
+        self.client_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client_id(&mut self, v: u64) {
+        self.client_id = v;
+    }
+
+    // uint64 seq = 2;
+
+
+    pub fn get_seq(&self) -> u64 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: u64) {
+        self.seq = v;
+    }
+}
+
+impl ::protobuf::Message for CommitReq {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.client_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.seq = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.client_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.client_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.client_id != 0 {
+            os.write_uint64(1, self.client_id)?;
+        }
+        if self.seq != 0 {
+            os.write_uint64(2, self.seq)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CommitReq {
+        CommitReq::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "client_id",
+                    |m: &CommitReq| { &m.client_id },
+                    |m: &mut CommitReq| { &mut m.client_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "seq",
+                    |m: &CommitReq| { &m.seq },
+                    |m: &mut CommitReq| { &mut m.seq },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CommitReq>(
+                    "CommitReq",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CommitReq {
+        static mut instance: ::protobuf::lazy::Lazy<CommitReq> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CommitReq,
+        };
+        unsafe {
+            instance.get(CommitReq::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CommitReq {
+    fn clear(&mut self) {
+        self.client_id = 0;
+        self.seq = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CommitReq {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommitReq {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CommitResp {
+    // message fields
+    pub value: ::std::string::String,
+    pub err: RespErr,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CommitResp {
+    fn default() -> &'a CommitResp {
+        <CommitResp as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CommitResp {
+    pub fn new() -> CommitResp {
+        ::std::default::Default::default()
+    }
+
+    // string value = 1;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    // .indexrpcpb.RespErr err = 2;
+
+
+    pub fn get_err(&self) -> RespErr {
+        self.err
+    }
+    pub fn clear_err(&mut self) {
+        self.err = RespErr::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err(&mut self, v: RespErr) {
+        self.err = v;
+    }
+}
+
+impl ::protobuf::Message for CommitResp {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.err, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.value);
+        }
+        if self.err != RespErr::OK {
+            my_size += ::protobuf::rt::enum_size(2, self.err);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.value.is_empty() {
+            os.write_string(1, &self.value)?;
+        }
+        if self.err != RespErr::OK {
+            os.write_enum(2, self.err.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CommitResp {
+        CommitResp::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &CommitResp| { &m.value },
+                    |m: &mut CommitResp| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
+                    "err",
+                    |m: &CommitResp| { &m.err },
+                    |m: &mut CommitResp| { &mut m.err },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CommitResp>(
+                    "CommitResp",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CommitResp {
+        static mut instance: ::protobuf::lazy::Lazy<CommitResp> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CommitResp,
+        };
+        unsafe {
+            instance.get(CommitResp::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CommitResp {
+    fn clear(&mut self) {
+        self.value.clear();
+        self.err = RespErr::OK;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CommitResp {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CommitResp {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RollbackReq {
+    // message fields
+    pub client_id: u64,
+    pub seq: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RollbackReq {
+    fn default() -> &'a RollbackReq {
+        <RollbackReq as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RollbackReq {
+    pub fn new() -> RollbackReq {
+        ::std::default::Default::default()
+    }
+
+    // uint64 client_id = 1;
+
+
+    pub fn get_client_id(&self) -> u64 {
+        self.client_id
+    }
+    pub fn clear_client_id(&mut self) {
+        self.client_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client_id(&mut self, v: u64) {
+        self.client_id = v;
+    }
+
+    // uint64 seq = 2;
+
+
+    pub fn get_seq(&self) -> u64 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: u64) {
+        self.seq = v;
+    }
+}
+
+impl ::protobuf::Message for RollbackReq {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.client_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.seq = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.client_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.client_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.client_id != 0 {
+            os.write_uint64(1, self.client_id)?;
+        }
+        if self.seq != 0 {
+            os.write_uint64(2, self.seq)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RollbackReq {
+        RollbackReq::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "client_id",
+                    |m: &RollbackReq| { &m.client_id },
+                    |m: &mut RollbackReq| { &mut m.client_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "seq",
+                    |m: &RollbackReq| { &m.seq },
+                    |m: &mut RollbackReq| { &mut m.seq },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RollbackReq>(
+                    "RollbackReq",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RollbackReq {
+        static mut instance: ::protobuf::lazy::Lazy<RollbackReq> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RollbackReq,
+        };
+        unsafe {
+            instance.get(RollbackReq::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RollbackReq {
+    fn clear(&mut self) {
+        self.client_id = 0;
+        self.seq = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RollbackReq {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RollbackReq {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RollbackResp {
+    // message fields
+    pub value: ::std::string::String,
+    pub err: RespErr,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RollbackResp {
+    fn default() -> &'a RollbackResp {
+        <RollbackResp as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RollbackResp {
+    pub fn new() -> RollbackResp {
+        ::std::default::Default::default()
+    }
+
+    // string value = 1;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    // .indexrpcpb.RespErr err = 2;
+
+
+    pub fn get_err(&self) -> RespErr {
+        self.err
+    }
+    pub fn clear_err(&mut self) {
+        self.err = RespErr::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err(&mut self, v: RespErr) {
+        self.err = v;
+    }
+}
+
+impl ::protobuf::Message for RollbackResp {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.err, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.value);
+        }
+        if self.err != RespErr::OK {
+            my_size += ::protobuf::rt::enum_size(2, self.err);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.value.is_empty() {
+            os.write_string(1, &self.value)?;
+        }
+        if self.err != RespErr::OK {
+            os.write_enum(2, self.err.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RollbackResp {
+        RollbackResp::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &RollbackResp| { &m.value },
+                    |m: &mut RollbackResp| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
+                    "err",
+                    |m: &RollbackResp| { &m.err },
+                    |m: &mut RollbackResp| { &mut m.err },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RollbackResp>(
+                    "RollbackResp",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static RollbackResp {
+        static mut instance: ::protobuf::lazy::Lazy<RollbackResp> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const RollbackResp,
+        };
+        unsafe {
+            instance.get(RollbackResp::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for RollbackResp {
+    fn clear(&mut self) {
+        self.value.clear();
+        self.err = RespErr::OK;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RollbackResp {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RollbackResp {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MergeReq {
+    // message fields
+    pub client_id: u64,
+    pub seq: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MergeReq {
+    fn default() -> &'a MergeReq {
+        <MergeReq as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MergeReq {
+    pub fn new() -> MergeReq {
+        ::std::default::Default::default()
+    }
+
+    // uint64 client_id = 1;
+
+
+    pub fn get_client_id(&self) -> u64 {
+        self.client_id
+    }
+    pub fn clear_client_id(&mut self) {
+        self.client_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client_id(&mut self, v: u64) {
+        self.client_id = v;
+    }
+
+    // uint64 seq = 2;
+
+
+    pub fn get_seq(&self) -> u64 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: u64) {
+        self.seq = v;
+    }
+}
+
+impl ::protobuf::Message for MergeReq {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.client_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.seq = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.client_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.client_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.client_id != 0 {
+            os.write_uint64(1, self.client_id)?;
+        }
+        if self.seq != 0 {
+            os.write_uint64(2, self.seq)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MergeReq {
+        MergeReq::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "client_id",
+                    |m: &MergeReq| { &m.client_id },
+                    |m: &mut MergeReq| { &mut m.client_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "seq",
+                    |m: &MergeReq| { &m.seq },
+                    |m: &mut MergeReq| { &mut m.seq },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MergeReq>(
+                    "MergeReq",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static MergeReq {
+        static mut instance: ::protobuf::lazy::Lazy<MergeReq> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MergeReq,
+        };
+        unsafe {
+            instance.get(MergeReq::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for MergeReq {
+    fn clear(&mut self) {
+        self.client_id = 0;
+        self.seq = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MergeReq {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MergeReq {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MergeResp {
+    // message fields
+    pub value: ::std::string::String,
+    pub err: RespErr,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MergeResp {
+    fn default() -> &'a MergeResp {
+        <MergeResp as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MergeResp {
+    pub fn new() -> MergeResp {
+        ::std::default::Default::default()
+    }
+
+    // string value = 1;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    // .indexrpcpb.RespErr err = 2;
+
+
+    pub fn get_err(&self) -> RespErr {
+        self.err
+    }
+    pub fn clear_err(&mut self) {
+        self.err = RespErr::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err(&mut self, v: RespErr) {
+        self.err = v;
+    }
+}
+
+impl ::protobuf::Message for MergeResp {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.err, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.value);
+        }
+        if self.err != RespErr::OK {
+            my_size += ::protobuf::rt::enum_size(2, self.err);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.value.is_empty() {
+            os.write_string(1, &self.value)?;
+        }
+        if self.err != RespErr::OK {
+            os.write_enum(2, self.err.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MergeResp {
+        MergeResp::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &MergeResp| { &m.value },
+                    |m: &mut MergeResp| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
+                    "err",
+                    |m: &MergeResp| { &m.err },
+                    |m: &mut MergeResp| { &mut m.err },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<MergeResp>(
+                    "MergeResp",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static MergeResp {
+        static mut instance: ::protobuf::lazy::Lazy<MergeResp> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const MergeResp,
+        };
+        unsafe {
+            instance.get(MergeResp::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for MergeResp {
+    fn clear(&mut self) {
+        self.value.clear();
+        self.err = RespErr::OK;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MergeResp {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MergeResp {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SearchReq {
+    // message fields
+    pub client_id: u64,
+    pub seq: u64,
+    pub query: ::std::string::String,
+    pub from: u64,
+    pub limit: u64,
+    pub exclude_count: bool,
+    pub exclude_docs: bool,
+    pub facet_field: ::std::string::String,
+    pub facet_prefixes: ::protobuf::RepeatedField<::std::string::String>,
+    pub facets: ::protobuf::RepeatedField<::std::string::String>,
+    pub post_filter: ::std::string::String,
+    pub more_like_this_doc_id: ::std::string::String,
+    pub more_like_this_text: ::std::string::String,
+    pub did_you_mean: bool,
+    pub geo_field: ::std::string::String,
+    pub geo_origin: ::std::string::String,
+    pub geo_distance: ::std::string::String,
+    pub geo_bounding_box: ::std::string::String,
+    pub geo_sort: bool,
+    pub date_field: ::std::string::String,
+    pub date_from: ::std::string::String,
+    pub date_to: ::std::string::String,
+    pub time_zone: ::std::string::String,
+    pub function_score: ::std::string::String,
+    pub collapse_field: ::std::string::String,
+    pub timeout: u64,
+    pub terminate_after: u64,
+    pub sort: ::protobuf::RepeatedField<::std::string::String>,
+    pub facet_limits: ::protobuf::RepeatedField<::std::string::String>,
+    pub facet_sorts: ::protobuf::RepeatedField<::std::string::String>,
+    pub aggregations: ::std::string::String,
+    pub highlight: ::std::string::String,
+    pub query_dsl: ::std::string::String,
+    pub typo_tolerance: bool,
+    pub default_fields: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SearchReq {
+    fn default() -> &'a SearchReq {
+        <SearchReq as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchReq {
+    pub fn new() -> SearchReq {
+        ::std::default::Default::default()
+    }
+
+    // uint64 client_id = 1;
+
+
+    pub fn get_client_id(&self) -> u64 {
+        self.client_id
+    }
+    pub fn clear_client_id(&mut self) {
+        self.client_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client_id(&mut self, v: u64) {
+        self.client_id = v;
+    }
+
+    // uint64 seq = 2;
+
+
+    pub fn get_seq(&self) -> u64 {
+        self.seq
+    }
+    pub fn clear_seq(&mut self) {
+        self.seq = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: u64) {
+        self.seq = v;
+    }
+
+    // string query = 3;
+
+
+    pub fn get_query(&self) -> &str {
+        &self.query
+    }
+    pub fn clear_query(&mut self) {
+        self.query.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query(&mut self, v: ::std::string::String) {
+        self.query = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_query(&mut self) -> &mut ::std::string::String {
+        &mut self.query
+    }
+
+    // Take field
+    pub fn take_query(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.query, ::std::string::String::new())
+    }
+
+    // uint64 from = 4;
+
+
+    pub fn get_from(&self) -> u64 {
+        self.from
+    }
+    pub fn clear_from(&mut self) {
+        self.from = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from(&mut self, v: u64) {
+        self.from = v;
+    }
+
+    // uint64 limit = 5;
+
+
+    pub fn get_limit(&self) -> u64 {
+        self.limit
+    }
+    pub fn clear_limit(&mut self) {
+        self.limit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_limit(&mut self, v: u64) {
+        self.limit = v;
+    }
+
+    // bool exclude_count = 6;
+
+
+    pub fn get_exclude_count(&self) -> bool {
+        self.exclude_count
+    }
+    pub fn clear_exclude_count(&mut self) {
+        self.exclude_count = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exclude_count(&mut self, v: bool) {
+        self.exclude_count = v;
+    }
+
+    // bool exclude_docs = 7;
+
+
+    pub fn get_exclude_docs(&self) -> bool {
+        self.exclude_docs
+    }
+    pub fn clear_exclude_docs(&mut self) {
+        self.exclude_docs = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exclude_docs(&mut self, v: bool) {
+        self.exclude_docs = v;
+    }
+
+    // string facet_field = 8;
+
+
+    pub fn get_facet_field(&self) -> &str {
+        &self.facet_field
+    }
+    pub fn clear_facet_field(&mut self) {
+        self.facet_field.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_facet_field(&mut self, v: ::std::string::String) {
+        self.facet_field = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_facet_field(&mut self) -> &mut ::std::string::String {
+        &mut self.facet_field
+    }
+
+    // Take field
+    pub fn take_facet_field(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.facet_field, ::std::string::String::new())
+    }
+
+    // repeated string facet_prefixes = 9;
+
+
+    pub fn get_facet_prefixes(&self) -> &[::std::string::String] {
+        &self.facet_prefixes
+    }
+    pub fn clear_facet_prefixes(&mut self) {
+        self.facet_prefixes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_facet_prefixes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.facet_prefixes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_facet_prefixes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.facet_prefixes
+    }
+
+    // Take field
+    pub fn take_facet_prefixes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.facet_prefixes, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string facets = 10;
+
+
+    pub fn get_facets(&self) -> &[::std::string::String] {
+        &self.facets
+    }
+    pub fn clear_facets(&mut self) {
+        self.facets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_facets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.facets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_facets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.facets
+    }
+
+    // Take field
+    pub fn take_facets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.facets, ::protobuf::RepeatedField::new())
+    }
+
+    // string post_filter = 11;
+
+
+    pub fn get_post_filter(&self) -> &str {
+        &self.post_filter
+    }
+    pub fn clear_post_filter(&mut self) {
+        self.post_filter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_post_filter(&mut self, v: ::std::string::String) {
+        self.post_filter = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_post_filter(&mut self) -> &mut ::std::string::String {
+        &mut self.post_filter
+    }
+
+    // Take field
+    pub fn take_post_filter(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.post_filter, ::std::string::String::new())
+    }
+
+    // string more_like_this_doc_id = 12;
+
+
+    pub fn get_more_like_this_doc_id(&self) -> &str {
+        &self.more_like_this_doc_id
+    }
+    pub fn clear_more_like_this_doc_id(&mut self) {
+        self.more_like_this_doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_more_like_this_doc_id(&mut self, v: ::std::string::String) {
+        self.more_like_this_doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_more_like_this_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.more_like_this_doc_id
+    }
+
+    // Take field
+    pub fn take_more_like_this_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.more_like_this_doc_id, ::std::string::String::new())
+    }
+
+    // string more_like_this_text = 13;
+
+
+    pub fn get_more_like_this_text(&self) -> &str {
+        &self.more_like_this_text
+    }
+    pub fn clear_more_like_this_text(&mut self) {
+        self.more_like_this_text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_more_like_this_text(&mut self, v: ::std::string::String) {
+        self.more_like_this_text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_more_like_this_text(&mut self) -> &mut ::std::string::String {
+        &mut self.more_like_this_text
+    }
+
+    // Take field
+    pub fn take_more_like_this_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.more_like_this_text, ::std::string::String::new())
+    }
+
+    // bool did_you_mean = 14;
+
+
+    pub fn get_did_you_mean(&self) -> bool {
+        self.did_you_mean
+    }
+    pub fn clear_did_you_mean(&mut self) {
+        self.did_you_mean = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_did_you_mean(&mut self, v: bool) {
+        self.did_you_mean = v;
+    }
+
+    // string geo_field = 15;
+
+
+    pub fn get_geo_field(&self) -> &str {
+        &self.geo_field
+    }
+    pub fn clear_geo_field(&mut self) {
+        self.geo_field.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_geo_field(&mut self, v: ::std::string::String) {
+        self.geo_field = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_geo_field(&mut self) -> &mut ::std::string::String {
+        &mut self.geo_field
+    }
+
+    // Take field
+    pub fn take_geo_field(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.geo_field, ::std::string::String::new())
+    }
+
+    // string geo_origin = 16;
+
+
+    pub fn get_geo_origin(&self) -> &str {
+        &self.geo_origin
+    }
+    pub fn clear_geo_origin(&mut self) {
+        self.geo_origin.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_geo_origin(&mut self, v: ::std::string::String) {
+        self.geo_origin = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_geo_origin(&mut self) -> &mut ::std::string::String {
+        &mut self.geo_origin
+    }
+
+    // Take field
+    pub fn take_geo_origin(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.geo_origin, ::std::string::String::new())
+    }
+
+    // string geo_distance = 17;
+
+
+    pub fn get_geo_distance(&self) -> &str {
+        &self.geo_distance
+    }
+    pub fn clear_geo_distance(&mut self) {
+        self.geo_distance.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_geo_distance(&mut self, v: ::std::string::String) {
+        self.geo_distance = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_geo_distance(&mut self) -> &mut ::std::string::String {
+        &mut self.geo_distance
+    }
+
+    // Take field
+    pub fn take_geo_distance(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.geo_distance, ::std::string::String::new())
+    }
+
+    // string geo_bounding_box = 18;
+
+
+    pub fn get_geo_bounding_box(&self) -> &str {
+        &self.geo_bounding_box
+    }
+    pub fn clear_geo_bounding_box(&mut self) {
+        self.geo_bounding_box.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_geo_bounding_box(&mut self, v: ::std::string::String) {
+        self.geo_bounding_box = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_geo_bounding_box(&mut self) -> &mut ::std::string::String {
+        &mut self.geo_bounding_box
+    }
+
+    // Take field
+    pub fn take_geo_bounding_box(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.geo_bounding_box, ::std::string::String::new())
+    }
+
+    // bool geo_sort = 19;
+
+
+    pub fn get_geo_sort(&self) -> bool {
+        self.geo_sort
+    }
+    pub fn clear_geo_sort(&mut self) {
+        self.geo_sort = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_geo_sort(&mut self, v: bool) {
+        self.geo_sort = v;
+    }
+
+    // string date_field = 20;
+
+
+    pub fn get_date_field(&self) -> &str {
+        &self.date_field
+    }
+    pub fn clear_date_field(&mut self) {
+        self.date_field.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date_field(&mut self, v: ::std::string::String) {
+        self.date_field = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_date_field(&mut self) -> &mut ::std::string::String {
+        &mut self.date_field
+    }
+
+    // Take field
+    pub fn take_date_field(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.date_field, ::std::string::String::new())
+    }
+
+    // string date_from = 21;
+
+
+    pub fn get_date_from(&self) -> &str {
+        &self.date_from
+    }
+    pub fn clear_date_from(&mut self) {
+        self.date_from.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date_from(&mut self, v: ::std::string::String) {
+        self.date_from = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_date_from(&mut self) -> &mut ::std::string::String {
+        &mut self.date_from
+    }
+
+    // Take field
+    pub fn take_date_from(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.date_from, ::std::string::String::new())
+    }
+
+    // string date_to = 22;
+
+
+    pub fn get_date_to(&self) -> &str {
+        &self.date_to
+    }
+    pub fn clear_date_to(&mut self) {
+        self.date_to.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_date_to(&mut self, v: ::std::string::String) {
+        self.date_to = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_date_to(&mut self) -> &mut ::std::string::String {
+        &mut self.date_to
+    }
+
+    // Take field
+    pub fn take_date_to(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.date_to, ::std::string::String::new())
+    }
+
+    // string time_zone = 23;
+
+
+    pub fn get_time_zone(&self) -> &str {
+        &self.time_zone
+    }
+    pub fn clear_time_zone(&mut self) {
+        self.time_zone.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_time_zone(&mut self, v: ::std::string::String) {
+        self.time_zone = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_time_zone(&mut self) -> &mut ::std::string::String {
+        &mut self.time_zone
+    }
+
+    // Take field
+    pub fn take_time_zone(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.time_zone, ::std::string::String::new())
+    }
+
+    // string function_score = 24;
+
+
+    pub fn get_function_score(&self) -> &str {
+        &self.function_score
+    }
+    pub fn clear_function_score(&mut self) {
+        self.function_score.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_function_score(&mut self, v: ::std::string::String) {
+        self.function_score = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_function_score(&mut self) -> &mut ::std::string::String {
+        &mut self.function_score
+    }
+
+    // Take field
+    pub fn take_function_score(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.function_score, ::std::string::String::new())
+    }
+
+    // string collapse_field = 25;
+
+
+    pub fn get_collapse_field(&self) -> &str {
+        &self.collapse_field
+    }
+    pub fn clear_collapse_field(&mut self) {
+        self.collapse_field.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_collapse_field(&mut self, v: ::std::string::String) {
+        self.collapse_field = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_collapse_field(&mut self) -> &mut ::std::string::String {
+        &mut self.collapse_field
+    }
+
+    // Take field
+    pub fn take_collapse_field(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.collapse_field, ::std::string::String::new())
+    }
+
+    // uint64 timeout = 26;
+
+
+    pub fn get_timeout(&self) -> u64 {
+        self.timeout
+    }
+    pub fn clear_timeout(&mut self) {
+        self.timeout = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timeout(&mut self, v: u64) {
+        self.timeout = v;
+    }
+
+    // uint64 terminate_after = 27;
+
+
+    pub fn get_terminate_after(&self) -> u64 {
+        self.terminate_after
+    }
+    pub fn clear_terminate_after(&mut self) {
+        self.terminate_after = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_terminate_after(&mut self, v: u64) {
+        self.terminate_after = v;
+    }
+
+    // repeated string sort = 28;
+
+
+    pub fn get_sort(&self) -> &[::std::string::String] {
+        &self.sort
+    }
+    pub fn clear_sort(&mut self) {
+        self.sort.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sort(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.sort = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sort(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.sort
+    }
+
+    // Take field
+    pub fn take_sort(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.sort, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string facet_limits = 29;
+
+
+    pub fn get_facet_limits(&self) -> &[::std::string::String] {
+        &self.facet_limits
+    }
+    pub fn clear_facet_limits(&mut self) {
+        self.facet_limits.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_facet_limits(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.facet_limits = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_facet_limits(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.facet_limits
+    }
+
+    // Take field
+    pub fn take_facet_limits(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.facet_limits, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string facet_sorts = 30;
+
+
+    pub fn get_facet_sorts(&self) -> &[::std::string::String] {
+        &self.facet_sorts
+    }
+    pub fn clear_facet_sorts(&mut self) {
+        self.facet_sorts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_facet_sorts(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.facet_sorts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_facet_sorts(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.facet_sorts
+    }
+
+    // Take field
+    pub fn take_facet_sorts(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.facet_sorts, ::protobuf::RepeatedField::new())
+    }
+
+    // string aggregations = 31;
+
+
+    pub fn get_aggregations(&self) -> &str {
+        &self.aggregations
+    }
+    pub fn clear_aggregations(&mut self) {
+        self.aggregations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_aggregations(&mut self, v: ::std::string::String) {
+        self.aggregations = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_aggregations(&mut self) -> &mut ::std::string::String {
+        &mut self.aggregations
+    }
+
+    // Take field
+    pub fn take_aggregations(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.aggregations, ::std::string::String::new())
+    }
+
+    // string highlight = 32;
+
+
+    pub fn get_highlight(&self) -> &str {
+        &self.highlight
+    }
+    pub fn clear_highlight(&mut self) {
+        self.highlight.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_highlight(&mut self, v: ::std::string::String) {
+        self.highlight = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_highlight(&mut self) -> &mut ::std::string::String {
+        &mut self.highlight
+    }
+
+    // Take field
+    pub fn take_highlight(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.highlight, ::std::string::String::new())
+    }
+
+    // string query_dsl = 33;
+
+
+    pub fn get_query_dsl(&self) -> &str {
+        &self.query_dsl
+    }
+    pub fn clear_query_dsl(&mut self) {
+        self.query_dsl.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query_dsl(&mut self, v: ::std::string::String) {
+        self.query_dsl = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_query_dsl(&mut self) -> &mut ::std::string::String {
+        &mut self.query_dsl
+    }
+
+    // Take field
+    pub fn take_query_dsl(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.query_dsl, ::std::string::String::new())
+    }
+
+    // bool typo_tolerance = 34;
+
+
+    pub fn get_typo_tolerance(&self) -> bool {
+        self.typo_tolerance
+    }
+    pub fn clear_typo_tolerance(&mut self) {
+        self.typo_tolerance = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_typo_tolerance(&mut self, v: bool) {
+        self.typo_tolerance = v;
+    }
+
+    // string default_fields = 35;
+
+
+    pub fn get_default_fields(&self) -> &str {
+        &self.default_fields
+    }
+    pub fn clear_default_fields(&mut self) {
+        self.default_fields.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_default_fields(&mut self, v: ::std::string::String) {
+        self.default_fields = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_default_fields(&mut self) -> &mut ::std::string::String {
+        &mut self.default_fields
+    }
+
+    // Take field
+    pub fn take_default_fields(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.default_fields, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for SearchReq {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.client_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.seq = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.query)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.from = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.limit = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exclude_count = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.exclude_docs = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.facet_field)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.facet_prefixes)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.facets)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.post_filter)?;
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.more_like_this_doc_id)?;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.more_like_this_text)?;
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.did_you_mean = tmp;
+                },
+                15 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.geo_field)?;
+                },
+                16 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.geo_origin)?;
+                },
+                17 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.geo_distance)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.geo_bounding_box)?;
+                },
+                19 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.geo_sort = tmp;
+                },
+                20 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.date_field)?;
+                },
+                21 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.date_from)?;
+                },
+                22 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.date_to)?;
+                },
+                23 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.time_zone)?;
+                },
+                24 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.function_score)?;
+                },
+                25 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.collapse_field)?;
+                },
+                26 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.timeout = tmp;
+                },
+                27 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.terminate_after = tmp;
+                },
+                28 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.sort)?;
+                },
+                29 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.facet_limits)?;
+                },
+                30 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.facet_sorts)?;
+                },
+                31 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.aggregations)?;
+                },
+                32 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.highlight)?;
+                },
+                33 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.query_dsl)?;
+                },
+                34 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.typo_tolerance = tmp;
+                },
+                35 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.default_fields)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.client_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.client_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.query.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.query);
+        }
+        if self.from != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.from, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.limit != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.limit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.exclude_count != false {
+            my_size += 2;
+        }
+        if self.exclude_docs != false {
+            my_size += 2;
+        }
+        if !self.facet_field.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.facet_field);
+        }
+        for value in &self.facet_prefixes {
+            my_size += ::protobuf::rt::string_size(9, &value);
+        };
+        for value in &self.facets {
+            my_size += ::protobuf::rt::string_size(10, &value);
+        };
+        if !self.post_filter.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.post_filter);
+        }
+        if !self.more_like_this_doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(12, &self.more_like_this_doc_id);
+        }
+        if !self.more_like_this_text.is_empty() {
+            my_size += ::protobuf::rt::string_size(13, &self.more_like_this_text);
+        }
+        if self.did_you_mean != false {
+            my_size += 2;
+        }
+        if !self.geo_field.is_empty() {
+            my_size += ::protobuf::rt::string_size(15, &self.geo_field);
+        }
+        if !self.geo_origin.is_empty() {
+            my_size += ::protobuf::rt::string_size(16, &self.geo_origin);
+        }
+        if !self.geo_distance.is_empty() {
+            my_size += ::protobuf::rt::string_size(17, &self.geo_distance);
+        }
+        if !self.geo_bounding_box.is_empty() {
+            my_size += ::protobuf::rt::string_size(18, &self.geo_bounding_box);
+        }
+        if self.geo_sort != false {
+            my_size += 3;
+        }
+        if !self.date_field.is_empty() {
+            my_size += ::protobuf::rt::string_size(20, &self.date_field);
+        }
+        if !self.date_from.is_empty() {
+            my_size += ::protobuf::rt::string_size(21, &self.date_from);
+        }
+        if !self.date_to.is_empty() {
+            my_size += ::protobuf::rt::string_size(22, &self.date_to);
+        }
+        if !self.time_zone.is_empty() {
+            my_size += ::protobuf::rt::string_size(23, &self.time_zone);
+        }
+        if !self.function_score.is_empty() {
+            my_size += ::protobuf::rt::string_size(24, &self.function_score);
+        }
+        if !self.collapse_field.is_empty() {
+            my_size += ::protobuf::rt::string_size(25, &self.collapse_field);
+        }
+        if self.timeout != 0 {
+            my_size += ::protobuf::rt::value_size(26, self.timeout, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.terminate_after != 0 {
+            my_size += ::protobuf::rt::value_size(27, self.terminate_after, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.sort {
+            my_size += ::protobuf::rt::string_size(28, &value);
+        };
+        for value in &self.facet_limits {
+            my_size += ::protobuf::rt::string_size(29, &value);
+        };
+        for value in &self.facet_sorts {
+            my_size += ::protobuf::rt::string_size(30, &value);
+        };
+        if !self.aggregations.is_empty() {
+            my_size += ::protobuf::rt::string_size(31, &self.aggregations);
+        }
+        if !self.highlight.is_empty() {
+            my_size += ::protobuf::rt::string_size(32, &self.highlight);
+        }
+        if !self.query_dsl.is_empty() {
+            my_size += ::protobuf::rt::string_size(33, &self.query_dsl);
+        }
+        if self.typo_tolerance != false {
+            my_size += 3;
+        }
+        if !self.default_fields.is_empty() {
+            my_size += ::protobuf::rt::string_size(35, &self.default_fields);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.client_id != 0 {
+            os.write_uint64(1, self.client_id)?;
+        }
+        if self.seq != 0 {
+            os.write_uint64(2, self.seq)?;
+        }
+        if !self.query.is_empty() {
+            os.write_string(3, &self.query)?;
+        }
+        if self.from != 0 {
+            os.write_uint64(4, self.from)?;
+        }
+        if self.limit != 0 {
+            os.write_uint64(5, self.limit)?;
+        }
+        if self.exclude_count != false {
+            os.write_bool(6, self.exclude_count)?;
+        }
+        if self.exclude_docs != false {
+            os.write_bool(7, self.exclude_docs)?;
+        }
+        if !self.facet_field.is_empty() {
+            os.write_string(8, &self.facet_field)?;
+        }
+        for v in &self.facet_prefixes {
+            os.write_string(9, &v)?;
+        };
+        for v in &self.facets {
+            os.write_string(10, &v)?;
+        };
+        if !self.post_filter.is_empty() {
+            os.write_string(11, &self.post_filter)?;
+        }
+        if !self.more_like_this_doc_id.is_empty() {
+            os.write_string(12, &self.more_like_this_doc_id)?;
+        }
+        if !self.more_like_this_text.is_empty() {
+            os.write_string(13, &self.more_like_this_text)?;
+        }
+        if self.did_you_mean != false {
+            os.write_bool(14, self.did_you_mean)?;
+        }
+        if !self.geo_field.is_empty() {
+            os.write_string(15, &self.geo_field)?;
+        }
+        if !self.geo_origin.is_empty() {
+            os.write_string(16, &self.geo_origin)?;
+        }
+        if !self.geo_distance.is_empty() {
+            os.write_string(17, &self.geo_distance)?;
+        }
+        if !self.geo_bounding_box.is_empty() {
+            os.write_string(18, &self.geo_bounding_box)?;
+        }
+        if self.geo_sort != false {
+            os.write_bool(19, self.geo_sort)?;
+        }
+        if !self.date_field.is_empty() {
+            os.write_string(20, &self.date_field)?;
+        }
+        if !self.date_from.is_empty() {
+            os.write_string(21, &self.date_from)?;
+        }
+        if !self.date_to.is_empty() {
+            os.write_string(22, &self.date_to)?;
+        }
+        if !self.time_zone.is_empty() {
+            os.write_string(23, &self.time_zone)?;
+        }
+        if !self.function_score.is_empty() {
+            os.write_string(24, &self.function_score)?;
+        }
+        if !self.collapse_field.is_empty() {
+            os.write_string(25, &self.collapse_field)?;
+        }
+        if self.timeout != 0 {
+            os.write_uint64(26, self.timeout)?;
+        }
+        if self.terminate_after != 0 {
+            os.write_uint64(27, self.terminate_after)?;
+        }
+        for v in &self.sort {
+            os.write_string(28, &v)?;
+        };
+        for v in &self.facet_limits {
+            os.write_string(29, &v)?;
+        };
+        for v in &self.facet_sorts {
+            os.write_string(30, &v)?;
+        };
+        if !self.aggregations.is_empty() {
+            os.write_string(31, &self.aggregations)?;
+        }
+        if !self.highlight.is_empty() {
+            os.write_string(32, &self.highlight)?;
+        }
+        if !self.query_dsl.is_empty() {
+            os.write_string(33, &self.query_dsl)?;
+        }
+        if self.typo_tolerance != false {
+            os.write_bool(34, self.typo_tolerance)?;
+        }
+        if !self.default_fields.is_empty() {
+            os.write_string(35, &self.default_fields)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SearchReq {
+        SearchReq::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "client_id",
+                    |m: &SearchReq| { &m.client_id },
+                    |m: &mut SearchReq| { &mut m.client_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "seq",
+                    |m: &SearchReq| { &m.seq },
+                    |m: &mut SearchReq| { &mut m.seq },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "query",
+                    |m: &SearchReq| { &m.query },
+                    |m: &mut SearchReq| { &mut m.query },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "from",
+                    |m: &SearchReq| { &m.from },
+                    |m: &mut SearchReq| { &mut m.from },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "limit",
+                    |m: &SearchReq| { &m.limit },
+                    |m: &mut SearchReq| { &mut m.limit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exclude_count",
+                    |m: &SearchReq| { &m.exclude_count },
+                    |m: &mut SearchReq| { &mut m.exclude_count },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "exclude_docs",
+                    |m: &SearchReq| { &m.exclude_docs },
+                    |m: &mut SearchReq| { &mut m.exclude_docs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "facet_field",
+                    |m: &SearchReq| { &m.facet_field },
+                    |m: &mut SearchReq| { &mut m.facet_field },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "facet_prefixes",
+                    |m: &SearchReq| { &m.facet_prefixes },
+                    |m: &mut SearchReq| { &mut m.facet_prefixes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "facets",
+                    |m: &SearchReq| { &m.facets },
+                    |m: &mut SearchReq| { &mut m.facets },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "post_filter",
+                    |m: &SearchReq| { &m.post_filter },
+                    |m: &mut SearchReq| { &mut m.post_filter },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "more_like_this_doc_id",
+                    |m: &SearchReq| { &m.more_like_this_doc_id },
+                    |m: &mut SearchReq| { &mut m.more_like_this_doc_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "more_like_this_text",
+                    |m: &SearchReq| { &m.more_like_this_text },
+                    |m: &mut SearchReq| { &mut m.more_like_this_text },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "did_you_mean",
+                    |m: &SearchReq| { &m.did_you_mean },
+                    |m: &mut SearchReq| { &mut m.did_you_mean },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "geo_field",
+                    |m: &SearchReq| { &m.geo_field },
+                    |m: &mut SearchReq| { &mut m.geo_field },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "geo_origin",
+                    |m: &SearchReq| { &m.geo_origin },
+                    |m: &mut SearchReq| { &mut m.geo_origin },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "geo_distance",
+                    |m: &SearchReq| { &m.geo_distance },
+                    |m: &mut SearchReq| { &mut m.geo_distance },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "geo_bounding_box",
+                    |m: &SearchReq| { &m.geo_bounding_box },
+                    |m: &mut SearchReq| { &mut m.geo_bounding_box },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "geo_sort",
+                    |m: &SearchReq| { &m.geo_sort },
+                    |m: &mut SearchReq| { &mut m.geo_sort },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "date_field",
+                    |m: &SearchReq| { &m.date_field },
+                    |m: &mut SearchReq| { &mut m.date_field },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "date_from",
+                    |m: &SearchReq| { &m.date_from },
+                    |m: &mut SearchReq| { &mut m.date_from },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "date_to",
+                    |m: &SearchReq| { &m.date_to },
+                    |m: &mut SearchReq| { &mut m.date_to },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "time_zone",
+                    |m: &SearchReq| { &m.time_zone },
+                    |m: &mut SearchReq| { &mut m.time_zone },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "function_score",
+                    |m: &SearchReq| { &m.function_score },
+                    |m: &mut SearchReq| { &mut m.function_score },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "collapse_field",
+                    |m: &SearchReq| { &m.collapse_field },
+                    |m: &mut SearchReq| { &mut m.collapse_field },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "timeout",
+                    |m: &SearchReq| { &m.timeout },
+                    |m: &mut SearchReq| { &mut m.timeout },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "terminate_after",
+                    |m: &SearchReq| { &m.terminate_after },
+                    |m: &mut SearchReq| { &mut m.terminate_after },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "sort",
+                    |m: &SearchReq| { &m.sort },
+                    |m: &mut SearchReq| { &mut m.sort },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "facet_limits",
+                    |m: &SearchReq| { &m.facet_limits },
+                    |m: &mut SearchReq| { &mut m.facet_limits },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "facet_sorts",
+                    |m: &SearchReq| { &m.facet_sorts },
+                    |m: &mut SearchReq| { &mut m.facet_sorts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "aggregations",
+                    |m: &SearchReq| { &m.aggregations },
+                    |m: &mut SearchReq| { &mut m.aggregations },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "highlight",
+                    |m: &SearchReq| { &m.highlight },
+                    |m: &mut SearchReq| { &mut m.highlight },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "query_dsl",
+                    |m: &SearchReq| { &m.query_dsl },
+                    |m: &mut SearchReq| { &mut m.query_dsl },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "typo_tolerance",
+                    |m: &SearchReq| { &m.typo_tolerance },
+                    |m: &mut SearchReq| { &mut m.typo_tolerance },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "default_fields",
+                    |m: &SearchReq| { &m.default_fields },
+                    |m: &mut SearchReq| { &mut m.default_fields },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SearchReq>(
+                    "SearchReq",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SearchReq {
+        static mut instance: ::protobuf::lazy::Lazy<SearchReq> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SearchReq,
+        };
+        unsafe {
+            instance.get(SearchReq::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SearchReq {
+    fn clear(&mut self) {
+        self.client_id = 0;
+        self.seq = 0;
+        self.query.clear();
+        self.from = 0;
+        self.limit = 0;
+        self.exclude_count = false;
+        self.exclude_docs = false;
+        self.facet_field.clear();
+        self.facet_prefixes.clear();
+        self.facets.clear();
+        self.post_filter.clear();
+        self.more_like_this_doc_id.clear();
+        self.more_like_this_text.clear();
+        self.did_you_mean = false;
+        self.geo_field.clear();
+        self.geo_origin.clear();
+        self.geo_distance.clear();
+        self.geo_bounding_box.clear();
+        self.geo_sort = false;
+        self.date_field.clear();
+        self.date_from.clear();
+        self.date_to.clear();
+        self.time_zone.clear();
+        self.function_score.clear();
+        self.collapse_field.clear();
+        self.timeout = 0;
+        self.terminate_after = 0;
+        self.sort.clear();
+        self.facet_limits.clear();
+        self.facet_sorts.clear();
+        self.aggregations.clear();
+        self.highlight.clear();
+        self.query_dsl.clear();
+        self.typo_tolerance = false;
+        self.default_fields.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SearchReq {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SearchReq {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SearchResp {
+    // message fields
+    pub value: ::std::string::String,
+    pub err: RespErr,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SearchResp {
+    fn default() -> &'a SearchResp {
+        <SearchResp as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SearchResp {
+    pub fn new() -> SearchResp {
+        ::std::default::Default::default()
+    }
+
+    // string value = 1;
+
+
+    pub fn get_value(&self) -> &str {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::string::String) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::string::String {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.value, ::std::string::String::new())
+    }
+
+    // .indexrpcpb.RespErr err = 2;
+
+
+    pub fn get_err(&self) -> RespErr {
+        self.err
+    }
+    pub fn clear_err(&mut self) {
+        self.err = RespErr::OK;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_err(&mut self, v: RespErr) {
+        self.err = v;
+    }
+}
+
+impl ::protobuf::Message for SearchResp {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.value)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.err, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.value);
+        }
+        if self.err != RespErr::OK {
+            my_size += ::protobuf::rt::enum_size(2, self.err);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.value.is_empty() {
+            os.write_string(1, &self.value)?;
+        }
+        if self.err != RespErr::OK {
+            os.write_enum(2, self.err.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SearchResp {
+        SearchResp::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "value",
+                    |m: &SearchResp| { &m.value },
+                    |m: &mut SearchResp| { &mut m.value },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
                     "err",
-                    |m: &MergeResp| { &m.err },
-                    |m: &mut MergeResp| { &mut m.err },
+                    |m: &SearchResp| { &m.err },
+                    |m: &mut SearchResp| { &mut m.err },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<MergeResp>(
-                    "MergeResp",
+                ::protobuf::reflect::MessageDescriptor::new::<SearchResp>(
+                    "SearchResp",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5225,18 +8636,18 @@ impl ::protobuf::Message for MergeResp {
         }
     }
 
-    fn default_instance() -> &'static MergeResp {
-        static mut instance: ::protobuf::lazy::Lazy<MergeResp> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static SearchResp {
+        static mut instance: ::protobuf::lazy::Lazy<SearchResp> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const MergeResp,
+            ptr: 0 as *const SearchResp,
         };
         unsafe {
-            instance.get(MergeResp::new)
+            instance.get(SearchResp::new)
         }
     }
 }
 
-impl ::protobuf::Clear for MergeResp {
+impl ::protobuf::Clear for SearchResp {
     fn clear(&mut self) {
         self.value.clear();
         self.err = RespErr::OK;
@@ -5244,43 +8655,40 @@ impl ::protobuf::Clear for MergeResp {
     }
 }
 
-impl ::std::fmt::Debug for MergeResp {
+impl ::std::fmt::Debug for SearchResp {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MergeResp {
+impl ::protobuf::reflect::ProtobufValue for SearchResp {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SearchReq {
+pub struct SuggestReq {
     // message fields
     pub client_id: u64,
     pub seq: u64,
-    pub query: ::std::string::String,
-    pub from: u64,
+    pub field: ::std::string::String,
+    pub prefix: ::std::string::String,
     pub limit: u64,
-    pub exclude_count: bool,
-    pub exclude_docs: bool,
-    pub facet_field: ::std::string::String,
-    pub facet_prefixes: ::protobuf::RepeatedField<::std::string::String>,
+    pub weight_field: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SearchReq {
-    fn default() -> &'a SearchReq {
-        <SearchReq as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SuggestReq {
+    fn default() -> &'a SuggestReq {
+        <SuggestReq as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SearchReq {
-    pub fn new() -> SearchReq {
+impl SuggestReq {
+    pub fn new() -> SuggestReq {
         ::std::default::Default::default()
     }
 
@@ -5314,45 +8722,56 @@ impl SearchReq {
         self.seq = v;
     }
 
-    // string query = 3;
+    // string field = 3;
 
 
-    pub fn get_query(&self) -> &str {
-        &self.query
+    pub fn get_field(&self) -> &str {
+        &self.field
     }
-    pub fn clear_query(&mut self) {
-        self.query.clear();
+    pub fn clear_field(&mut self) {
+        self.field.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_query(&mut self, v: ::std::string::String) {
-        self.query = v;
+    pub fn set_field(&mut self, v: ::std::string::String) {
+        self.field = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_query(&mut self) -> &mut ::std::string::String {
-        &mut self.query
+    pub fn mut_field(&mut self) -> &mut ::std::string::String {
+        &mut self.field
     }
 
     // Take field
-    pub fn take_query(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.query, ::std::string::String::new())
+    pub fn take_field(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field, ::std::string::String::new())
     }
 
-    // uint64 from = 4;
+    // string prefix = 4;
 
 
-    pub fn get_from(&self) -> u64 {
-        self.from
+    pub fn get_prefix(&self) -> &str {
+        &self.prefix
     }
-    pub fn clear_from(&mut self) {
-        self.from = 0;
+    pub fn clear_prefix(&mut self) {
+        self.prefix.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_from(&mut self, v: u64) {
-        self.from = v;
+    pub fn set_prefix(&mut self, v: ::std::string::String) {
+        self.prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prefix(&mut self) -> &mut ::std::string::String {
+        &mut self.prefix
+    }
+
+    // Take field
+    pub fn take_prefix(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.prefix, ::std::string::String::new())
     }
 
     // uint64 limit = 5;
@@ -5370,89 +8789,34 @@ impl SearchReq {
         self.limit = v;
     }
 
-    // bool exclude_count = 6;
-
-
-    pub fn get_exclude_count(&self) -> bool {
-        self.exclude_count
-    }
-    pub fn clear_exclude_count(&mut self) {
-        self.exclude_count = false;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_exclude_count(&mut self, v: bool) {
-        self.exclude_count = v;
-    }
-
-    // bool exclude_docs = 7;
-
-
-    pub fn get_exclude_docs(&self) -> bool {
-        self.exclude_docs
-    }
-    pub fn clear_exclude_docs(&mut self) {
-        self.exclude_docs = false;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_exclude_docs(&mut self, v: bool) {
-        self.exclude_docs = v;
-    }
-
-    // string facet_field = 8;
+    // string weight_field = 6;
 
 
-    pub fn get_facet_field(&self) -> &str {
-        &self.facet_field
+    pub fn get_weight_field(&self) -> &str {
+        &self.weight_field
     }
-    pub fn clear_facet_field(&mut self) {
-        self.facet_field.clear();
+    pub fn clear_weight_field(&mut self) {
+        self.weight_field.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_facet_field(&mut self, v: ::std::string::String) {
-        self.facet_field = v;
+    pub fn set_weight_field(&mut self, v: ::std::string::String) {
+        self.weight_field = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_facet_field(&mut self) -> &mut ::std::string::String {
-        &mut self.facet_field
-    }
-
-    // Take field
-    pub fn take_facet_field(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.facet_field, ::std::string::String::new())
-    }
-
-    // repeated string facet_prefixes = 9;
-
-
-    pub fn get_facet_prefixes(&self) -> &[::std::string::String] {
-        &self.facet_prefixes
-    }
-    pub fn clear_facet_prefixes(&mut self) {
-        self.facet_prefixes.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_facet_prefixes(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.facet_prefixes = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_facet_prefixes(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.facet_prefixes
+    pub fn mut_weight_field(&mut self) -> &mut ::std::string::String {
+        &mut self.weight_field
     }
 
     // Take field
-    pub fn take_facet_prefixes(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.facet_prefixes, ::protobuf::RepeatedField::new())
+    pub fn take_weight_field(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.weight_field, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for SearchReq {
+impl ::protobuf::Message for SuggestReq {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5476,14 +8840,10 @@ impl ::protobuf::Message for SearchReq {
                     self.seq = tmp;
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.query)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field)?;
                 },
                 4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.from = tmp;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.prefix)?;
                 },
                 5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -5493,24 +8853,7 @@ impl ::protobuf::Message for SearchReq {
                     self.limit = tmp;
                 },
                 6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.exclude_count = tmp;
-                },
-                7 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.exclude_docs = tmp;
-                },
-                8 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.facet_field)?;
-                },
-                9 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.facet_prefixes)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.weight_field)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -5530,27 +8873,18 @@ impl ::protobuf::Message for SearchReq {
         if self.seq != 0 {
             my_size += ::protobuf::rt::value_size(2, self.seq, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.query.is_empty() {
-            my_size += ::protobuf::rt::string_size(3, &self.query);
+        if !self.field.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.field);
         }
-        if self.from != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.from, ::protobuf::wire_format::WireTypeVarint);
+        if !self.prefix.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.prefix);
         }
         if self.limit != 0 {
             my_size += ::protobuf::rt::value_size(5, self.limit, ::protobuf::wire_format::WireTypeVarint);
         }
-        if self.exclude_count != false {
-            my_size += 2;
-        }
-        if self.exclude_docs != false {
-            my_size += 2;
-        }
-        if !self.facet_field.is_empty() {
-            my_size += ::protobuf::rt::string_size(8, &self.facet_field);
+        if !self.weight_field.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.weight_field);
         }
-        for value in &self.facet_prefixes {
-            my_size += ::protobuf::rt::string_size(9, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -5563,27 +8897,18 @@ impl ::protobuf::Message for SearchReq {
         if self.seq != 0 {
             os.write_uint64(2, self.seq)?;
         }
-        if !self.query.is_empty() {
-            os.write_string(3, &self.query)?;
+        if !self.field.is_empty() {
+            os.write_string(3, &self.field)?;
         }
-        if self.from != 0 {
-            os.write_uint64(4, self.from)?;
+        if !self.prefix.is_empty() {
+            os.write_string(4, &self.prefix)?;
         }
         if self.limit != 0 {
             os.write_uint64(5, self.limit)?;
         }
-        if self.exclude_count != false {
-            os.write_bool(6, self.exclude_count)?;
-        }
-        if self.exclude_docs != false {
-            os.write_bool(7, self.exclude_docs)?;
-        }
-        if !self.facet_field.is_empty() {
-            os.write_string(8, &self.facet_field)?;
+        if !self.weight_field.is_empty() {
+            os.write_string(6, &self.weight_field)?;
         }
-        for v in &self.facet_prefixes {
-            os.write_string(9, &v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5614,8 +8939,8 @@ impl ::protobuf::Message for SearchReq {
         Self::descriptor_static()
     }
 
-    fn new() -> SearchReq {
-        SearchReq::new()
+    fn new() -> SuggestReq {
+        SuggestReq::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -5628,51 +8953,36 @@ impl ::protobuf::Message for SearchReq {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "client_id",
-                    |m: &SearchReq| { &m.client_id },
-                    |m: &mut SearchReq| { &mut m.client_id },
+                    |m: &SuggestReq| { &m.client_id },
+                    |m: &mut SuggestReq| { &mut m.client_id },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "seq",
-                    |m: &SearchReq| { &m.seq },
-                    |m: &mut SearchReq| { &mut m.seq },
+                    |m: &SuggestReq| { &m.seq },
+                    |m: &mut SuggestReq| { &mut m.seq },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "query",
-                    |m: &SearchReq| { &m.query },
-                    |m: &mut SearchReq| { &mut m.query },
+                    "field",
+                    |m: &SuggestReq| { &m.field },
+                    |m: &mut SuggestReq| { &mut m.field },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "from",
-                    |m: &SearchReq| { &m.from },
-                    |m: &mut SearchReq| { &mut m.from },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "prefix",
+                    |m: &SuggestReq| { &m.prefix },
+                    |m: &mut SuggestReq| { &mut m.prefix },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "limit",
-                    |m: &SearchReq| { &m.limit },
-                    |m: &mut SearchReq| { &mut m.limit },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "exclude_count",
-                    |m: &SearchReq| { &m.exclude_count },
-                    |m: &mut SearchReq| { &mut m.exclude_count },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "exclude_docs",
-                    |m: &SearchReq| { &m.exclude_docs },
-                    |m: &mut SearchReq| { &mut m.exclude_docs },
+                    |m: &SuggestReq| { &m.limit },
+                    |m: &mut SuggestReq| { &mut m.limit },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "facet_field",
-                    |m: &SearchReq| { &m.facet_field },
-                    |m: &mut SearchReq| { &mut m.facet_field },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "facet_prefixes",
-                    |m: &SearchReq| { &m.facet_prefixes },
-                    |m: &mut SearchReq| { &mut m.facet_prefixes },
+                    "weight_field",
+                    |m: &SuggestReq| { &m.weight_field },
+                    |m: &mut SuggestReq| { &mut m.weight_field },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SearchReq>(
-                    "SearchReq",
+                ::protobuf::reflect::MessageDescriptor::new::<SuggestReq>(
+                    "SuggestReq",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5680,46 +8990,43 @@ impl ::protobuf::Message for SearchReq {
         }
     }
 
-    fn default_instance() -> &'static SearchReq {
-        static mut instance: ::protobuf::lazy::Lazy<SearchReq> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static SuggestReq {
+        static mut instance: ::protobuf::lazy::Lazy<SuggestReq> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SearchReq,
+            ptr: 0 as *const SuggestReq,
         };
         unsafe {
-            instance.get(SearchReq::new)
+            instance.get(SuggestReq::new)
         }
     }
 }
 
-impl ::protobuf::Clear for SearchReq {
+impl ::protobuf::Clear for SuggestReq {
     fn clear(&mut self) {
         self.client_id = 0;
         self.seq = 0;
-        self.query.clear();
-        self.from = 0;
+        self.field.clear();
+        self.prefix.clear();
         self.limit = 0;
-        self.exclude_count = false;
-        self.exclude_docs = false;
-        self.facet_field.clear();
-        self.facet_prefixes.clear();
+        self.weight_field.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SearchReq {
+impl ::std::fmt::Debug for SuggestReq {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SearchReq {
+impl ::protobuf::reflect::ProtobufValue for SuggestReq {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SearchResp {
+pub struct SuggestResp {
     // message fields
     pub value: ::std::string::String,
     pub err: RespErr,
@@ -5728,14 +9035,14 @@ pub struct SearchResp {
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SearchResp {
-    fn default() -> &'a SearchResp {
-        <SearchResp as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a SuggestResp {
+    fn default() -> &'a SuggestResp {
+        <SuggestResp as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SearchResp {
-    pub fn new() -> SearchResp {
+impl SuggestResp {
+    pub fn new() -> SuggestResp {
         ::std::default::Default::default()
     }
 
@@ -5781,7 +9088,7 @@ impl SearchResp {
     }
 }
 
-impl ::protobuf::Message for SearchResp {
+impl ::protobuf::Message for SuggestResp {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5856,8 +9163,8 @@ impl ::protobuf::Message for SearchResp {
         Self::descriptor_static()
     }
 
-    fn new() -> SearchResp {
-        SearchResp::new()
+    fn new() -> SuggestResp {
+        SuggestResp::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -5870,16 +9177,16 @@ impl ::protobuf::Message for SearchResp {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "value",
-                    |m: &SearchResp| { &m.value },
-                    |m: &mut SearchResp| { &mut m.value },
+                    |m: &SuggestResp| { &m.value },
+                    |m: &mut SuggestResp| { &mut m.value },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<RespErr>>(
                     "err",
-                    |m: &SearchResp| { &m.err },
-                    |m: &mut SearchResp| { &mut m.err },
+                    |m: &SuggestResp| { &m.err },
+                    |m: &mut SuggestResp| { &mut m.err },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SearchResp>(
-                    "SearchResp",
+                ::protobuf::reflect::MessageDescriptor::new::<SuggestResp>(
+                    "SuggestResp",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5887,18 +9194,18 @@ impl ::protobuf::Message for SearchResp {
         }
     }
 
-    fn default_instance() -> &'static SearchResp {
-        static mut instance: ::protobuf::lazy::Lazy<SearchResp> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static SuggestResp {
+        static mut instance: ::protobuf::lazy::Lazy<SuggestResp> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SearchResp,
+            ptr: 0 as *const SuggestResp,
         };
         unsafe {
-            instance.get(SearchResp::new)
+            instance.get(SuggestResp::new)
         }
     }
 }
 
-impl ::protobuf::Clear for SearchResp {
+impl ::protobuf::Clear for SuggestResp {
     fn clear(&mut self) {
         self.value.clear();
         self.err = RespErr::OK;
@@ -5906,13 +9213,13 @@ impl ::protobuf::Clear for SearchResp {
     }
 }
 
-impl ::std::fmt::Debug for SearchResp {
+impl ::std::fmt::Debug for SuggestResp {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SearchResp {
+impl ::protobuf::reflect::ProtobufValue for SuggestResp {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -6320,6 +9627,12 @@ pub enum RespErr {
     OK = 0,
     ErrWrongLeader = 1,
     ErrNoKey = 2,
+    ErrConflict = 3,
+    ErrInvalidQuery = 4,
+    ErrInvalidDocument = 5,
+    ErrUnknownField = 6,
+    ErrSchemaMismatch = 7,
+    ErrInternal = 8,
 }
 
 impl ::protobuf::ProtobufEnum for RespErr {
@@ -6332,6 +9645,12 @@ impl ::protobuf::ProtobufEnum for RespErr {
             0 => ::std::option::Option::Some(RespErr::OK),
             1 => ::std::option::Option::Some(RespErr::ErrWrongLeader),
             2 => ::std::option::Option::Some(RespErr::ErrNoKey),
+            3 => ::std::option::Option::Some(RespErr::ErrConflict),
+            4 => ::std::option::Option::Some(RespErr::ErrInvalidQuery),
+            5 => ::std::option::Option::Some(RespErr::ErrInvalidDocument),
+            6 => ::std::option::Option::Some(RespErr::ErrUnknownField),
+            7 => ::std::option::Option::Some(RespErr::ErrSchemaMismatch),
+            8 => ::std::option::Option::Some(RespErr::ErrInternal),
             _ => ::std::option::Option::None
         }
     }
@@ -6341,6 +9660,12 @@ impl ::protobuf::ProtobufEnum for RespErr {
             RespErr::OK,
             RespErr::ErrWrongLeader,
             RespErr::ErrNoKey,
+            RespErr::ErrConflict,
+            RespErr::ErrInvalidQuery,
+            RespErr::ErrInvalidDocument,
+            RespErr::ErrUnknownField,
+            RespErr::ErrSchemaMismatch,
+            RespErr::ErrInternal,
         ];
         values
     }
@@ -6382,6 +9707,8 @@ pub enum ReqType {
     Commit = 4,
     Rollback = 5,
     Merge = 6,
+    Update = 7,
+    DeleteByQuery = 8,
 }
 
 impl ::protobuf::ProtobufEnum for ReqType {
@@ -6398,6 +9725,8 @@ impl ::protobuf::ProtobufEnum for ReqType {
             4 => ::std::option::Option::Some(ReqType::Commit),
             5 => ::std::option::Option::Some(ReqType::Rollback),
             6 => ::std::option::Option::Some(ReqType::Merge),
+            7 => ::std::option::Option::Some(ReqType::Update),
+            8 => ::std::option::Option::Some(ReqType::DeleteByQuery),
             _ => ::std::option::Option::None
         }
     }
@@ -6411,6 +9740,8 @@ impl ::protobuf::ProtobufEnum for ReqType {
             ReqType::Commit,
             ReqType::Rollback,
             ReqType::Merge,
+            ReqType::Update,
+            ReqType::DeleteByQuery,
         ];
         values
     }
@@ -6444,7 +9775,7 @@ impl ::protobuf::reflect::ProtobufValue for ReqType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10indexrpcpb.proto\x12\nindexrpcpb\x1a\reraftpb.proto\"\xc2\x03\n\
+    \n\x10indexrpcpb.proto\x12\nindexrpcpb\x1a\reraftpb.proto\"\xc5\x04\n\
     \x08ApplyReq\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12.\
     \n\x08req_type\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.ReqTypeR\x07reqType\
     \x12.\n\x08join_req\x18\x03\x20\x01(\x0b2\x13.indexrpcpb.JoinReqR\x07joi\
@@ -6454,380 +9785,580 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     leteReqR\tdeleteReq\x124\n\ncommit_req\x18\x07\x20\x01(\x0b2\x15.indexrp\
     cpb.CommitReqR\tcommitReq\x12:\n\x0crollback_req\x18\x08\x20\x01(\x0b2\
     \x17.indexrpcpb.RollbackReqR\x0brollbackReq\x121\n\tmerge_req\x18\t\x20\
-    \x01(\x0b2\x14.indexrpcpb.MergeReqR\x08mergeReq\"X\n\rConfChangeReq\x12#\
-    \n\x02cc\x18\x01\x20\x01(\x0b2\x13.eraftpb.ConfChangeR\x02cc\x12\x0e\n\
-    \x02ip\x18\x02\x20\x01(\tR\x02ip\x12\x12\n\x04port\x18\x03\x20\x01(\rR\
-    \x04port\"1\n\x08RaftDone\x12%\n\x03err\x18\x01\x20\x01(\x0e2\x13.indexr\
-    pcpb.RespErrR\x03err\"\\\n\x07JoinReq\x12\x1b\n\tclient_id\x18\x01\x20\
+    \x01(\x0b2\x14.indexrpcpb.MergeReqR\x08mergeReq\x124\n\nupdate_req\x18\n\
+    \x20\x01(\x0b2\x15.indexrpcpb.UpdateReqR\tupdateReq\x12K\n\x13delete_by_\
+    query_req\x18\x0b\x20\x01(\x0b2\x1c.indexrpcpb.DeleteByQueryReqR\x10dele\
+    teByQueryReq\"X\n\rConfChangeReq\x12#\n\x02cc\x18\x01\x20\x01(\x0b2\x13.\
+    eraftpb.ConfChangeR\x02cc\x12\x0e\n\x02ip\x18\x02\x20\x01(\tR\x02ip\x12\
+    \x12\n\x04port\x18\x03\x20\x01(\rR\x04port\"1\n\x08RaftDone\x12%\n\x03er\
+    r\x18\x01\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\"\\\n\x07JoinReq\
+    \x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x17\n\x07pee\
+    r_id\x18\x02\x20\x01(\x04R\x06peerId\x12\x1b\n\tpeer_addr\x18\x03\x20\
+    \x01(\tR\x08peerAddr\"]\n\x08LeaveReq\x12\x1b\n\tclient_id\x18\x01\x20\
     \x01(\x04R\x08clientId\x12\x17\n\x07peer_id\x18\x02\x20\x01(\x04R\x06pee\
-    rId\x12\x1b\n\tpeer_addr\x18\x03\x20\x01(\tR\x08peerAddr\"]\n\x08LeaveRe\
-    q\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x17\n\x07pe\
-    er_id\x18\x02\x20\x01(\x04R\x06peerId\x12\x1b\n\tpeer_addr\x18\x03\x20\
-    \x01(\tR\x08peerAddr\"9\n\x08ProbeReq\x12\x1b\n\tclient_id\x18\x01\x20\
-    \x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\"H\
-    \n\tProbeResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03\
-    err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\"9\n\x08PeersRe\
+    rId\x12\x1b\n\tpeer_addr\x18\x03\x20\x01(\tR\x08peerAddr\"9\n\x08ProbeRe\
     q\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\x03se\
-    q\x18\x02\x20\x01(\x04R\x03seq\"H\n\tPeersResp\x12\x14\n\x05value\x18\
+    q\x18\x02\x20\x01(\x04R\x03seq\"H\n\tProbeResp\x12\x14\n\x05value\x18\
     \x01\x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.indexr\
-    pcpb.RespErrR\x03err\";\n\nMetricsReq\x12\x1b\n\tclient_id\x18\x01\x20\
-    \x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\"J\
-    \n\x0bMetricsResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\
-    \x03err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\"N\n\x06Get\
-    Req\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\x03\
-    seq\x18\x02\x20\x01(\x04R\x03seq\x12\x15\n\x06doc_id\x18\x03\x20\x01(\tR\
-    \x05docId\"F\n\x07GetResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05valu\
-    e\x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\"f\
-    \n\x06PutReq\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\
+    pcpb.RespErrR\x03err\"9\n\x08PeersReq\x12\x1b\n\tclient_id\x18\x01\x20\
+    \x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\"H\
+    \n\tPeersResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03\
+    err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\";\n\nMetricsRe\
+    q\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\x03se\
+    q\x18\x02\x20\x01(\x04R\x03seq\"J\n\x0bMetricsResp\x12\x14\n\x05value\
+    \x18\x01\x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.in\
+    dexrpcpb.RespErrR\x03err\"N\n\x06GetReq\x12\x1b\n\tclient_id\x18\x01\x20\
+    \x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\x12\
+    \x15\n\x06doc_id\x18\x03\x20\x01(\tR\x05docId\"F\n\x07GetResp\x12\x14\n\
+    \x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\
+    \x0e2\x13.indexrpcpb.RespErrR\x03err\"U\n\x0bMultiGetReq\x12\x1b\n\tclie\
+    nt_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01\
+    (\x04R\x03seq\x12\x17\n\x07doc_ids\x18\x03\x20\x03(\tR\x06docIds\"K\n\
+    \x0cMultiGetResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\
+    \x03err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\"\xbc\x01\n\
+    \x06PutReq\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\
     \x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\x12\x15\n\x06doc_id\x18\x03\
     \x20\x01(\tR\x05docId\x12\x16\n\x06fields\x18\x04\x20\x01(\tR\x06fields\
-    \"F\n\x07PutResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\
-    \x03err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\"Q\n\tDelet\
-    eReq\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\
-    \x03seq\x18\x02\x20\x01(\x04R\x03seq\x12\x15\n\x06doc_id\x18\x03\x20\x01\
-    (\tR\x05docId\"I\n\nDeleteResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\
-    \x05value\x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\
-    \x03err\":\n\tCommitReq\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08cl\
-    ientId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\"I\n\nCommitResp\
+    \x12\x18\n\x07version\x18\x05\x20\x01(\x04R\x07version\x12\x1d\n\nif_ver\
+    sion\x18\x06\x20\x01(\x04R\tifVersion\x12\x1b\n\tif_absent\x18\x07\x20\
+    \x01(\x08R\x08ifAbsent\"F\n\x07PutResp\x12\x14\n\x05value\x18\x01\x20\
+    \x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.Res\
+    pErrR\x03err\"\x93\x01\n\tUpdateReq\x12\x1b\n\tclient_id\x18\x01\x20\x01\
+    (\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\x12\x15\
+    \n\x06doc_id\x18\x03\x20\x01(\tR\x05docId\x12\x10\n\x03set\x18\x04\x20\
+    \x01(\tR\x03set\x12\x16\n\x06append\x18\x05\x20\x01(\tR\x06append\x12\
+    \x16\n\x06remove\x18\x06\x20\x01(\tR\x06remove\"I\n\nUpdateResp\x12\x14\
+    \n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\
+    \x0e2\x13.indexrpcpb.RespErrR\x03err\"p\n\tDeleteReq\x12\x1b\n\tclient_i\
+    d\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01(\
+    \x04R\x03seq\x12\x15\n\x06doc_id\x18\x03\x20\x01(\tR\x05docId\x12\x1d\n\
+    \nif_version\x18\x04\x20\x01(\x04R\tifVersion\"I\n\nDeleteResp\x12\x14\n\
+    \x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\
+    \x0e2\x13.indexrpcpb.RespErrR\x03err\"W\n\x10DeleteByQueryReq\x12\x1b\n\
+    \tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\
+    \x20\x01(\x04R\x03seq\x12\x14\n\x05query\x18\x03\x20\x01(\tR\x05query\"P\
+    \n\x11DeleteByQueryResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\
+    \x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\":\n\
+    \tCommitReq\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\
+    \x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\"I\n\nCommitResp\x12\x14\n\
+    \x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\
+    \x0e2\x13.indexrpcpb.RespErrR\x03err\"<\n\x0bRollbackReq\x12\x1b\n\tclie\
+    nt_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01\
+    (\x04R\x03seq\"K\n\x0cRollbackResp\x12\x14\n\x05value\x18\x01\x20\x01(\t\
+    R\x05value\x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\
+    \x03err\"9\n\x08MergeReq\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08c\
+    lientId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\"H\n\tMergeResp\
     \x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\
-    \x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\"<\n\x0bRollbackReq\x12\
+    \x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\"\xf2\x08\n\tSearchReq\x12\
     \x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\
-    \x02\x20\x01(\x04R\x03seq\"K\n\x0cRollbackResp\x12\x14\n\x05value\x18\
-    \x01\x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.indexr\
-    pcpb.RespErrR\x03err\"9\n\x08MergeReq\x12\x1b\n\tclient_id\x18\x01\x20\
-    \x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\"H\
-    \n\tMergeResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03\
-    err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\"\x8a\x02\n\tSe\
-    archReq\x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\
-    \x03seq\x18\x02\x20\x01(\x04R\x03seq\x12\x14\n\x05query\x18\x03\x20\x01(\
-    \tR\x05query\x12\x12\n\x04from\x18\x04\x20\x01(\x04R\x04from\x12\x14\n\
-    \x05limit\x18\x05\x20\x01(\x04R\x05limit\x12#\n\rexclude_count\x18\x06\
-    \x20\x01(\x08R\x0cexcludeCount\x12!\n\x0cexclude_docs\x18\x07\x20\x01(\
-    \x08R\x0bexcludeDocs\x12\x1f\n\x0bfacet_field\x18\x08\x20\x01(\tR\nfacet\
-    Field\x12%\n\x0efacet_prefixes\x18\t\x20\x03(\tR\rfacetPrefixes\"I\n\nSe\
-    archResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03err\
+    \x02\x20\x01(\x04R\x03seq\x12\x14\n\x05query\x18\x03\x20\x01(\tR\x05quer\
+    y\x12\x12\n\x04from\x18\x04\x20\x01(\x04R\x04from\x12\x14\n\x05limit\x18\
+    \x05\x20\x01(\x04R\x05limit\x12#\n\rexclude_count\x18\x06\x20\x01(\x08R\
+    \x0cexcludeCount\x12!\n\x0cexclude_docs\x18\x07\x20\x01(\x08R\x0bexclude\
+    Docs\x12\x1f\n\x0bfacet_field\x18\x08\x20\x01(\tR\nfacetField\x12%\n\x0e\
+    facet_prefixes\x18\t\x20\x03(\tR\rfacetPrefixes\x12\x16\n\x06facets\x18\
+    \n\x20\x03(\tR\x06facets\x12\x1f\n\x0bpost_filter\x18\x0b\x20\x01(\tR\np\
+    ostFilter\x120\n\x15more_like_this_doc_id\x18\x0c\x20\x01(\tR\x11moreLik\
+    eThisDocId\x12-\n\x13more_like_this_text\x18\r\x20\x01(\tR\x10moreLikeTh\
+    isText\x12\x20\n\x0cdid_you_mean\x18\x0e\x20\x01(\x08R\ndidYouMean\x12\
+    \x1b\n\tgeo_field\x18\x0f\x20\x01(\tR\x08geoField\x12\x1d\n\ngeo_origin\
+    \x18\x10\x20\x01(\tR\tgeoOrigin\x12!\n\x0cgeo_distance\x18\x11\x20\x01(\
+    \tR\x0bgeoDistance\x12(\n\x10geo_bounding_box\x18\x12\x20\x01(\tR\x0egeo\
+    BoundingBox\x12\x19\n\x08geo_sort\x18\x13\x20\x01(\x08R\x07geoSort\x12\
+    \x1d\n\ndate_field\x18\x14\x20\x01(\tR\tdateField\x12\x1b\n\tdate_from\
+    \x18\x15\x20\x01(\tR\x08dateFrom\x12\x17\n\x07date_to\x18\x16\x20\x01(\t\
+    R\x06dateTo\x12\x1b\n\ttime_zone\x18\x17\x20\x01(\tR\x08timeZone\x12%\n\
+    \x0efunction_score\x18\x18\x20\x01(\tR\rfunctionScore\x12%\n\x0ecollapse\
+    _field\x18\x19\x20\x01(\tR\rcollapseField\x12\x18\n\x07timeout\x18\x1a\
+    \x20\x01(\x04R\x07timeout\x12'\n\x0fterminate_after\x18\x1b\x20\x01(\x04\
+    R\x0eterminateAfter\x12\x12\n\x04sort\x18\x1c\x20\x03(\tR\x04sort\x12!\n\
+    \x0cfacet_limits\x18\x1d\x20\x03(\tR\x0bfacetLimits\x12\x1f\n\x0bfacet_s\
+    orts\x18\x1e\x20\x03(\tR\nfacetSorts\x12\"\n\x0caggregations\x18\x1f\x20\
+    \x01(\tR\x0caggregations\x12\x1c\n\thighlight\x18\x20\x20\x01(\tR\thighl\
+    ight\x12\x1b\n\tquery_dsl\x18!\x20\x01(\tR\x08queryDsl\x12%\n\x0etypo_to\
+    lerance\x18\"\x20\x01(\x08R\rtypoTolerance\x12%\n\x0edefault_fields\x18#\
+    \x20\x01(\tR\rdefaultFields\"I\n\nSearchResp\x12\x14\n\x05value\x18\x01\
+    \x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.indexrpcpb\
+    .RespErrR\x03err\"\xa2\x01\n\nSuggestReq\x12\x1b\n\tclient_id\x18\x01\
+    \x20\x01(\x04R\x08clientId\x12\x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\
+    \x12\x14\n\x05field\x18\x03\x20\x01(\tR\x05field\x12\x16\n\x06prefix\x18\
+    \x04\x20\x01(\tR\x06prefix\x12\x14\n\x05limit\x18\x05\x20\x01(\x04R\x05l\
+    imit\x12!\n\x0cweight_field\x18\x06\x20\x01(\tR\x0bweightField\"J\n\x0bS\
+    uggestResp\x12\x14\n\x05value\x18\x01\x20\x01(\tR\x05value\x12%\n\x03err\
     \x18\x02\x20\x01(\x0e2\x13.indexrpcpb.RespErrR\x03err\":\n\tSchemaReq\
     \x12\x1b\n\tclient_id\x18\x01\x20\x01(\x04R\x08clientId\x12\x10\n\x03seq\
     \x18\x02\x20\x01(\x04R\x03seq\"I\n\nSchemaResp\x12\x14\n\x05value\x18\
     \x01\x20\x01(\tR\x05value\x12%\n\x03err\x18\x02\x20\x01(\x0e2\x13.indexr\
-    pcpb.RespErrR\x03err*3\n\x07RespErr\x12\x06\n\x02OK\x10\0\x12\x12\n\x0eE\
-    rrWrongLeader\x10\x01\x12\x0c\n\x08ErrNoKey\x10\x02*X\n\x07ReqType\x12\
-    \x08\n\x04Join\x10\0\x12\t\n\x05Leave\x10\x01\x12\x07\n\x03Put\x10\x02\
-    \x12\n\n\x06Delete\x10\x03\x12\n\n\x06Commit\x10\x04\x12\x0c\n\x08Rollba\
-    ck\x10\x05\x12\t\n\x05Merge\x10\x06J\xf92\n\x07\x12\x05\0\0\xaf\x01\x01\
-    \n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\0\x13\n\t\n\
-    \x02\x03\0\x12\x03\x03\0\x17\n\n\n\x02\x05\0\x12\x04\x05\0\t\x01\n\n\n\
+    pcpb.RespErrR\x03err*\xae\x01\n\x07RespErr\x12\x06\n\x02OK\x10\0\x12\x12\
+    \n\x0eErrWrongLeader\x10\x01\x12\x0c\n\x08ErrNoKey\x10\x02\x12\x0f\n\x0b\
+    ErrConflict\x10\x03\x12\x13\n\x0fErrInvalidQuery\x10\x04\x12\x16\n\x12Er\
+    rInvalidDocument\x10\x05\x12\x13\n\x0fErrUnknownField\x10\x06\x12\x15\n\
+    \x11ErrSchemaMismatch\x10\x07\x12\x0f\n\x0bErrInternal\x10\x08*w\n\x07Re\
+    qType\x12\x08\n\x04Join\x10\0\x12\t\n\x05Leave\x10\x01\x12\x07\n\x03Put\
+    \x10\x02\x12\n\n\x06Delete\x10\x03\x12\n\n\x06Commit\x10\x04\x12\x0c\n\
+    \x08Rollback\x10\x05\x12\t\n\x05Merge\x10\x06\x12\n\n\x06Update\x10\x07\
+    \x12\x11\n\rDeleteByQuery\x10\x08J\xb6I\n\x07\x12\x05\0\0\x88\x02\x01\n\
+    \x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\0\x13\n\t\n\
+    \x02\x03\0\x12\x03\x03\0\x17\n\n\n\x02\x05\0\x12\x04\x05\0\x0f\x01\n\n\n\
     \x03\x05\0\x01\x12\x03\x05\x05\x0c\n\x0b\n\x04\x05\0\x02\0\x12\x03\x06\
     \x04\x0b\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x06\x04\x06\n\x0c\n\x05\x05\
     \0\x02\0\x02\x12\x03\x06\t\n\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x07\x04\
     \x17\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x07\x04\x12\n\x0c\n\x05\x05\0\
     \x02\x01\x02\x12\x03\x07\x15\x16\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x08\
     \x04\x11\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x08\x04\x0c\n\x0c\n\x05\
-    \x05\0\x02\x02\x02\x12\x03\x08\x0f\x10\n\n\n\x02\x05\x01\x12\x04\x0b\0\
-    \x13\x01\n\n\n\x03\x05\x01\x01\x12\x03\x0b\x05\x0c\n\x0b\n\x04\x05\x01\
-    \x02\0\x12\x03\x0c\x04\r\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x0c\x04\
-    \x08\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\x0c\x0b\x0c\n\x0b\n\x04\x05\
-    \x01\x02\x01\x12\x03\r\x04\x0e\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\r\
-    \x04\t\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\r\x0c\r\n\x0b\n\x04\x05\
-    \x01\x02\x02\x12\x03\x0e\x04\x0c\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\
-    \x0e\x04\x07\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x0e\n\x0b\n\x0b\n\
-    \x04\x05\x01\x02\x03\x12\x03\x0f\x04\x0f\n\x0c\n\x05\x05\x01\x02\x03\x01\
-    \x12\x03\x0f\x04\n\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x0f\r\x0e\n\
-    \x0b\n\x04\x05\x01\x02\x04\x12\x03\x10\x04\x0f\n\x0c\n\x05\x05\x01\x02\
-    \x04\x01\x12\x03\x10\x04\n\n\x0c\n\x05\x05\x01\x02\x04\x02\x12\x03\x10\r\
-    \x0e\n\x0b\n\x04\x05\x01\x02\x05\x12\x03\x11\x04\x11\n\x0c\n\x05\x05\x01\
-    \x02\x05\x01\x12\x03\x11\x04\x0c\n\x0c\n\x05\x05\x01\x02\x05\x02\x12\x03\
-    \x11\x0f\x10\n\x0b\n\x04\x05\x01\x02\x06\x12\x03\x12\x04\x0e\n\x0c\n\x05\
-    \x05\x01\x02\x06\x01\x12\x03\x12\x04\t\n\x0c\n\x05\x05\x01\x02\x06\x02\
-    \x12\x03\x12\x0c\r\n\n\n\x02\x04\0\x12\x04\x15\0\x1f\x01\n\n\n\x03\x04\0\
-    \x01\x12\x03\x15\x08\x10\n\x0b\n\x04\x04\0\x02\0\x12\x03\x16\x04\x19\n\r\
-    \n\x05\x04\0\x02\0\x04\x12\x04\x16\x04\x15\x12\n\x0c\n\x05\x04\0\x02\0\
-    \x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x16\x0b\x14\n\
-    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x16\x17\x18\n\x0b\n\x04\x04\0\x02\x01\
-    \x12\x03\x17\x04\x19\n\r\n\x05\x04\0\x02\x01\x04\x12\x04\x17\x04\x16\x19\
-    \n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x17\x04\x0b\n\x0c\n\x05\x04\0\x02\
-    \x01\x01\x12\x03\x17\x0c\x14\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x17\
-    \x17\x18\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x18\x04\x19\n\r\n\x05\x04\0\
-    \x02\x02\x04\x12\x04\x18\x04\x17\x19\n\x0c\n\x05\x04\0\x02\x02\x06\x12\
-    \x03\x18\x04\x0b\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x18\x0c\x14\n\x0c\
-    \n\x05\x04\0\x02\x02\x03\x12\x03\x18\x17\x18\n\x0b\n\x04\x04\0\x02\x03\
-    \x12\x03\x19\x04\x1b\n\r\n\x05\x04\0\x02\x03\x04\x12\x04\x19\x04\x18\x19\
-    \n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03\x19\x04\x0c\n\x0c\n\x05\x04\0\x02\
-    \x03\x01\x12\x03\x19\r\x16\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x19\x19\
-    \x1a\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x1a\x04\x17\n\r\n\x05\x04\0\x02\
-    \x04\x04\x12\x04\x1a\x04\x19\x1b\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\
-    \x1a\x04\n\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x1a\x0b\x12\n\x0c\n\x05\
-    \x04\0\x02\x04\x03\x12\x03\x1a\x15\x16\n\x0b\n\x04\x04\0\x02\x05\x12\x03\
-    \x1b\x04\x1d\n\r\n\x05\x04\0\x02\x05\x04\x12\x04\x1b\x04\x1a\x17\n\x0c\n\
-    \x05\x04\0\x02\x05\x06\x12\x03\x1b\x04\r\n\x0c\n\x05\x04\0\x02\x05\x01\
-    \x12\x03\x1b\x0e\x18\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x1b\x1b\x1c\n\
-    \x0b\n\x04\x04\0\x02\x06\x12\x03\x1c\x04\x1d\n\r\n\x05\x04\0\x02\x06\x04\
-    \x12\x04\x1c\x04\x1b\x1d\n\x0c\n\x05\x04\0\x02\x06\x06\x12\x03\x1c\x04\r\
-    \n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03\x1c\x0e\x18\n\x0c\n\x05\x04\0\x02\
-    \x06\x03\x12\x03\x1c\x1b\x1c\n\x0b\n\x04\x04\0\x02\x07\x12\x03\x1d\x04!\
-    \n\r\n\x05\x04\0\x02\x07\x04\x12\x04\x1d\x04\x1c\x1d\n\x0c\n\x05\x04\0\
-    \x02\x07\x06\x12\x03\x1d\x04\x0f\n\x0c\n\x05\x04\0\x02\x07\x01\x12\x03\
-    \x1d\x10\x1c\n\x0c\n\x05\x04\0\x02\x07\x03\x12\x03\x1d\x1f\x20\n\x0b\n\
-    \x04\x04\0\x02\x08\x12\x03\x1e\x04\x1b\n\r\n\x05\x04\0\x02\x08\x04\x12\
-    \x04\x1e\x04\x1d!\n\x0c\n\x05\x04\0\x02\x08\x06\x12\x03\x1e\x04\x0c\n\
-    \x0c\n\x05\x04\0\x02\x08\x01\x12\x03\x1e\r\x16\n\x0c\n\x05\x04\0\x02\x08\
-    \x03\x12\x03\x1e\x19\x1a\n\n\n\x02\x04\x01\x12\x04!\0%\x01\n\n\n\x03\x04\
-    \x01\x01\x12\x03!\x08\x15\n\x0b\n\x04\x04\x01\x02\0\x12\x03\"\x04\x1e\n\
-    \r\n\x05\x04\x01\x02\0\x04\x12\x04\"\x04!\x17\n\x0c\n\x05\x04\x01\x02\0\
-    \x06\x12\x03\"\x04\x16\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\"\x17\x19\n\
-    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03\"\x1c\x1d\n\x0b\n\x04\x04\x01\x02\
-    \x01\x12\x03#\x04\x12\n\r\n\x05\x04\x01\x02\x01\x04\x12\x04#\x04\"\x1e\n\
-    \x0c\n\x05\x04\x01\x02\x01\x05\x12\x03#\x04\n\n\x0c\n\x05\x04\x01\x02\
-    \x01\x01\x12\x03#\x0b\r\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03#\x10\x11\
-    \n\x0b\n\x04\x04\x01\x02\x02\x12\x03$\x04\x14\n\r\n\x05\x04\x01\x02\x02\
-    \x04\x12\x04$\x04#\x12\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03$\x04\n\n\
-    \x0c\n\x05\x04\x01\x02\x02\x01\x12\x03$\x0b\x0f\n\x0c\n\x05\x04\x01\x02\
-    \x02\x03\x12\x03$\x12\x13\n\n\n\x02\x04\x02\x12\x04'\0)\x01\n\n\n\x03\
-    \x04\x02\x01\x12\x03'\x08\x10\n\x0b\n\x04\x04\x02\x02\0\x12\x03(\x04\x14\
-    \n\r\n\x05\x04\x02\x02\0\x04\x12\x04(\x04'\x12\n\x0c\n\x05\x04\x02\x02\0\
-    \x06\x12\x03(\x04\x0b\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03(\x0c\x0f\n\
-    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03(\x12\x13\n\n\n\x02\x04\x03\x12\x04+\
-    \0/\x01\n\n\n\x03\x04\x03\x01\x12\x03+\x08\x0f\n\x0b\n\x04\x04\x03\x02\0\
-    \x12\x03,\x04\x19\n\r\n\x05\x04\x03\x02\0\x04\x12\x04,\x04+\x11\n\x0c\n\
-    \x05\x04\x03\x02\0\x05\x12\x03,\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\
-    \x03,\x0b\x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03,\x17\x18\n\x0b\n\x04\
-    \x04\x03\x02\x01\x12\x03-\x04\x17\n\r\n\x05\x04\x03\x02\x01\x04\x12\x04-\
-    \x04,\x19\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03-\x04\n\n\x0c\n\x05\x04\
-    \x03\x02\x01\x01\x12\x03-\x0b\x12\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\
-    \x03-\x15\x16\n\x0b\n\x04\x04\x03\x02\x02\x12\x03.\x04\x19\n\r\n\x05\x04\
-    \x03\x02\x02\x04\x12\x04.\x04-\x17\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\
-    \x03.\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03.\x0b\x14\n\x0c\n\x05\
-    \x04\x03\x02\x02\x03\x12\x03.\x17\x18\n\n\n\x02\x04\x04\x12\x041\05\x01\
-    \n\n\n\x03\x04\x04\x01\x12\x031\x08\x10\n\x0b\n\x04\x04\x04\x02\0\x12\
-    \x032\x04\x19\n\r\n\x05\x04\x04\x02\0\x04\x12\x042\x041\x12\n\x0c\n\x05\
-    \x04\x04\x02\0\x05\x12\x032\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x032\
-    \x0b\x14\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x032\x17\x18\n\x0b\n\x04\x04\
-    \x04\x02\x01\x12\x033\x04\x17\n\r\n\x05\x04\x04\x02\x01\x04\x12\x043\x04\
-    2\x19\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x033\x04\n\n\x0c\n\x05\x04\x04\
-    \x02\x01\x01\x12\x033\x0b\x12\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x033\
-    \x15\x16\n\x0b\n\x04\x04\x04\x02\x02\x12\x034\x04\x19\n\r\n\x05\x04\x04\
-    \x02\x02\x04\x12\x044\x043\x17\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\x034\
-    \x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x034\x0b\x14\n\x0c\n\x05\x04\
-    \x04\x02\x02\x03\x12\x034\x17\x18\n\n\n\x02\x04\x05\x12\x047\0:\x01\n\n\
-    \n\x03\x04\x05\x01\x12\x037\x08\x10\n\x0b\n\x04\x04\x05\x02\0\x12\x038\
-    \x04\x19\n\r\n\x05\x04\x05\x02\0\x04\x12\x048\x047\x12\n\x0c\n\x05\x04\
-    \x05\x02\0\x05\x12\x038\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x038\x0b\
-    \x14\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x038\x17\x18\n\x0b\n\x04\x04\x05\
-    \x02\x01\x12\x039\x04\x13\n\r\n\x05\x04\x05\x02\x01\x04\x12\x049\x048\
-    \x19\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x039\x04\n\n\x0c\n\x05\x04\x05\
-    \x02\x01\x01\x12\x039\x0b\x0e\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x039\
-    \x11\x12\n\n\n\x02\x04\x06\x12\x04<\0?\x01\n\n\n\x03\x04\x06\x01\x12\x03\
-    <\x08\x11\n\x0b\n\x04\x04\x06\x02\0\x12\x03=\x04\x15\n\r\n\x05\x04\x06\
-    \x02\0\x04\x12\x04=\x04<\x13\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03=\x04\
-    \n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03=\x0b\x10\n\x0c\n\x05\x04\x06\
-    \x02\0\x03\x12\x03=\x13\x14\n\x0b\n\x04\x04\x06\x02\x01\x12\x03>\x04\x14\
-    \n\r\n\x05\x04\x06\x02\x01\x04\x12\x04>\x04=\x15\n\x0c\n\x05\x04\x06\x02\
-    \x01\x06\x12\x03>\x04\x0b\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03>\x0c\
-    \x0f\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03>\x12\x13\n\n\n\x02\x04\x07\
-    \x12\x04A\0D\x01\n\n\n\x03\x04\x07\x01\x12\x03A\x08\x10\n\x0b\n\x04\x04\
-    \x07\x02\0\x12\x03B\x04\x19\n\r\n\x05\x04\x07\x02\0\x04\x12\x04B\x04A\
-    \x12\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03B\x04\n\n\x0c\n\x05\x04\x07\
-    \x02\0\x01\x12\x03B\x0b\x14\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03B\x17\
-    \x18\n\x0b\n\x04\x04\x07\x02\x01\x12\x03C\x04\x13\n\r\n\x05\x04\x07\x02\
-    \x01\x04\x12\x04C\x04B\x19\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03C\x04\
-    \n\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x03C\x0b\x0e\n\x0c\n\x05\x04\x07\
-    \x02\x01\x03\x12\x03C\x11\x12\n\n\n\x02\x04\x08\x12\x04F\0I\x01\n\n\n\
-    \x03\x04\x08\x01\x12\x03F\x08\x11\n\x0b\n\x04\x04\x08\x02\0\x12\x03G\x04\
-    \x15\n\r\n\x05\x04\x08\x02\0\x04\x12\x04G\x04F\x13\n\x0c\n\x05\x04\x08\
-    \x02\0\x05\x12\x03G\x04\n\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03G\x0b\x10\
-    \n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03G\x13\x14\n\x0b\n\x04\x04\x08\x02\
-    \x01\x12\x03H\x04\x14\n\r\n\x05\x04\x08\x02\x01\x04\x12\x04H\x04G\x15\n\
-    \x0c\n\x05\x04\x08\x02\x01\x06\x12\x03H\x04\x0b\n\x0c\n\x05\x04\x08\x02\
-    \x01\x01\x12\x03H\x0c\x0f\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03H\x12\
-    \x13\n\n\n\x02\x04\t\x12\x04K\0N\x01\n\n\n\x03\x04\t\x01\x12\x03K\x08\
-    \x12\n\x0b\n\x04\x04\t\x02\0\x12\x03L\x04\x19\n\r\n\x05\x04\t\x02\0\x04\
-    \x12\x04L\x04K\x14\n\x0c\n\x05\x04\t\x02\0\x05\x12\x03L\x04\n\n\x0c\n\
-    \x05\x04\t\x02\0\x01\x12\x03L\x0b\x14\n\x0c\n\x05\x04\t\x02\0\x03\x12\
-    \x03L\x17\x18\n\x0b\n\x04\x04\t\x02\x01\x12\x03M\x04\x13\n\r\n\x05\x04\t\
-    \x02\x01\x04\x12\x04M\x04L\x19\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03M\
-    \x04\n\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03M\x0b\x0e\n\x0c\n\x05\x04\t\
-    \x02\x01\x03\x12\x03M\x11\x12\n\n\n\x02\x04\n\x12\x04P\0S\x01\n\n\n\x03\
-    \x04\n\x01\x12\x03P\x08\x13\n\x0b\n\x04\x04\n\x02\0\x12\x03Q\x04\x15\n\r\
-    \n\x05\x04\n\x02\0\x04\x12\x04Q\x04P\x15\n\x0c\n\x05\x04\n\x02\0\x05\x12\
-    \x03Q\x04\n\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03Q\x0b\x10\n\x0c\n\x05\x04\
-    \n\x02\0\x03\x12\x03Q\x13\x14\n\x0b\n\x04\x04\n\x02\x01\x12\x03R\x04\x14\
-    \n\r\n\x05\x04\n\x02\x01\x04\x12\x04R\x04Q\x15\n\x0c\n\x05\x04\n\x02\x01\
-    \x06\x12\x03R\x04\x0b\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03R\x0c\x0f\n\
-    \x0c\n\x05\x04\n\x02\x01\x03\x12\x03R\x12\x13\n\n\n\x02\x04\x0b\x12\x04U\
-    \0Y\x01\n\n\n\x03\x04\x0b\x01\x12\x03U\x08\x0e\n\x0b\n\x04\x04\x0b\x02\0\
-    \x12\x03V\x04\x19\n\r\n\x05\x04\x0b\x02\0\x04\x12\x04V\x04U\x10\n\x0c\n\
-    \x05\x04\x0b\x02\0\x05\x12\x03V\x04\n\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\
-    \x03V\x0b\x14\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03V\x17\x18\n\x0b\n\x04\
-    \x04\x0b\x02\x01\x12\x03W\x04\x13\n\r\n\x05\x04\x0b\x02\x01\x04\x12\x04W\
-    \x04V\x19\n\x0c\n\x05\x04\x0b\x02\x01\x05\x12\x03W\x04\n\n\x0c\n\x05\x04\
-    \x0b\x02\x01\x01\x12\x03W\x0b\x0e\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\
-    \x03W\x11\x12\n\x0b\n\x04\x04\x0b\x02\x02\x12\x03X\x04\x16\n\r\n\x05\x04\
-    \x0b\x02\x02\x04\x12\x04X\x04W\x13\n\x0c\n\x05\x04\x0b\x02\x02\x05\x12\
-    \x03X\x04\n\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\x03X\x0b\x11\n\x0c\n\x05\
-    \x04\x0b\x02\x02\x03\x12\x03X\x14\x15\n\n\n\x02\x04\x0c\x12\x04[\0^\x01\
-    \n\n\n\x03\x04\x0c\x01\x12\x03[\x08\x0f\n\x0b\n\x04\x04\x0c\x02\0\x12\
-    \x03\\\x04\x15\n\r\n\x05\x04\x0c\x02\0\x04\x12\x04\\\x04[\x11\n\x0c\n\
-    \x05\x04\x0c\x02\0\x05\x12\x03\\\x04\n\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\
-    \x03\\\x0b\x10\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03\\\x13\x14\n\x0b\n\
-    \x04\x04\x0c\x02\x01\x12\x03]\x04\x14\n\r\n\x05\x04\x0c\x02\x01\x04\x12\
-    \x04]\x04\\\x15\n\x0c\n\x05\x04\x0c\x02\x01\x06\x12\x03]\x04\x0b\n\x0c\n\
-    \x05\x04\x0c\x02\x01\x01\x12\x03]\x0c\x0f\n\x0c\n\x05\x04\x0c\x02\x01\
-    \x03\x12\x03]\x12\x13\n\n\n\x02\x04\r\x12\x04`\0e\x01\n\n\n\x03\x04\r\
-    \x01\x12\x03`\x08\x0e\n\x0b\n\x04\x04\r\x02\0\x12\x03a\x04\x19\n\r\n\x05\
-    \x04\r\x02\0\x04\x12\x04a\x04`\x10\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03a\
-    \x04\n\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03a\x0b\x14\n\x0c\n\x05\x04\r\
-    \x02\0\x03\x12\x03a\x17\x18\n\x0b\n\x04\x04\r\x02\x01\x12\x03b\x04\x13\n\
-    \r\n\x05\x04\r\x02\x01\x04\x12\x04b\x04a\x19\n\x0c\n\x05\x04\r\x02\x01\
-    \x05\x12\x03b\x04\n\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03b\x0b\x0e\n\x0c\
-    \n\x05\x04\r\x02\x01\x03\x12\x03b\x11\x12\n\x0b\n\x04\x04\r\x02\x02\x12\
-    \x03c\x04\x16\n\r\n\x05\x04\r\x02\x02\x04\x12\x04c\x04b\x13\n\x0c\n\x05\
-    \x04\r\x02\x02\x05\x12\x03c\x04\n\n\x0c\n\x05\x04\r\x02\x02\x01\x12\x03c\
-    \x0b\x11\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03c\x14\x15\n\x0b\n\x04\x04\
-    \r\x02\x03\x12\x03d\x04\x16\n\r\n\x05\x04\r\x02\x03\x04\x12\x04d\x04c\
-    \x16\n\x0c\n\x05\x04\r\x02\x03\x05\x12\x03d\x04\n\n\x0c\n\x05\x04\r\x02\
-    \x03\x01\x12\x03d\x0b\x11\n\x0c\n\x05\x04\r\x02\x03\x03\x12\x03d\x14\x15\
-    \n\n\n\x02\x04\x0e\x12\x04g\0j\x01\n\n\n\x03\x04\x0e\x01\x12\x03g\x08\
-    \x0f\n\x0b\n\x04\x04\x0e\x02\0\x12\x03h\x04\x15\n\r\n\x05\x04\x0e\x02\0\
-    \x04\x12\x04h\x04g\x11\n\x0c\n\x05\x04\x0e\x02\0\x05\x12\x03h\x04\n\n\
-    \x0c\n\x05\x04\x0e\x02\0\x01\x12\x03h\x0b\x10\n\x0c\n\x05\x04\x0e\x02\0\
-    \x03\x12\x03h\x13\x14\n\x0b\n\x04\x04\x0e\x02\x01\x12\x03i\x04\x14\n\r\n\
-    \x05\x04\x0e\x02\x01\x04\x12\x04i\x04h\x15\n\x0c\n\x05\x04\x0e\x02\x01\
-    \x06\x12\x03i\x04\x0b\n\x0c\n\x05\x04\x0e\x02\x01\x01\x12\x03i\x0c\x0f\n\
-    \x0c\n\x05\x04\x0e\x02\x01\x03\x12\x03i\x12\x13\n\n\n\x02\x04\x0f\x12\
-    \x04l\0p\x01\n\n\n\x03\x04\x0f\x01\x12\x03l\x08\x11\n\x0b\n\x04\x04\x0f\
-    \x02\0\x12\x03m\x04\x19\n\r\n\x05\x04\x0f\x02\0\x04\x12\x04m\x04l\x13\n\
-    \x0c\n\x05\x04\x0f\x02\0\x05\x12\x03m\x04\n\n\x0c\n\x05\x04\x0f\x02\0\
-    \x01\x12\x03m\x0b\x14\n\x0c\n\x05\x04\x0f\x02\0\x03\x12\x03m\x17\x18\n\
-    \x0b\n\x04\x04\x0f\x02\x01\x12\x03n\x04\x13\n\r\n\x05\x04\x0f\x02\x01\
-    \x04\x12\x04n\x04m\x19\n\x0c\n\x05\x04\x0f\x02\x01\x05\x12\x03n\x04\n\n\
-    \x0c\n\x05\x04\x0f\x02\x01\x01\x12\x03n\x0b\x0e\n\x0c\n\x05\x04\x0f\x02\
-    \x01\x03\x12\x03n\x11\x12\n\x0b\n\x04\x04\x0f\x02\x02\x12\x03o\x04\x16\n\
-    \r\n\x05\x04\x0f\x02\x02\x04\x12\x04o\x04n\x13\n\x0c\n\x05\x04\x0f\x02\
-    \x02\x05\x12\x03o\x04\n\n\x0c\n\x05\x04\x0f\x02\x02\x01\x12\x03o\x0b\x11\
-    \n\x0c\n\x05\x04\x0f\x02\x02\x03\x12\x03o\x14\x15\n\n\n\x02\x04\x10\x12\
-    \x04r\0u\x01\n\n\n\x03\x04\x10\x01\x12\x03r\x08\x12\n\x0b\n\x04\x04\x10\
-    \x02\0\x12\x03s\x04\x15\n\r\n\x05\x04\x10\x02\0\x04\x12\x04s\x04r\x14\n\
-    \x0c\n\x05\x04\x10\x02\0\x05\x12\x03s\x04\n\n\x0c\n\x05\x04\x10\x02\0\
-    \x01\x12\x03s\x0b\x10\n\x0c\n\x05\x04\x10\x02\0\x03\x12\x03s\x13\x14\n\
-    \x0b\n\x04\x04\x10\x02\x01\x12\x03t\x04\x14\n\r\n\x05\x04\x10\x02\x01\
-    \x04\x12\x04t\x04s\x15\n\x0c\n\x05\x04\x10\x02\x01\x06\x12\x03t\x04\x0b\
-    \n\x0c\n\x05\x04\x10\x02\x01\x01\x12\x03t\x0c\x0f\n\x0c\n\x05\x04\x10\
-    \x02\x01\x03\x12\x03t\x12\x13\n\n\n\x02\x04\x11\x12\x04w\0z\x01\n\n\n\
-    \x03\x04\x11\x01\x12\x03w\x08\x11\n\x0b\n\x04\x04\x11\x02\0\x12\x03x\x04\
-    \x19\n\r\n\x05\x04\x11\x02\0\x04\x12\x04x\x04w\x13\n\x0c\n\x05\x04\x11\
-    \x02\0\x05\x12\x03x\x04\n\n\x0c\n\x05\x04\x11\x02\0\x01\x12\x03x\x0b\x14\
-    \n\x0c\n\x05\x04\x11\x02\0\x03\x12\x03x\x17\x18\n\x0b\n\x04\x04\x11\x02\
-    \x01\x12\x03y\x04\x13\n\r\n\x05\x04\x11\x02\x01\x04\x12\x04y\x04x\x19\n\
-    \x0c\n\x05\x04\x11\x02\x01\x05\x12\x03y\x04\n\n\x0c\n\x05\x04\x11\x02\
-    \x01\x01\x12\x03y\x0b\x0e\n\x0c\n\x05\x04\x11\x02\x01\x03\x12\x03y\x11\
-    \x12\n\n\n\x02\x04\x12\x12\x04|\0\x7f\x01\n\n\n\x03\x04\x12\x01\x12\x03|\
-    \x08\x12\n\x0b\n\x04\x04\x12\x02\0\x12\x03}\x04\x15\n\r\n\x05\x04\x12\
-    \x02\0\x04\x12\x04}\x04|\x14\n\x0c\n\x05\x04\x12\x02\0\x05\x12\x03}\x04\
-    \n\n\x0c\n\x05\x04\x12\x02\0\x01\x12\x03}\x0b\x10\n\x0c\n\x05\x04\x12\
-    \x02\0\x03\x12\x03}\x13\x14\n\x0b\n\x04\x04\x12\x02\x01\x12\x03~\x04\x14\
-    \n\r\n\x05\x04\x12\x02\x01\x04\x12\x04~\x04}\x15\n\x0c\n\x05\x04\x12\x02\
-    \x01\x06\x12\x03~\x04\x0b\n\x0c\n\x05\x04\x12\x02\x01\x01\x12\x03~\x0c\
-    \x0f\n\x0c\n\x05\x04\x12\x02\x01\x03\x12\x03~\x12\x13\n\x0c\n\x02\x04\
-    \x13\x12\x06\x81\x01\0\x84\x01\x01\n\x0b\n\x03\x04\x13\x01\x12\x04\x81\
-    \x01\x08\x13\n\x0c\n\x04\x04\x13\x02\0\x12\x04\x82\x01\x04\x19\n\x0f\n\
-    \x05\x04\x13\x02\0\x04\x12\x06\x82\x01\x04\x81\x01\x15\n\r\n\x05\x04\x13\
-    \x02\0\x05\x12\x04\x82\x01\x04\n\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\x82\
-    \x01\x0b\x14\n\r\n\x05\x04\x13\x02\0\x03\x12\x04\x82\x01\x17\x18\n\x0c\n\
-    \x04\x04\x13\x02\x01\x12\x04\x83\x01\x04\x13\n\x0f\n\x05\x04\x13\x02\x01\
-    \x04\x12\x06\x83\x01\x04\x82\x01\x19\n\r\n\x05\x04\x13\x02\x01\x05\x12\
-    \x04\x83\x01\x04\n\n\r\n\x05\x04\x13\x02\x01\x01\x12\x04\x83\x01\x0b\x0e\
-    \n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\x83\x01\x11\x12\n\x0c\n\x02\x04\
-    \x14\x12\x06\x86\x01\0\x89\x01\x01\n\x0b\n\x03\x04\x14\x01\x12\x04\x86\
-    \x01\x08\x14\n\x0c\n\x04\x04\x14\x02\0\x12\x04\x87\x01\x04\x15\n\x0f\n\
-    \x05\x04\x14\x02\0\x04\x12\x06\x87\x01\x04\x86\x01\x16\n\r\n\x05\x04\x14\
-    \x02\0\x05\x12\x04\x87\x01\x04\n\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\x87\
-    \x01\x0b\x10\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\x87\x01\x13\x14\n\x0c\n\
-    \x04\x04\x14\x02\x01\x12\x04\x88\x01\x04\x14\n\x0f\n\x05\x04\x14\x02\x01\
-    \x04\x12\x06\x88\x01\x04\x87\x01\x15\n\r\n\x05\x04\x14\x02\x01\x06\x12\
-    \x04\x88\x01\x04\x0b\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\x88\x01\x0c\
-    \x0f\n\r\n\x05\x04\x14\x02\x01\x03\x12\x04\x88\x01\x12\x13\n\x0c\n\x02\
-    \x04\x15\x12\x06\x8b\x01\0\x8e\x01\x01\n\x0b\n\x03\x04\x15\x01\x12\x04\
-    \x8b\x01\x08\x10\n\x0c\n\x04\x04\x15\x02\0\x12\x04\x8c\x01\x04\x19\n\x0f\
-    \n\x05\x04\x15\x02\0\x04\x12\x06\x8c\x01\x04\x8b\x01\x12\n\r\n\x05\x04\
-    \x15\x02\0\x05\x12\x04\x8c\x01\x04\n\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\
-    \x8c\x01\x0b\x14\n\r\n\x05\x04\x15\x02\0\x03\x12\x04\x8c\x01\x17\x18\n\
-    \x0c\n\x04\x04\x15\x02\x01\x12\x04\x8d\x01\x04\x13\n\x0f\n\x05\x04\x15\
-    \x02\x01\x04\x12\x06\x8d\x01\x04\x8c\x01\x19\n\r\n\x05\x04\x15\x02\x01\
-    \x05\x12\x04\x8d\x01\x04\n\n\r\n\x05\x04\x15\x02\x01\x01\x12\x04\x8d\x01\
-    \x0b\x0e\n\r\n\x05\x04\x15\x02\x01\x03\x12\x04\x8d\x01\x11\x12\n\x0c\n\
-    \x02\x04\x16\x12\x06\x90\x01\0\x93\x01\x01\n\x0b\n\x03\x04\x16\x01\x12\
-    \x04\x90\x01\x08\x11\n\x0c\n\x04\x04\x16\x02\0\x12\x04\x91\x01\x04\x15\n\
-    \x0f\n\x05\x04\x16\x02\0\x04\x12\x06\x91\x01\x04\x90\x01\x13\n\r\n\x05\
-    \x04\x16\x02\0\x05\x12\x04\x91\x01\x04\n\n\r\n\x05\x04\x16\x02\0\x01\x12\
-    \x04\x91\x01\x0b\x10\n\r\n\x05\x04\x16\x02\0\x03\x12\x04\x91\x01\x13\x14\
-    \n\x0c\n\x04\x04\x16\x02\x01\x12\x04\x92\x01\x04\x14\n\x0f\n\x05\x04\x16\
-    \x02\x01\x04\x12\x06\x92\x01\x04\x91\x01\x15\n\r\n\x05\x04\x16\x02\x01\
-    \x06\x12\x04\x92\x01\x04\x0b\n\r\n\x05\x04\x16\x02\x01\x01\x12\x04\x92\
-    \x01\x0c\x0f\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\x92\x01\x12\x13\n\x0c\
-    \n\x02\x04\x17\x12\x06\x95\x01\0\xa0\x01\x01\n\x0b\n\x03\x04\x17\x01\x12\
-    \x04\x95\x01\x08\x11\n\x0c\n\x04\x04\x17\x02\0\x12\x04\x96\x01\x04\x19\n\
-    \x0f\n\x05\x04\x17\x02\0\x04\x12\x06\x96\x01\x04\x95\x01\x13\n\r\n\x05\
-    \x04\x17\x02\0\x05\x12\x04\x96\x01\x04\n\n\r\n\x05\x04\x17\x02\0\x01\x12\
-    \x04\x96\x01\x0b\x14\n\r\n\x05\x04\x17\x02\0\x03\x12\x04\x96\x01\x17\x18\
-    \n\x0c\n\x04\x04\x17\x02\x01\x12\x04\x97\x01\x04\x13\n\x0f\n\x05\x04\x17\
-    \x02\x01\x04\x12\x06\x97\x01\x04\x96\x01\x19\n\r\n\x05\x04\x17\x02\x01\
-    \x05\x12\x04\x97\x01\x04\n\n\r\n\x05\x04\x17\x02\x01\x01\x12\x04\x97\x01\
-    \x0b\x0e\n\r\n\x05\x04\x17\x02\x01\x03\x12\x04\x97\x01\x11\x12\n\x0c\n\
-    \x04\x04\x17\x02\x02\x12\x04\x98\x01\x04\x15\n\x0f\n\x05\x04\x17\x02\x02\
-    \x04\x12\x06\x98\x01\x04\x97\x01\x13\n\r\n\x05\x04\x17\x02\x02\x05\x12\
-    \x04\x98\x01\x04\n\n\r\n\x05\x04\x17\x02\x02\x01\x12\x04\x98\x01\x0b\x10\
-    \n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\x98\x01\x13\x14\n\x0c\n\x04\x04\
-    \x17\x02\x03\x12\x04\x99\x01\x04\x14\n\x0f\n\x05\x04\x17\x02\x03\x04\x12\
-    \x06\x99\x01\x04\x98\x01\x15\n\r\n\x05\x04\x17\x02\x03\x05\x12\x04\x99\
-    \x01\x04\n\n\r\n\x05\x04\x17\x02\x03\x01\x12\x04\x99\x01\x0b\x0f\n\r\n\
-    \x05\x04\x17\x02\x03\x03\x12\x04\x99\x01\x12\x13\n\x0c\n\x04\x04\x17\x02\
-    \x04\x12\x04\x9a\x01\x04\x15\n\x0f\n\x05\x04\x17\x02\x04\x04\x12\x06\x9a\
-    \x01\x04\x99\x01\x14\n\r\n\x05\x04\x17\x02\x04\x05\x12\x04\x9a\x01\x04\n\
-    \n\r\n\x05\x04\x17\x02\x04\x01\x12\x04\x9a\x01\x0b\x10\n\r\n\x05\x04\x17\
-    \x02\x04\x03\x12\x04\x9a\x01\x13\x14\n\x0c\n\x04\x04\x17\x02\x05\x12\x04\
-    \x9b\x01\x04\x1b\n\x0f\n\x05\x04\x17\x02\x05\x04\x12\x06\x9b\x01\x04\x9a\
-    \x01\x15\n\r\n\x05\x04\x17\x02\x05\x05\x12\x04\x9b\x01\x04\x08\n\r\n\x05\
-    \x04\x17\x02\x05\x01\x12\x04\x9b\x01\t\x16\n\r\n\x05\x04\x17\x02\x05\x03\
-    \x12\x04\x9b\x01\x19\x1a\n\x0c\n\x04\x04\x17\x02\x06\x12\x04\x9c\x01\x04\
-    \x1a\n\x0f\n\x05\x04\x17\x02\x06\x04\x12\x06\x9c\x01\x04\x9b\x01\x1b\n\r\
-    \n\x05\x04\x17\x02\x06\x05\x12\x04\x9c\x01\x04\x08\n\r\n\x05\x04\x17\x02\
-    \x06\x01\x12\x04\x9c\x01\t\x15\n\r\n\x05\x04\x17\x02\x06\x03\x12\x04\x9c\
-    \x01\x18\x19\n\x0c\n\x04\x04\x17\x02\x07\x12\x04\x9d\x01\x04\x1b\n\x0f\n\
-    \x05\x04\x17\x02\x07\x04\x12\x06\x9d\x01\x04\x9c\x01\x1a\n\r\n\x05\x04\
-    \x17\x02\x07\x05\x12\x04\x9d\x01\x04\n\n\r\n\x05\x04\x17\x02\x07\x01\x12\
-    \x04\x9d\x01\x0b\x16\n\r\n\x05\x04\x17\x02\x07\x03\x12\x04\x9d\x01\x19\
-    \x1a\n/\n\x04\x04\x17\x02\x08\x12\x04\x9e\x01\x04'\"!\x20\x20\x20\x20rep\
-    eated\x20string\x20facets\x20=\x2010;\n\n\r\n\x05\x04\x17\x02\x08\x04\
-    \x12\x04\x9e\x01\x04\x0c\n\r\n\x05\x04\x17\x02\x08\x05\x12\x04\x9e\x01\r\
-    \x13\n\r\n\x05\x04\x17\x02\x08\x01\x12\x04\x9e\x01\x14\"\n\r\n\x05\x04\
-    \x17\x02\x08\x03\x12\x04\x9e\x01%&\n\x0c\n\x02\x04\x18\x12\x06\xa2\x01\0\
-    \xa5\x01\x01\n\x0b\n\x03\x04\x18\x01\x12\x04\xa2\x01\x08\x12\n\x0c\n\x04\
-    \x04\x18\x02\0\x12\x04\xa3\x01\x04\x15\n\x0f\n\x05\x04\x18\x02\0\x04\x12\
-    \x06\xa3\x01\x04\xa2\x01\x14\n\r\n\x05\x04\x18\x02\0\x05\x12\x04\xa3\x01\
-    \x04\n\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\xa3\x01\x0b\x10\n\r\n\x05\x04\
-    \x18\x02\0\x03\x12\x04\xa3\x01\x13\x14\n\x0c\n\x04\x04\x18\x02\x01\x12\
-    \x04\xa4\x01\x04\x14\n\x0f\n\x05\x04\x18\x02\x01\x04\x12\x06\xa4\x01\x04\
-    \xa3\x01\x15\n\r\n\x05\x04\x18\x02\x01\x06\x12\x04\xa4\x01\x04\x0b\n\r\n\
-    \x05\x04\x18\x02\x01\x01\x12\x04\xa4\x01\x0c\x0f\n\r\n\x05\x04\x18\x02\
-    \x01\x03\x12\x04\xa4\x01\x12\x13\n\x0c\n\x02\x04\x19\x12\x06\xa7\x01\0\
-    \xaa\x01\x01\n\x0b\n\x03\x04\x19\x01\x12\x04\xa7\x01\x08\x11\n\x0c\n\x04\
-    \x04\x19\x02\0\x12\x04\xa8\x01\x04\x19\n\x0f\n\x05\x04\x19\x02\0\x04\x12\
-    \x06\xa8\x01\x04\xa7\x01\x13\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\xa8\x01\
-    \x04\n\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\xa8\x01\x0b\x14\n\r\n\x05\x04\
-    \x19\x02\0\x03\x12\x04\xa8\x01\x17\x18\n\x0c\n\x04\x04\x19\x02\x01\x12\
-    \x04\xa9\x01\x04\x13\n\x0f\n\x05\x04\x19\x02\x01\x04\x12\x06\xa9\x01\x04\
-    \xa8\x01\x19\n\r\n\x05\x04\x19\x02\x01\x05\x12\x04\xa9\x01\x04\n\n\r\n\
-    \x05\x04\x19\x02\x01\x01\x12\x04\xa9\x01\x0b\x0e\n\r\n\x05\x04\x19\x02\
-    \x01\x03\x12\x04\xa9\x01\x11\x12\n\x0c\n\x02\x04\x1a\x12\x06\xac\x01\0\
-    \xaf\x01\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\xac\x01\x08\x12\n\x0c\n\x04\
-    \x04\x1a\x02\0\x12\x04\xad\x01\x04\x15\n\x0f\n\x05\x04\x1a\x02\0\x04\x12\
-    \x06\xad\x01\x04\xac\x01\x14\n\r\n\x05\x04\x1a\x02\0\x05\x12\x04\xad\x01\
-    \x04\n\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\xad\x01\x0b\x10\n\r\n\x05\x04\
-    \x1a\x02\0\x03\x12\x04\xad\x01\x13\x14\n\x0c\n\x04\x04\x1a\x02\x01\x12\
-    \x04\xae\x01\x04\x14\n\x0f\n\x05\x04\x1a\x02\x01\x04\x12\x06\xae\x01\x04\
-    \xad\x01\x15\n\r\n\x05\x04\x1a\x02\x01\x06\x12\x04\xae\x01\x04\x0b\n\r\n\
-    \x05\x04\x1a\x02\x01\x01\x12\x04\xae\x01\x0c\x0f\n\r\n\x05\x04\x1a\x02\
-    \x01\x03\x12\x04\xae\x01\x12\x13b\x06proto3\
+    \x05\0\x02\x02\x02\x12\x03\x08\x0f\x10\n\x0b\n\x04\x05\0\x02\x03\x12\x03\
+    \t\x04\x14\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\t\x04\x0f\n\x0c\n\x05\
+    \x05\0\x02\x03\x02\x12\x03\t\x12\x13\n\x0b\n\x04\x05\0\x02\x04\x12\x03\n\
+    \x04\x18\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\n\x04\x13\n\x0c\n\x05\x05\
+    \0\x02\x04\x02\x12\x03\n\x16\x17\n\x0b\n\x04\x05\0\x02\x05\x12\x03\x0b\
+    \x04\x1b\n\x0c\n\x05\x05\0\x02\x05\x01\x12\x03\x0b\x04\x16\n\x0c\n\x05\
+    \x05\0\x02\x05\x02\x12\x03\x0b\x19\x1a\n\x0b\n\x04\x05\0\x02\x06\x12\x03\
+    \x0c\x04\x18\n\x0c\n\x05\x05\0\x02\x06\x01\x12\x03\x0c\x04\x13\n\x0c\n\
+    \x05\x05\0\x02\x06\x02\x12\x03\x0c\x16\x17\n\x0b\n\x04\x05\0\x02\x07\x12\
+    \x03\r\x04\x1a\n\x0c\n\x05\x05\0\x02\x07\x01\x12\x03\r\x04\x15\n\x0c\n\
+    \x05\x05\0\x02\x07\x02\x12\x03\r\x18\x19\n\x0b\n\x04\x05\0\x02\x08\x12\
+    \x03\x0e\x04\x14\n\x0c\n\x05\x05\0\x02\x08\x01\x12\x03\x0e\x04\x0f\n\x0c\
+    \n\x05\x05\0\x02\x08\x02\x12\x03\x0e\x12\x13\n\n\n\x02\x05\x01\x12\x04\
+    \x11\0\x1b\x01\n\n\n\x03\x05\x01\x01\x12\x03\x11\x05\x0c\n\x0b\n\x04\x05\
+    \x01\x02\0\x12\x03\x12\x04\r\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\x12\
+    \x04\x08\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\x12\x0b\x0c\n\x0b\n\x04\
+    \x05\x01\x02\x01\x12\x03\x13\x04\x0e\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\
+    \x03\x13\x04\t\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\x13\x0c\r\n\x0b\n\
+    \x04\x05\x01\x02\x02\x12\x03\x14\x04\x0c\n\x0c\n\x05\x05\x01\x02\x02\x01\
+    \x12\x03\x14\x04\x07\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x14\n\x0b\n\
+    \x0b\n\x04\x05\x01\x02\x03\x12\x03\x15\x04\x0f\n\x0c\n\x05\x05\x01\x02\
+    \x03\x01\x12\x03\x15\x04\n\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x15\r\
+    \x0e\n\x0b\n\x04\x05\x01\x02\x04\x12\x03\x16\x04\x0f\n\x0c\n\x05\x05\x01\
+    \x02\x04\x01\x12\x03\x16\x04\n\n\x0c\n\x05\x05\x01\x02\x04\x02\x12\x03\
+    \x16\r\x0e\n\x0b\n\x04\x05\x01\x02\x05\x12\x03\x17\x04\x11\n\x0c\n\x05\
+    \x05\x01\x02\x05\x01\x12\x03\x17\x04\x0c\n\x0c\n\x05\x05\x01\x02\x05\x02\
+    \x12\x03\x17\x0f\x10\n\x0b\n\x04\x05\x01\x02\x06\x12\x03\x18\x04\x0e\n\
+    \x0c\n\x05\x05\x01\x02\x06\x01\x12\x03\x18\x04\t\n\x0c\n\x05\x05\x01\x02\
+    \x06\x02\x12\x03\x18\x0c\r\n\x0b\n\x04\x05\x01\x02\x07\x12\x03\x19\x04\
+    \x0f\n\x0c\n\x05\x05\x01\x02\x07\x01\x12\x03\x19\x04\n\n\x0c\n\x05\x05\
+    \x01\x02\x07\x02\x12\x03\x19\r\x0e\n\x0b\n\x04\x05\x01\x02\x08\x12\x03\
+    \x1a\x04\x16\n\x0c\n\x05\x05\x01\x02\x08\x01\x12\x03\x1a\x04\x11\n\x0c\n\
+    \x05\x05\x01\x02\x08\x02\x12\x03\x1a\x14\x15\n\n\n\x02\x04\0\x12\x04\x1d\
+    \0)\x01\n\n\n\x03\x04\0\x01\x12\x03\x1d\x08\x10\n\x0b\n\x04\x04\0\x02\0\
+    \x12\x03\x1e\x04\x19\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x1e\x04\n\n\x0c\
+    \n\x05\x04\0\x02\0\x01\x12\x03\x1e\x0b\x14\n\x0c\n\x05\x04\0\x02\0\x03\
+    \x12\x03\x1e\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x1f\x04\x19\n\x0c\
+    \n\x05\x04\0\x02\x01\x06\x12\x03\x1f\x04\x0b\n\x0c\n\x05\x04\0\x02\x01\
+    \x01\x12\x03\x1f\x0c\x14\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x1f\x17\
+    \x18\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x20\x04\x19\n\x0c\n\x05\x04\0\x02\
+    \x02\x06\x12\x03\x20\x04\x0b\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x20\
+    \x0c\x14\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x20\x17\x18\n\x0b\n\x04\
+    \x04\0\x02\x03\x12\x03!\x04\x1b\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03!\
+    \x04\x0c\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03!\r\x16\n\x0c\n\x05\x04\0\
+    \x02\x03\x03\x12\x03!\x19\x1a\n\x0b\n\x04\x04\0\x02\x04\x12\x03\"\x04\
+    \x17\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\"\x04\n\n\x0c\n\x05\x04\0\x02\
+    \x04\x01\x12\x03\"\x0b\x12\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\"\x15\
+    \x16\n\x0b\n\x04\x04\0\x02\x05\x12\x03#\x04\x1d\n\x0c\n\x05\x04\0\x02\
+    \x05\x06\x12\x03#\x04\r\n\x0c\n\x05\x04\0\x02\x05\x01\x12\x03#\x0e\x18\n\
+    \x0c\n\x05\x04\0\x02\x05\x03\x12\x03#\x1b\x1c\n\x0b\n\x04\x04\0\x02\x06\
+    \x12\x03$\x04\x1d\n\x0c\n\x05\x04\0\x02\x06\x06\x12\x03$\x04\r\n\x0c\n\
+    \x05\x04\0\x02\x06\x01\x12\x03$\x0e\x18\n\x0c\n\x05\x04\0\x02\x06\x03\
+    \x12\x03$\x1b\x1c\n\x0b\n\x04\x04\0\x02\x07\x12\x03%\x04!\n\x0c\n\x05\
+    \x04\0\x02\x07\x06\x12\x03%\x04\x0f\n\x0c\n\x05\x04\0\x02\x07\x01\x12\
+    \x03%\x10\x1c\n\x0c\n\x05\x04\0\x02\x07\x03\x12\x03%\x1f\x20\n\x0b\n\x04\
+    \x04\0\x02\x08\x12\x03&\x04\x1b\n\x0c\n\x05\x04\0\x02\x08\x06\x12\x03&\
+    \x04\x0c\n\x0c\n\x05\x04\0\x02\x08\x01\x12\x03&\r\x16\n\x0c\n\x05\x04\0\
+    \x02\x08\x03\x12\x03&\x19\x1a\n\x0b\n\x04\x04\0\x02\t\x12\x03'\x04\x1e\n\
+    \x0c\n\x05\x04\0\x02\t\x06\x12\x03'\x04\r\n\x0c\n\x05\x04\0\x02\t\x01\
+    \x12\x03'\x0e\x18\n\x0c\n\x05\x04\0\x02\t\x03\x12\x03'\x1b\x1d\n\x0b\n\
+    \x04\x04\0\x02\n\x12\x03(\x04.\n\x0c\n\x05\x04\0\x02\n\x06\x12\x03(\x04\
+    \x14\n\x0c\n\x05\x04\0\x02\n\x01\x12\x03(\x15(\n\x0c\n\x05\x04\0\x02\n\
+    \x03\x12\x03(+-\n\n\n\x02\x04\x01\x12\x04+\0/\x01\n\n\n\x03\x04\x01\x01\
+    \x12\x03+\x08\x15\n\x0b\n\x04\x04\x01\x02\0\x12\x03,\x04\x1e\n\x0c\n\x05\
+    \x04\x01\x02\0\x06\x12\x03,\x04\x16\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
+    \x03,\x17\x19\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03,\x1c\x1d\n\x0b\n\x04\
+    \x04\x01\x02\x01\x12\x03-\x04\x12\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\
+    \x03-\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03-\x0b\r\n\x0c\n\x05\
+    \x04\x01\x02\x01\x03\x12\x03-\x10\x11\n\x0b\n\x04\x04\x01\x02\x02\x12\
+    \x03.\x04\x14\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03.\x04\n\n\x0c\n\x05\
+    \x04\x01\x02\x02\x01\x12\x03.\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\
+    \x12\x03.\x12\x13\n\n\n\x02\x04\x02\x12\x041\03\x01\n\n\n\x03\x04\x02\
+    \x01\x12\x031\x08\x10\n\x0b\n\x04\x04\x02\x02\0\x12\x032\x04\x14\n\x0c\n\
+    \x05\x04\x02\x02\0\x06\x12\x032\x04\x0b\n\x0c\n\x05\x04\x02\x02\0\x01\
+    \x12\x032\x0c\x0f\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x032\x12\x13\n\n\n\
+    \x02\x04\x03\x12\x045\09\x01\n\n\n\x03\x04\x03\x01\x12\x035\x08\x0f\n\
+    \x0b\n\x04\x04\x03\x02\0\x12\x036\x04\x19\n\x0c\n\x05\x04\x03\x02\0\x05\
+    \x12\x036\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x036\x0b\x14\n\x0c\n\
+    \x05\x04\x03\x02\0\x03\x12\x036\x17\x18\n\x0b\n\x04\x04\x03\x02\x01\x12\
+    \x037\x04\x17\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x037\x04\n\n\x0c\n\x05\
+    \x04\x03\x02\x01\x01\x12\x037\x0b\x12\n\x0c\n\x05\x04\x03\x02\x01\x03\
+    \x12\x037\x15\x16\n\x0b\n\x04\x04\x03\x02\x02\x12\x038\x04\x19\n\x0c\n\
+    \x05\x04\x03\x02\x02\x05\x12\x038\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\
+    \x12\x038\x0b\x14\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x038\x17\x18\n\n\n\
+    \x02\x04\x04\x12\x04;\0?\x01\n\n\n\x03\x04\x04\x01\x12\x03;\x08\x10\n\
+    \x0b\n\x04\x04\x04\x02\0\x12\x03<\x04\x19\n\x0c\n\x05\x04\x04\x02\0\x05\
+    \x12\x03<\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03<\x0b\x14\n\x0c\n\
+    \x05\x04\x04\x02\0\x03\x12\x03<\x17\x18\n\x0b\n\x04\x04\x04\x02\x01\x12\
+    \x03=\x04\x17\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03=\x04\n\n\x0c\n\x05\
+    \x04\x04\x02\x01\x01\x12\x03=\x0b\x12\n\x0c\n\x05\x04\x04\x02\x01\x03\
+    \x12\x03=\x15\x16\n\x0b\n\x04\x04\x04\x02\x02\x12\x03>\x04\x19\n\x0c\n\
+    \x05\x04\x04\x02\x02\x05\x12\x03>\x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\
+    \x12\x03>\x0b\x14\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03>\x17\x18\n\n\n\
+    \x02\x04\x05\x12\x04A\0D\x01\n\n\n\x03\x04\x05\x01\x12\x03A\x08\x10\n\
+    \x0b\n\x04\x04\x05\x02\0\x12\x03B\x04\x19\n\x0c\n\x05\x04\x05\x02\0\x05\
+    \x12\x03B\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03B\x0b\x14\n\x0c\n\
+    \x05\x04\x05\x02\0\x03\x12\x03B\x17\x18\n\x0b\n\x04\x04\x05\x02\x01\x12\
+    \x03C\x04\x13\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03C\x04\n\n\x0c\n\x05\
+    \x04\x05\x02\x01\x01\x12\x03C\x0b\x0e\n\x0c\n\x05\x04\x05\x02\x01\x03\
+    \x12\x03C\x11\x12\n\n\n\x02\x04\x06\x12\x04F\0I\x01\n\n\n\x03\x04\x06\
+    \x01\x12\x03F\x08\x11\n\x0b\n\x04\x04\x06\x02\0\x12\x03G\x04\x15\n\x0c\n\
+    \x05\x04\x06\x02\0\x05\x12\x03G\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\
+    \x03G\x0b\x10\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03G\x13\x14\n\x0b\n\x04\
+    \x04\x06\x02\x01\x12\x03H\x04\x14\n\x0c\n\x05\x04\x06\x02\x01\x06\x12\
+    \x03H\x04\x0b\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03H\x0c\x0f\n\x0c\n\
+    \x05\x04\x06\x02\x01\x03\x12\x03H\x12\x13\n\n\n\x02\x04\x07\x12\x04K\0N\
+    \x01\n\n\n\x03\x04\x07\x01\x12\x03K\x08\x10\n\x0b\n\x04\x04\x07\x02\0\
+    \x12\x03L\x04\x19\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03L\x04\n\n\x0c\n\
+    \x05\x04\x07\x02\0\x01\x12\x03L\x0b\x14\n\x0c\n\x05\x04\x07\x02\0\x03\
+    \x12\x03L\x17\x18\n\x0b\n\x04\x04\x07\x02\x01\x12\x03M\x04\x13\n\x0c\n\
+    \x05\x04\x07\x02\x01\x05\x12\x03M\x04\n\n\x0c\n\x05\x04\x07\x02\x01\x01\
+    \x12\x03M\x0b\x0e\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03M\x11\x12\n\n\n\
+    \x02\x04\x08\x12\x04P\0S\x01\n\n\n\x03\x04\x08\x01\x12\x03P\x08\x11\n\
+    \x0b\n\x04\x04\x08\x02\0\x12\x03Q\x04\x15\n\x0c\n\x05\x04\x08\x02\0\x05\
+    \x12\x03Q\x04\n\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03Q\x0b\x10\n\x0c\n\
+    \x05\x04\x08\x02\0\x03\x12\x03Q\x13\x14\n\x0b\n\x04\x04\x08\x02\x01\x12\
+    \x03R\x04\x14\n\x0c\n\x05\x04\x08\x02\x01\x06\x12\x03R\x04\x0b\n\x0c\n\
+    \x05\x04\x08\x02\x01\x01\x12\x03R\x0c\x0f\n\x0c\n\x05\x04\x08\x02\x01\
+    \x03\x12\x03R\x12\x13\n\n\n\x02\x04\t\x12\x04U\0X\x01\n\n\n\x03\x04\t\
+    \x01\x12\x03U\x08\x12\n\x0b\n\x04\x04\t\x02\0\x12\x03V\x04\x19\n\x0c\n\
+    \x05\x04\t\x02\0\x05\x12\x03V\x04\n\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03V\
+    \x0b\x14\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03V\x17\x18\n\x0b\n\x04\x04\t\
+    \x02\x01\x12\x03W\x04\x13\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03W\x04\n\n\
+    \x0c\n\x05\x04\t\x02\x01\x01\x12\x03W\x0b\x0e\n\x0c\n\x05\x04\t\x02\x01\
+    \x03\x12\x03W\x11\x12\n\n\n\x02\x04\n\x12\x04Z\0]\x01\n\n\n\x03\x04\n\
+    \x01\x12\x03Z\x08\x13\n\x0b\n\x04\x04\n\x02\0\x12\x03[\x04\x15\n\x0c\n\
+    \x05\x04\n\x02\0\x05\x12\x03[\x04\n\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03[\
+    \x0b\x10\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03[\x13\x14\n\x0b\n\x04\x04\n\
+    \x02\x01\x12\x03\\\x04\x14\n\x0c\n\x05\x04\n\x02\x01\x06\x12\x03\\\x04\
+    \x0b\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03\\\x0c\x0f\n\x0c\n\x05\x04\n\
+    \x02\x01\x03\x12\x03\\\x12\x13\n\n\n\x02\x04\x0b\x12\x04_\0c\x01\n\n\n\
+    \x03\x04\x0b\x01\x12\x03_\x08\x0e\n\x0b\n\x04\x04\x0b\x02\0\x12\x03`\x04\
+    \x19\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03`\x04\n\n\x0c\n\x05\x04\x0b\
+    \x02\0\x01\x12\x03`\x0b\x14\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03`\x17\
+    \x18\n\x0b\n\x04\x04\x0b\x02\x01\x12\x03a\x04\x13\n\x0c\n\x05\x04\x0b\
+    \x02\x01\x05\x12\x03a\x04\n\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03a\x0b\
+    \x0e\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03a\x11\x12\n\x0b\n\x04\x04\
+    \x0b\x02\x02\x12\x03b\x04\x16\n\x0c\n\x05\x04\x0b\x02\x02\x05\x12\x03b\
+    \x04\n\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\x03b\x0b\x11\n\x0c\n\x05\x04\
+    \x0b\x02\x02\x03\x12\x03b\x14\x15\n\n\n\x02\x04\x0c\x12\x04e\0h\x01\n\n\
+    \n\x03\x04\x0c\x01\x12\x03e\x08\x0f\n\x0b\n\x04\x04\x0c\x02\0\x12\x03f\
+    \x04\x15\n\x0c\n\x05\x04\x0c\x02\0\x05\x12\x03f\x04\n\n\x0c\n\x05\x04\
+    \x0c\x02\0\x01\x12\x03f\x0b\x10\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03f\
+    \x13\x14\n\x0b\n\x04\x04\x0c\x02\x01\x12\x03g\x04\x14\n\x0c\n\x05\x04\
+    \x0c\x02\x01\x06\x12\x03g\x04\x0b\n\x0c\n\x05\x04\x0c\x02\x01\x01\x12\
+    \x03g\x0c\x0f\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03g\x12\x13\n\n\n\x02\
+    \x04\r\x12\x04j\0n\x01\n\n\n\x03\x04\r\x01\x12\x03j\x08\x13\n\x0b\n\x04\
+    \x04\r\x02\0\x12\x03k\x04\x19\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03k\x04\n\
+    \n\x0c\n\x05\x04\r\x02\0\x01\x12\x03k\x0b\x14\n\x0c\n\x05\x04\r\x02\0\
+    \x03\x12\x03k\x17\x18\n\x0b\n\x04\x04\r\x02\x01\x12\x03l\x04\x13\n\x0c\n\
+    \x05\x04\r\x02\x01\x05\x12\x03l\x04\n\n\x0c\n\x05\x04\r\x02\x01\x01\x12\
+    \x03l\x0b\x0e\n\x0c\n\x05\x04\r\x02\x01\x03\x12\x03l\x11\x12\n\x0b\n\x04\
+    \x04\r\x02\x02\x12\x03m\x04\x20\n\x0c\n\x05\x04\r\x02\x02\x04\x12\x03m\
+    \x04\x0c\n\x0c\n\x05\x04\r\x02\x02\x05\x12\x03m\r\x13\n\x0c\n\x05\x04\r\
+    \x02\x02\x01\x12\x03m\x14\x1b\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03m\x1e\
+    \x1f\n\n\n\x02\x04\x0e\x12\x04p\0s\x01\n\n\n\x03\x04\x0e\x01\x12\x03p\
+    \x08\x14\n\x0b\n\x04\x04\x0e\x02\0\x12\x03q\x04\x15\n\x0c\n\x05\x04\x0e\
+    \x02\0\x05\x12\x03q\x04\n\n\x0c\n\x05\x04\x0e\x02\0\x01\x12\x03q\x0b\x10\
+    \n\x0c\n\x05\x04\x0e\x02\0\x03\x12\x03q\x13\x14\n\x0b\n\x04\x04\x0e\x02\
+    \x01\x12\x03r\x04\x14\n\x0c\n\x05\x04\x0e\x02\x01\x06\x12\x03r\x04\x0b\n\
+    \x0c\n\x05\x04\x0e\x02\x01\x01\x12\x03r\x0c\x0f\n\x0c\n\x05\x04\x0e\x02\
+    \x01\x03\x12\x03r\x12\x13\n\n\n\x02\x04\x0f\x12\x04u\0}\x01\n\n\n\x03\
+    \x04\x0f\x01\x12\x03u\x08\x0e\n\x0b\n\x04\x04\x0f\x02\0\x12\x03v\x04\x19\
+    \n\x0c\n\x05\x04\x0f\x02\0\x05\x12\x03v\x04\n\n\x0c\n\x05\x04\x0f\x02\0\
+    \x01\x12\x03v\x0b\x14\n\x0c\n\x05\x04\x0f\x02\0\x03\x12\x03v\x17\x18\n\
+    \x0b\n\x04\x04\x0f\x02\x01\x12\x03w\x04\x13\n\x0c\n\x05\x04\x0f\x02\x01\
+    \x05\x12\x03w\x04\n\n\x0c\n\x05\x04\x0f\x02\x01\x01\x12\x03w\x0b\x0e\n\
+    \x0c\n\x05\x04\x0f\x02\x01\x03\x12\x03w\x11\x12\n\x0b\n\x04\x04\x0f\x02\
+    \x02\x12\x03x\x04\x16\n\x0c\n\x05\x04\x0f\x02\x02\x05\x12\x03x\x04\n\n\
+    \x0c\n\x05\x04\x0f\x02\x02\x01\x12\x03x\x0b\x11\n\x0c\n\x05\x04\x0f\x02\
+    \x02\x03\x12\x03x\x14\x15\n\x0b\n\x04\x04\x0f\x02\x03\x12\x03y\x04\x16\n\
+    \x0c\n\x05\x04\x0f\x02\x03\x05\x12\x03y\x04\n\n\x0c\n\x05\x04\x0f\x02\
+    \x03\x01\x12\x03y\x0b\x11\n\x0c\n\x05\x04\x0f\x02\x03\x03\x12\x03y\x14\
+    \x15\n\x0b\n\x04\x04\x0f\x02\x04\x12\x03z\x04\x17\n\x0c\n\x05\x04\x0f\
+    \x02\x04\x05\x12\x03z\x04\n\n\x0c\n\x05\x04\x0f\x02\x04\x01\x12\x03z\x0b\
+    \x12\n\x0c\n\x05\x04\x0f\x02\x04\x03\x12\x03z\x15\x16\n\x0b\n\x04\x04\
+    \x0f\x02\x05\x12\x03{\x04\x1a\n\x0c\n\x05\x04\x0f\x02\x05\x05\x12\x03{\
+    \x04\n\n\x0c\n\x05\x04\x0f\x02\x05\x01\x12\x03{\x0b\x15\n\x0c\n\x05\x04\
+    \x0f\x02\x05\x03\x12\x03{\x18\x19\n\x0b\n\x04\x04\x0f\x02\x06\x12\x03|\
+    \x04\x17\n\x0c\n\x05\x04\x0f\x02\x06\x05\x12\x03|\x04\x08\n\x0c\n\x05\
+    \x04\x0f\x02\x06\x01\x12\x03|\t\x12\n\x0c\n\x05\x04\x0f\x02\x06\x03\x12\
+    \x03|\x15\x16\n\x0b\n\x02\x04\x10\x12\x05\x7f\0\x82\x01\x01\n\n\n\x03\
+    \x04\x10\x01\x12\x03\x7f\x08\x0f\n\x0c\n\x04\x04\x10\x02\0\x12\x04\x80\
+    \x01\x04\x15\n\r\n\x05\x04\x10\x02\0\x05\x12\x04\x80\x01\x04\n\n\r\n\x05\
+    \x04\x10\x02\0\x01\x12\x04\x80\x01\x0b\x10\n\r\n\x05\x04\x10\x02\0\x03\
+    \x12\x04\x80\x01\x13\x14\n\x0c\n\x04\x04\x10\x02\x01\x12\x04\x81\x01\x04\
+    \x14\n\r\n\x05\x04\x10\x02\x01\x06\x12\x04\x81\x01\x04\x0b\n\r\n\x05\x04\
+    \x10\x02\x01\x01\x12\x04\x81\x01\x0c\x0f\n\r\n\x05\x04\x10\x02\x01\x03\
+    \x12\x04\x81\x01\x12\x13\n\x0c\n\x02\x04\x11\x12\x06\x84\x01\0\x8b\x01\
+    \x01\n\x0b\n\x03\x04\x11\x01\x12\x04\x84\x01\x08\x11\n\x0c\n\x04\x04\x11\
+    \x02\0\x12\x04\x85\x01\x04\x19\n\r\n\x05\x04\x11\x02\0\x05\x12\x04\x85\
+    \x01\x04\n\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\x85\x01\x0b\x14\n\r\n\x05\
+    \x04\x11\x02\0\x03\x12\x04\x85\x01\x17\x18\n\x0c\n\x04\x04\x11\x02\x01\
+    \x12\x04\x86\x01\x04\x13\n\r\n\x05\x04\x11\x02\x01\x05\x12\x04\x86\x01\
+    \x04\n\n\r\n\x05\x04\x11\x02\x01\x01\x12\x04\x86\x01\x0b\x0e\n\r\n\x05\
+    \x04\x11\x02\x01\x03\x12\x04\x86\x01\x11\x12\n\x0c\n\x04\x04\x11\x02\x02\
+    \x12\x04\x87\x01\x04\x16\n\r\n\x05\x04\x11\x02\x02\x05\x12\x04\x87\x01\
+    \x04\n\n\r\n\x05\x04\x11\x02\x02\x01\x12\x04\x87\x01\x0b\x11\n\r\n\x05\
+    \x04\x11\x02\x02\x03\x12\x04\x87\x01\x14\x15\n\x0c\n\x04\x04\x11\x02\x03\
+    \x12\x04\x88\x01\x04\x13\n\r\n\x05\x04\x11\x02\x03\x05\x12\x04\x88\x01\
+    \x04\n\n\r\n\x05\x04\x11\x02\x03\x01\x12\x04\x88\x01\x0b\x0e\n\r\n\x05\
+    \x04\x11\x02\x03\x03\x12\x04\x88\x01\x11\x12\n\x0c\n\x04\x04\x11\x02\x04\
+    \x12\x04\x89\x01\x04\x16\n\r\n\x05\x04\x11\x02\x04\x05\x12\x04\x89\x01\
+    \x04\n\n\r\n\x05\x04\x11\x02\x04\x01\x12\x04\x89\x01\x0b\x11\n\r\n\x05\
+    \x04\x11\x02\x04\x03\x12\x04\x89\x01\x14\x15\n\x0c\n\x04\x04\x11\x02\x05\
+    \x12\x04\x8a\x01\x04\x16\n\r\n\x05\x04\x11\x02\x05\x05\x12\x04\x8a\x01\
+    \x04\n\n\r\n\x05\x04\x11\x02\x05\x01\x12\x04\x8a\x01\x0b\x11\n\r\n\x05\
+    \x04\x11\x02\x05\x03\x12\x04\x8a\x01\x14\x15\n\x0c\n\x02\x04\x12\x12\x06\
+    \x8d\x01\0\x90\x01\x01\n\x0b\n\x03\x04\x12\x01\x12\x04\x8d\x01\x08\x12\n\
+    \x0c\n\x04\x04\x12\x02\0\x12\x04\x8e\x01\x04\x15\n\r\n\x05\x04\x12\x02\0\
+    \x05\x12\x04\x8e\x01\x04\n\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\x8e\x01\
+    \x0b\x10\n\r\n\x05\x04\x12\x02\0\x03\x12\x04\x8e\x01\x13\x14\n\x0c\n\x04\
+    \x04\x12\x02\x01\x12\x04\x8f\x01\x04\x14\n\r\n\x05\x04\x12\x02\x01\x06\
+    \x12\x04\x8f\x01\x04\x0b\n\r\n\x05\x04\x12\x02\x01\x01\x12\x04\x8f\x01\
+    \x0c\x0f\n\r\n\x05\x04\x12\x02\x01\x03\x12\x04\x8f\x01\x12\x13\n\x0c\n\
+    \x02\x04\x13\x12\x06\x92\x01\0\x97\x01\x01\n\x0b\n\x03\x04\x13\x01\x12\
+    \x04\x92\x01\x08\x11\n\x0c\n\x04\x04\x13\x02\0\x12\x04\x93\x01\x04\x19\n\
+    \r\n\x05\x04\x13\x02\0\x05\x12\x04\x93\x01\x04\n\n\r\n\x05\x04\x13\x02\0\
+    \x01\x12\x04\x93\x01\x0b\x14\n\r\n\x05\x04\x13\x02\0\x03\x12\x04\x93\x01\
+    \x17\x18\n\x0c\n\x04\x04\x13\x02\x01\x12\x04\x94\x01\x04\x13\n\r\n\x05\
+    \x04\x13\x02\x01\x05\x12\x04\x94\x01\x04\n\n\r\n\x05\x04\x13\x02\x01\x01\
+    \x12\x04\x94\x01\x0b\x0e\n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\x94\x01\
+    \x11\x12\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\x95\x01\x04\x16\n\r\n\x05\
+    \x04\x13\x02\x02\x05\x12\x04\x95\x01\x04\n\n\r\n\x05\x04\x13\x02\x02\x01\
+    \x12\x04\x95\x01\x0b\x11\n\r\n\x05\x04\x13\x02\x02\x03\x12\x04\x95\x01\
+    \x14\x15\n\x0c\n\x04\x04\x13\x02\x03\x12\x04\x96\x01\x04\x1a\n\r\n\x05\
+    \x04\x13\x02\x03\x05\x12\x04\x96\x01\x04\n\n\r\n\x05\x04\x13\x02\x03\x01\
+    \x12\x04\x96\x01\x0b\x15\n\r\n\x05\x04\x13\x02\x03\x03\x12\x04\x96\x01\
+    \x18\x19\n\x0c\n\x02\x04\x14\x12\x06\x99\x01\0\x9c\x01\x01\n\x0b\n\x03\
+    \x04\x14\x01\x12\x04\x99\x01\x08\x12\n\x0c\n\x04\x04\x14\x02\0\x12\x04\
+    \x9a\x01\x04\x15\n\r\n\x05\x04\x14\x02\0\x05\x12\x04\x9a\x01\x04\n\n\r\n\
+    \x05\x04\x14\x02\0\x01\x12\x04\x9a\x01\x0b\x10\n\r\n\x05\x04\x14\x02\0\
+    \x03\x12\x04\x9a\x01\x13\x14\n\x0c\n\x04\x04\x14\x02\x01\x12\x04\x9b\x01\
+    \x04\x14\n\r\n\x05\x04\x14\x02\x01\x06\x12\x04\x9b\x01\x04\x0b\n\r\n\x05\
+    \x04\x14\x02\x01\x01\x12\x04\x9b\x01\x0c\x0f\n\r\n\x05\x04\x14\x02\x01\
+    \x03\x12\x04\x9b\x01\x12\x13\n\x0c\n\x02\x04\x15\x12\x06\x9e\x01\0\xa2\
+    \x01\x01\n\x0b\n\x03\x04\x15\x01\x12\x04\x9e\x01\x08\x18\n\x0c\n\x04\x04\
+    \x15\x02\0\x12\x04\x9f\x01\x04\x19\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\
+    \x9f\x01\x04\n\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\x9f\x01\x0b\x14\n\r\n\
+    \x05\x04\x15\x02\0\x03\x12\x04\x9f\x01\x17\x18\n\x0c\n\x04\x04\x15\x02\
+    \x01\x12\x04\xa0\x01\x04\x13\n\r\n\x05\x04\x15\x02\x01\x05\x12\x04\xa0\
+    \x01\x04\n\n\r\n\x05\x04\x15\x02\x01\x01\x12\x04\xa0\x01\x0b\x0e\n\r\n\
+    \x05\x04\x15\x02\x01\x03\x12\x04\xa0\x01\x11\x12\n\x0c\n\x04\x04\x15\x02\
+    \x02\x12\x04\xa1\x01\x04\x15\n\r\n\x05\x04\x15\x02\x02\x05\x12\x04\xa1\
+    \x01\x04\n\n\r\n\x05\x04\x15\x02\x02\x01\x12\x04\xa1\x01\x0b\x10\n\r\n\
+    \x05\x04\x15\x02\x02\x03\x12\x04\xa1\x01\x13\x14\n\x0c\n\x02\x04\x16\x12\
+    \x06\xa4\x01\0\xa7\x01\x01\n\x0b\n\x03\x04\x16\x01\x12\x04\xa4\x01\x08\
+    \x19\n\x0c\n\x04\x04\x16\x02\0\x12\x04\xa5\x01\x04\x15\n\r\n\x05\x04\x16\
+    \x02\0\x05\x12\x04\xa5\x01\x04\n\n\r\n\x05\x04\x16\x02\0\x01\x12\x04\xa5\
+    \x01\x0b\x10\n\r\n\x05\x04\x16\x02\0\x03\x12\x04\xa5\x01\x13\x14\n\x0c\n\
+    \x04\x04\x16\x02\x01\x12\x04\xa6\x01\x04\x14\n\r\n\x05\x04\x16\x02\x01\
+    \x06\x12\x04\xa6\x01\x04\x0b\n\r\n\x05\x04\x16\x02\x01\x01\x12\x04\xa6\
+    \x01\x0c\x0f\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\xa6\x01\x12\x13\n\x0c\
+    \n\x02\x04\x17\x12\x06\xa9\x01\0\xac\x01\x01\n\x0b\n\x03\x04\x17\x01\x12\
+    \x04\xa9\x01\x08\x11\n\x0c\n\x04\x04\x17\x02\0\x12\x04\xaa\x01\x04\x19\n\
+    \r\n\x05\x04\x17\x02\0\x05\x12\x04\xaa\x01\x04\n\n\r\n\x05\x04\x17\x02\0\
+    \x01\x12\x04\xaa\x01\x0b\x14\n\r\n\x05\x04\x17\x02\0\x03\x12\x04\xaa\x01\
+    \x17\x18\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\xab\x01\x04\x13\n\r\n\x05\
+    \x04\x17\x02\x01\x05\x12\x04\xab\x01\x04\n\n\r\n\x05\x04\x17\x02\x01\x01\
+    \x12\x04\xab\x01\x0b\x0e\n\r\n\x05\x04\x17\x02\x01\x03\x12\x04\xab\x01\
+    \x11\x12\n\x0c\n\x02\x04\x18\x12\x06\xae\x01\0\xb1\x01\x01\n\x0b\n\x03\
+    \x04\x18\x01\x12\x04\xae\x01\x08\x12\n\x0c\n\x04\x04\x18\x02\0\x12\x04\
+    \xaf\x01\x04\x15\n\r\n\x05\x04\x18\x02\0\x05\x12\x04\xaf\x01\x04\n\n\r\n\
+    \x05\x04\x18\x02\0\x01\x12\x04\xaf\x01\x0b\x10\n\r\n\x05\x04\x18\x02\0\
+    \x03\x12\x04\xaf\x01\x13\x14\n\x0c\n\x04\x04\x18\x02\x01\x12\x04\xb0\x01\
+    \x04\x14\n\r\n\x05\x04\x18\x02\x01\x06\x12\x04\xb0\x01\x04\x0b\n\r\n\x05\
+    \x04\x18\x02\x01\x01\x12\x04\xb0\x01\x0c\x0f\n\r\n\x05\x04\x18\x02\x01\
+    \x03\x12\x04\xb0\x01\x12\x13\n\x0c\n\x02\x04\x19\x12\x06\xb3\x01\0\xb6\
+    \x01\x01\n\x0b\n\x03\x04\x19\x01\x12\x04\xb3\x01\x08\x13\n\x0c\n\x04\x04\
+    \x19\x02\0\x12\x04\xb4\x01\x04\x19\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\
+    \xb4\x01\x04\n\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\xb4\x01\x0b\x14\n\r\n\
+    \x05\x04\x19\x02\0\x03\x12\x04\xb4\x01\x17\x18\n\x0c\n\x04\x04\x19\x02\
+    \x01\x12\x04\xb5\x01\x04\x13\n\r\n\x05\x04\x19\x02\x01\x05\x12\x04\xb5\
+    \x01\x04\n\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\xb5\x01\x0b\x0e\n\r\n\
+    \x05\x04\x19\x02\x01\x03\x12\x04\xb5\x01\x11\x12\n\x0c\n\x02\x04\x1a\x12\
+    \x06\xb8\x01\0\xbb\x01\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\xb8\x01\x08\
+    \x14\n\x0c\n\x04\x04\x1a\x02\0\x12\x04\xb9\x01\x04\x15\n\r\n\x05\x04\x1a\
+    \x02\0\x05\x12\x04\xb9\x01\x04\n\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\xb9\
+    \x01\x0b\x10\n\r\n\x05\x04\x1a\x02\0\x03\x12\x04\xb9\x01\x13\x14\n\x0c\n\
+    \x04\x04\x1a\x02\x01\x12\x04\xba\x01\x04\x14\n\r\n\x05\x04\x1a\x02\x01\
+    \x06\x12\x04\xba\x01\x04\x0b\n\r\n\x05\x04\x1a\x02\x01\x01\x12\x04\xba\
+    \x01\x0c\x0f\n\r\n\x05\x04\x1a\x02\x01\x03\x12\x04\xba\x01\x12\x13\n\x0c\
+    \n\x02\x04\x1b\x12\x06\xbd\x01\0\xc0\x01\x01\n\x0b\n\x03\x04\x1b\x01\x12\
+    \x04\xbd\x01\x08\x10\n\x0c\n\x04\x04\x1b\x02\0\x12\x04\xbe\x01\x04\x19\n\
+    \r\n\x05\x04\x1b\x02\0\x05\x12\x04\xbe\x01\x04\n\n\r\n\x05\x04\x1b\x02\0\
+    \x01\x12\x04\xbe\x01\x0b\x14\n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\xbe\x01\
+    \x17\x18\n\x0c\n\x04\x04\x1b\x02\x01\x12\x04\xbf\x01\x04\x13\n\r\n\x05\
+    \x04\x1b\x02\x01\x05\x12\x04\xbf\x01\x04\n\n\r\n\x05\x04\x1b\x02\x01\x01\
+    \x12\x04\xbf\x01\x0b\x0e\n\r\n\x05\x04\x1b\x02\x01\x03\x12\x04\xbf\x01\
+    \x11\x12\n\x0c\n\x02\x04\x1c\x12\x06\xc2\x01\0\xc5\x01\x01\n\x0b\n\x03\
+    \x04\x1c\x01\x12\x04\xc2\x01\x08\x11\n\x0c\n\x04\x04\x1c\x02\0\x12\x04\
+    \xc3\x01\x04\x15\n\r\n\x05\x04\x1c\x02\0\x05\x12\x04\xc3\x01\x04\n\n\r\n\
+    \x05\x04\x1c\x02\0\x01\x12\x04\xc3\x01\x0b\x10\n\r\n\x05\x04\x1c\x02\0\
+    \x03\x12\x04\xc3\x01\x13\x14\n\x0c\n\x04\x04\x1c\x02\x01\x12\x04\xc4\x01\
+    \x04\x14\n\r\n\x05\x04\x1c\x02\x01\x06\x12\x04\xc4\x01\x04\x0b\n\r\n\x05\
+    \x04\x1c\x02\x01\x01\x12\x04\xc4\x01\x0c\x0f\n\r\n\x05\x04\x1c\x02\x01\
+    \x03\x12\x04\xc4\x01\x12\x13\n\x0c\n\x02\x04\x1d\x12\x06\xc7\x01\0\xeb\
+    \x01\x01\n\x0b\n\x03\x04\x1d\x01\x12\x04\xc7\x01\x08\x11\n\x0c\n\x04\x04\
+    \x1d\x02\0\x12\x04\xc8\x01\x04\x19\n\r\n\x05\x04\x1d\x02\0\x05\x12\x04\
+    \xc8\x01\x04\n\n\r\n\x05\x04\x1d\x02\0\x01\x12\x04\xc8\x01\x0b\x14\n\r\n\
+    \x05\x04\x1d\x02\0\x03\x12\x04\xc8\x01\x17\x18\n\x0c\n\x04\x04\x1d\x02\
+    \x01\x12\x04\xc9\x01\x04\x13\n\r\n\x05\x04\x1d\x02\x01\x05\x12\x04\xc9\
+    \x01\x04\n\n\r\n\x05\x04\x1d\x02\x01\x01\x12\x04\xc9\x01\x0b\x0e\n\r\n\
+    \x05\x04\x1d\x02\x01\x03\x12\x04\xc9\x01\x11\x12\n\x0c\n\x04\x04\x1d\x02\
+    \x02\x12\x04\xca\x01\x04\x15\n\r\n\x05\x04\x1d\x02\x02\x05\x12\x04\xca\
+    \x01\x04\n\n\r\n\x05\x04\x1d\x02\x02\x01\x12\x04\xca\x01\x0b\x10\n\r\n\
+    \x05\x04\x1d\x02\x02\x03\x12\x04\xca\x01\x13\x14\n\x0c\n\x04\x04\x1d\x02\
+    \x03\x12\x04\xcb\x01\x04\x14\n\r\n\x05\x04\x1d\x02\x03\x05\x12\x04\xcb\
+    \x01\x04\n\n\r\n\x05\x04\x1d\x02\x03\x01\x12\x04\xcb\x01\x0b\x0f\n\r\n\
+    \x05\x04\x1d\x02\x03\x03\x12\x04\xcb\x01\x12\x13\n\x0c\n\x04\x04\x1d\x02\
+    \x04\x12\x04\xcc\x01\x04\x15\n\r\n\x05\x04\x1d\x02\x04\x05\x12\x04\xcc\
+    \x01\x04\n\n\r\n\x05\x04\x1d\x02\x04\x01\x12\x04\xcc\x01\x0b\x10\n\r\n\
+    \x05\x04\x1d\x02\x04\x03\x12\x04\xcc\x01\x13\x14\n\x0c\n\x04\x04\x1d\x02\
+    \x05\x12\x04\xcd\x01\x04\x1b\n\r\n\x05\x04\x1d\x02\x05\x05\x12\x04\xcd\
+    \x01\x04\x08\n\r\n\x05\x04\x1d\x02\x05\x01\x12\x04\xcd\x01\t\x16\n\r\n\
+    \x05\x04\x1d\x02\x05\x03\x12\x04\xcd\x01\x19\x1a\n\x0c\n\x04\x04\x1d\x02\
+    \x06\x12\x04\xce\x01\x04\x1a\n\r\n\x05\x04\x1d\x02\x06\x05\x12\x04\xce\
+    \x01\x04\x08\n\r\n\x05\x04\x1d\x02\x06\x01\x12\x04\xce\x01\t\x15\n\r\n\
+    \x05\x04\x1d\x02\x06\x03\x12\x04\xce\x01\x18\x19\n\x0c\n\x04\x04\x1d\x02\
+    \x07\x12\x04\xcf\x01\x04\x1b\n\r\n\x05\x04\x1d\x02\x07\x05\x12\x04\xcf\
+    \x01\x04\n\n\r\n\x05\x04\x1d\x02\x07\x01\x12\x04\xcf\x01\x0b\x16\n\r\n\
+    \x05\x04\x1d\x02\x07\x03\x12\x04\xcf\x01\x19\x1a\n\x0c\n\x04\x04\x1d\x02\
+    \x08\x12\x04\xd0\x01\x04'\n\r\n\x05\x04\x1d\x02\x08\x04\x12\x04\xd0\x01\
+    \x04\x0c\n\r\n\x05\x04\x1d\x02\x08\x05\x12\x04\xd0\x01\r\x13\n\r\n\x05\
+    \x04\x1d\x02\x08\x01\x12\x04\xd0\x01\x14\"\n\r\n\x05\x04\x1d\x02\x08\x03\
+    \x12\x04\xd0\x01%&\n\x0c\n\x04\x04\x1d\x02\t\x12\x04\xd1\x01\x04\x20\n\r\
+    \n\x05\x04\x1d\x02\t\x04\x12\x04\xd1\x01\x04\x0c\n\r\n\x05\x04\x1d\x02\t\
+    \x05\x12\x04\xd1\x01\r\x13\n\r\n\x05\x04\x1d\x02\t\x01\x12\x04\xd1\x01\
+    \x14\x1a\n\r\n\x05\x04\x1d\x02\t\x03\x12\x04\xd1\x01\x1d\x1f\n\x0c\n\x04\
+    \x04\x1d\x02\n\x12\x04\xd2\x01\x04\x1c\n\r\n\x05\x04\x1d\x02\n\x05\x12\
+    \x04\xd2\x01\x04\n\n\r\n\x05\x04\x1d\x02\n\x01\x12\x04\xd2\x01\x0b\x16\n\
+    \r\n\x05\x04\x1d\x02\n\x03\x12\x04\xd2\x01\x19\x1b\n\x0c\n\x04\x04\x1d\
+    \x02\x0b\x12\x04\xd3\x01\x04&\n\r\n\x05\x04\x1d\x02\x0b\x05\x12\x04\xd3\
+    \x01\x04\n\n\r\n\x05\x04\x1d\x02\x0b\x01\x12\x04\xd3\x01\x0b\x20\n\r\n\
+    \x05\x04\x1d\x02\x0b\x03\x12\x04\xd3\x01#%\n\x0c\n\x04\x04\x1d\x02\x0c\
+    \x12\x04\xd4\x01\x04$\n\r\n\x05\x04\x1d\x02\x0c\x05\x12\x04\xd4\x01\x04\
+    \n\n\r\n\x05\x04\x1d\x02\x0c\x01\x12\x04\xd4\x01\x0b\x1e\n\r\n\x05\x04\
+    \x1d\x02\x0c\x03\x12\x04\xd4\x01!#\n\x0c\n\x04\x04\x1d\x02\r\x12\x04\xd5\
+    \x01\x04\x1b\n\r\n\x05\x04\x1d\x02\r\x05\x12\x04\xd5\x01\x04\x08\n\r\n\
+    \x05\x04\x1d\x02\r\x01\x12\x04\xd5\x01\t\x15\n\r\n\x05\x04\x1d\x02\r\x03\
+    \x12\x04\xd5\x01\x18\x1a\n\x0c\n\x04\x04\x1d\x02\x0e\x12\x04\xd6\x01\x04\
+    \x1a\n\r\n\x05\x04\x1d\x02\x0e\x05\x12\x04\xd6\x01\x04\n\n\r\n\x05\x04\
+    \x1d\x02\x0e\x01\x12\x04\xd6\x01\x0b\x14\n\r\n\x05\x04\x1d\x02\x0e\x03\
+    \x12\x04\xd6\x01\x17\x19\n\x0c\n\x04\x04\x1d\x02\x0f\x12\x04\xd7\x01\x04\
+    \x1b\n\r\n\x05\x04\x1d\x02\x0f\x05\x12\x04\xd7\x01\x04\n\n\r\n\x05\x04\
+    \x1d\x02\x0f\x01\x12\x04\xd7\x01\x0b\x15\n\r\n\x05\x04\x1d\x02\x0f\x03\
+    \x12\x04\xd7\x01\x18\x1a\n\x0c\n\x04\x04\x1d\x02\x10\x12\x04\xd8\x01\x04\
+    \x1d\n\r\n\x05\x04\x1d\x02\x10\x05\x12\x04\xd8\x01\x04\n\n\r\n\x05\x04\
+    \x1d\x02\x10\x01\x12\x04\xd8\x01\x0b\x17\n\r\n\x05\x04\x1d\x02\x10\x03\
+    \x12\x04\xd8\x01\x1a\x1c\n\x0c\n\x04\x04\x1d\x02\x11\x12\x04\xd9\x01\x04\
+    !\n\r\n\x05\x04\x1d\x02\x11\x05\x12\x04\xd9\x01\x04\n\n\r\n\x05\x04\x1d\
+    \x02\x11\x01\x12\x04\xd9\x01\x0b\x1b\n\r\n\x05\x04\x1d\x02\x11\x03\x12\
+    \x04\xd9\x01\x1e\x20\n\x0c\n\x04\x04\x1d\x02\x12\x12\x04\xda\x01\x04\x17\
+    \n\r\n\x05\x04\x1d\x02\x12\x05\x12\x04\xda\x01\x04\x08\n\r\n\x05\x04\x1d\
+    \x02\x12\x01\x12\x04\xda\x01\t\x11\n\r\n\x05\x04\x1d\x02\x12\x03\x12\x04\
+    \xda\x01\x14\x16\n\x0c\n\x04\x04\x1d\x02\x13\x12\x04\xdb\x01\x04\x1b\n\r\
+    \n\x05\x04\x1d\x02\x13\x05\x12\x04\xdb\x01\x04\n\n\r\n\x05\x04\x1d\x02\
+    \x13\x01\x12\x04\xdb\x01\x0b\x15\n\r\n\x05\x04\x1d\x02\x13\x03\x12\x04\
+    \xdb\x01\x18\x1a\n\x0c\n\x04\x04\x1d\x02\x14\x12\x04\xdc\x01\x04\x1a\n\r\
+    \n\x05\x04\x1d\x02\x14\x05\x12\x04\xdc\x01\x04\n\n\r\n\x05\x04\x1d\x02\
+    \x14\x01\x12\x04\xdc\x01\x0b\x14\n\r\n\x05\x04\x1d\x02\x14\x03\x12\x04\
+    \xdc\x01\x17\x19\n\x0c\n\x04\x04\x1d\x02\x15\x12\x04\xdd\x01\x04\x18\n\r\
+    \n\x05\x04\x1d\x02\x15\x05\x12\x04\xdd\x01\x04\n\n\r\n\x05\x04\x1d\x02\
+    \x15\x01\x12\x04\xdd\x01\x0b\x12\n\r\n\x05\x04\x1d\x02\x15\x03\x12\x04\
+    \xdd\x01\x15\x17\n\x0c\n\x04\x04\x1d\x02\x16\x12\x04\xde\x01\x04\x1a\n\r\
+    \n\x05\x04\x1d\x02\x16\x05\x12\x04\xde\x01\x04\n\n\r\n\x05\x04\x1d\x02\
+    \x16\x01\x12\x04\xde\x01\x0b\x14\n\r\n\x05\x04\x1d\x02\x16\x03\x12\x04\
+    \xde\x01\x17\x19\n\x0c\n\x04\x04\x1d\x02\x17\x12\x04\xdf\x01\x04\x1f\n\r\
+    \n\x05\x04\x1d\x02\x17\x05\x12\x04\xdf\x01\x04\n\n\r\n\x05\x04\x1d\x02\
+    \x17\x01\x12\x04\xdf\x01\x0b\x19\n\r\n\x05\x04\x1d\x02\x17\x03\x12\x04\
+    \xdf\x01\x1c\x1e\n\x0c\n\x04\x04\x1d\x02\x18\x12\x04\xe0\x01\x04\x1f\n\r\
+    \n\x05\x04\x1d\x02\x18\x05\x12\x04\xe0\x01\x04\n\n\r\n\x05\x04\x1d\x02\
+    \x18\x01\x12\x04\xe0\x01\x0b\x19\n\r\n\x05\x04\x1d\x02\x18\x03\x12\x04\
+    \xe0\x01\x1c\x1e\n\x0c\n\x04\x04\x1d\x02\x19\x12\x04\xe1\x01\x04\x18\n\r\
+    \n\x05\x04\x1d\x02\x19\x05\x12\x04\xe1\x01\x04\n\n\r\n\x05\x04\x1d\x02\
+    \x19\x01\x12\x04\xe1\x01\x0b\x12\n\r\n\x05\x04\x1d\x02\x19\x03\x12\x04\
+    \xe1\x01\x15\x17\n\x0c\n\x04\x04\x1d\x02\x1a\x12\x04\xe2\x01\x04\x20\n\r\
+    \n\x05\x04\x1d\x02\x1a\x05\x12\x04\xe2\x01\x04\n\n\r\n\x05\x04\x1d\x02\
+    \x1a\x01\x12\x04\xe2\x01\x0b\x1a\n\r\n\x05\x04\x1d\x02\x1a\x03\x12\x04\
+    \xe2\x01\x1d\x1f\n\x0c\n\x04\x04\x1d\x02\x1b\x12\x04\xe3\x01\x04\x1e\n\r\
+    \n\x05\x04\x1d\x02\x1b\x04\x12\x04\xe3\x01\x04\x0c\n\r\n\x05\x04\x1d\x02\
+    \x1b\x05\x12\x04\xe3\x01\r\x13\n\r\n\x05\x04\x1d\x02\x1b\x01\x12\x04\xe3\
+    \x01\x14\x18\n\r\n\x05\x04\x1d\x02\x1b\x03\x12\x04\xe3\x01\x1b\x1d\n\x0c\
+    \n\x04\x04\x1d\x02\x1c\x12\x04\xe4\x01\x04&\n\r\n\x05\x04\x1d\x02\x1c\
+    \x04\x12\x04\xe4\x01\x04\x0c\n\r\n\x05\x04\x1d\x02\x1c\x05\x12\x04\xe4\
+    \x01\r\x13\n\r\n\x05\x04\x1d\x02\x1c\x01\x12\x04\xe4\x01\x14\x20\n\r\n\
+    \x05\x04\x1d\x02\x1c\x03\x12\x04\xe4\x01#%\n\x0c\n\x04\x04\x1d\x02\x1d\
+    \x12\x04\xe5\x01\x04%\n\r\n\x05\x04\x1d\x02\x1d\x04\x12\x04\xe5\x01\x04\
+    \x0c\n\r\n\x05\x04\x1d\x02\x1d\x05\x12\x04\xe5\x01\r\x13\n\r\n\x05\x04\
+    \x1d\x02\x1d\x01\x12\x04\xe5\x01\x14\x1f\n\r\n\x05\x04\x1d\x02\x1d\x03\
+    \x12\x04\xe5\x01\"$\n\x0c\n\x04\x04\x1d\x02\x1e\x12\x04\xe6\x01\x04\x1d\
+    \n\r\n\x05\x04\x1d\x02\x1e\x05\x12\x04\xe6\x01\x04\n\n\r\n\x05\x04\x1d\
+    \x02\x1e\x01\x12\x04\xe6\x01\x0b\x17\n\r\n\x05\x04\x1d\x02\x1e\x03\x12\
+    \x04\xe6\x01\x1a\x1c\n\x0c\n\x04\x04\x1d\x02\x1f\x12\x04\xe7\x01\x04\x1a\
+    \n\r\n\x05\x04\x1d\x02\x1f\x05\x12\x04\xe7\x01\x04\n\n\r\n\x05\x04\x1d\
+    \x02\x1f\x01\x12\x04\xe7\x01\x0b\x14\n\r\n\x05\x04\x1d\x02\x1f\x03\x12\
+    \x04\xe7\x01\x17\x19\n\x0c\n\x04\x04\x1d\x02\x20\x12\x04\xe8\x01\x04\x1a\
+    \n\r\n\x05\x04\x1d\x02\x20\x05\x12\x04\xe8\x01\x04\n\n\r\n\x05\x04\x1d\
+    \x02\x20\x01\x12\x04\xe8\x01\x0b\x14\n\r\n\x05\x04\x1d\x02\x20\x03\x12\
+    \x04\xe8\x01\x17\x19\n\x0c\n\x04\x04\x1d\x02!\x12\x04\xe9\x01\x04\x1d\n\
+    \r\n\x05\x04\x1d\x02!\x05\x12\x04\xe9\x01\x04\x08\n\r\n\x05\x04\x1d\x02!\
+    \x01\x12\x04\xe9\x01\t\x17\n\r\n\x05\x04\x1d\x02!\x03\x12\x04\xe9\x01\
+    \x1a\x1c\n\x0c\n\x04\x04\x1d\x02\"\x12\x04\xea\x01\x04\x1f\n\r\n\x05\x04\
+    \x1d\x02\"\x05\x12\x04\xea\x01\x04\n\n\r\n\x05\x04\x1d\x02\"\x01\x12\x04\
+    \xea\x01\x0b\x19\n\r\n\x05\x04\x1d\x02\"\x03\x12\x04\xea\x01\x1c\x1e\n\
+    \x0c\n\x02\x04\x1e\x12\x06\xed\x01\0\xf0\x01\x01\n\x0b\n\x03\x04\x1e\x01\
+    \x12\x04\xed\x01\x08\x12\n\x0c\n\x04\x04\x1e\x02\0\x12\x04\xee\x01\x04\
+    \x15\n\r\n\x05\x04\x1e\x02\0\x05\x12\x04\xee\x01\x04\n\n\r\n\x05\x04\x1e\
+    \x02\0\x01\x12\x04\xee\x01\x0b\x10\n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\
+    \xee\x01\x13\x14\n\x0c\n\x04\x04\x1e\x02\x01\x12\x04\xef\x01\x04\x14\n\r\
+    \n\x05\x04\x1e\x02\x01\x06\x12\x04\xef\x01\x04\x0b\n\r\n\x05\x04\x1e\x02\
+    \x01\x01\x12\x04\xef\x01\x0c\x0f\n\r\n\x05\x04\x1e\x02\x01\x03\x12\x04\
+    \xef\x01\x12\x13\n\x0c\n\x02\x04\x1f\x12\x06\xf2\x01\0\xf9\x01\x01\n\x0b\
+    \n\x03\x04\x1f\x01\x12\x04\xf2\x01\x08\x12\n\x0c\n\x04\x04\x1f\x02\0\x12\
+    \x04\xf3\x01\x04\x19\n\r\n\x05\x04\x1f\x02\0\x05\x12\x04\xf3\x01\x04\n\n\
+    \r\n\x05\x04\x1f\x02\0\x01\x12\x04\xf3\x01\x0b\x14\n\r\n\x05\x04\x1f\x02\
+    \0\x03\x12\x04\xf3\x01\x17\x18\n\x0c\n\x04\x04\x1f\x02\x01\x12\x04\xf4\
+    \x01\x04\x13\n\r\n\x05\x04\x1f\x02\x01\x05\x12\x04\xf4\x01\x04\n\n\r\n\
+    \x05\x04\x1f\x02\x01\x01\x12\x04\xf4\x01\x0b\x0e\n\r\n\x05\x04\x1f\x02\
+    \x01\x03\x12\x04\xf4\x01\x11\x12\n\x0c\n\x04\x04\x1f\x02\x02\x12\x04\xf5\
+    \x01\x04\x15\n\r\n\x05\x04\x1f\x02\x02\x05\x12\x04\xf5\x01\x04\n\n\r\n\
+    \x05\x04\x1f\x02\x02\x01\x12\x04\xf5\x01\x0b\x10\n\r\n\x05\x04\x1f\x02\
+    \x02\x03\x12\x04\xf5\x01\x13\x14\n\x0c\n\x04\x04\x1f\x02\x03\x12\x04\xf6\
+    \x01\x04\x16\n\r\n\x05\x04\x1f\x02\x03\x05\x12\x04\xf6\x01\x04\n\n\r\n\
+    \x05\x04\x1f\x02\x03\x01\x12\x04\xf6\x01\x0b\x11\n\r\n\x05\x04\x1f\x02\
+    \x03\x03\x12\x04\xf6\x01\x14\x15\n\x0c\n\x04\x04\x1f\x02\x04\x12\x04\xf7\
+    \x01\x04\x15\n\r\n\x05\x04\x1f\x02\x04\x05\x12\x04\xf7\x01\x04\n\n\r\n\
+    \x05\x04\x1f\x02\x04\x01\x12\x04\xf7\x01\x0b\x10\n\r\n\x05\x04\x1f\x02\
+    \x04\x03\x12\x04\xf7\x01\x13\x14\n\x0c\n\x04\x04\x1f\x02\x05\x12\x04\xf8\
+    \x01\x04\x1c\n\r\n\x05\x04\x1f\x02\x05\x05\x12\x04\xf8\x01\x04\n\n\r\n\
+    \x05\x04\x1f\x02\x05\x01\x12\x04\xf8\x01\x0b\x17\n\r\n\x05\x04\x1f\x02\
+    \x05\x03\x12\x04\xf8\x01\x1a\x1b\n\x0c\n\x02\x04\x20\x12\x06\xfb\x01\0\
+    \xfe\x01\x01\n\x0b\n\x03\x04\x20\x01\x12\x04\xfb\x01\x08\x13\n\x0c\n\x04\
+    \x04\x20\x02\0\x12\x04\xfc\x01\x04\x15\n\r\n\x05\x04\x20\x02\0\x05\x12\
+    \x04\xfc\x01\x04\n\n\r\n\x05\x04\x20\x02\0\x01\x12\x04\xfc\x01\x0b\x10\n\
+    \r\n\x05\x04\x20\x02\0\x03\x12\x04\xfc\x01\x13\x14\n\x0c\n\x04\x04\x20\
+    \x02\x01\x12\x04\xfd\x01\x04\x14\n\r\n\x05\x04\x20\x02\x01\x06\x12\x04\
+    \xfd\x01\x04\x0b\n\r\n\x05\x04\x20\x02\x01\x01\x12\x04\xfd\x01\x0c\x0f\n\
+    \r\n\x05\x04\x20\x02\x01\x03\x12\x04\xfd\x01\x12\x13\n\x0c\n\x02\x04!\
+    \x12\x06\x80\x02\0\x83\x02\x01\n\x0b\n\x03\x04!\x01\x12\x04\x80\x02\x08\
+    \x11\n\x0c\n\x04\x04!\x02\0\x12\x04\x81\x02\x04\x19\n\r\n\x05\x04!\x02\0\
+    \x05\x12\x04\x81\x02\x04\n\n\r\n\x05\x04!\x02\0\x01\x12\x04\x81\x02\x0b\
+    \x14\n\r\n\x05\x04!\x02\0\x03\x12\x04\x81\x02\x17\x18\n\x0c\n\x04\x04!\
+    \x02\x01\x12\x04\x82\x02\x04\x13\n\r\n\x05\x04!\x02\x01\x05\x12\x04\x82\
+    \x02\x04\n\n\r\n\x05\x04!\x02\x01\x01\x12\x04\x82\x02\x0b\x0e\n\r\n\x05\
+    \x04!\x02\x01\x03\x12\x04\x82\x02\x11\x12\n\x0c\n\x02\x04\"\x12\x06\x85\
+    \x02\0\x88\x02\x01\n\x0b\n\x03\x04\"\x01\x12\x04\x85\x02\x08\x12\n\x0c\n\
+    \x04\x04\"\x02\0\x12\x04\x86\x02\x04\x15\n\r\n\x05\x04\"\x02\0\x05\x12\
+    \x04\x86\x02\x04\n\n\r\n\x05\x04\"\x02\0\x01\x12\x04\x86\x02\x0b\x10\n\r\
+    \n\x05\x04\"\x02\0\x03\x12\x04\x86\x02\x13\x14\n\x0c\n\x04\x04\"\x02\x01\
+    \x12\x04\x87\x02\x04\x14\n\r\n\x05\x04\"\x02\x01\x06\x12\x04\x87\x02\x04\
+    \x0b\n\r\n\x05\x04\"\x02\x01\x01\x12\x04\x87\x02\x0c\x0f\n\r\n\x05\x04\"\
+    \x02\x01\x03\x12\x04\x87\x02\x12\x13b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -1,4 +1,21 @@
+pub mod aggregation;
+pub mod analyzer;
+pub mod budget;
+pub mod cache;
+pub mod cardinality;
+pub mod collapse;
+pub mod date_math;
+pub mod facet;
+pub mod function_score;
+pub mod fuzzy;
+pub mod geo;
+pub mod highlight;
 pub mod metrics;
 pub mod peer;
+pub mod percentiles;
+pub mod phrase;
+pub mod query_dsl;
+pub mod ranking;
 pub mod server;
+pub mod spelling;
 pub mod util;
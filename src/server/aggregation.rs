@@ -0,0 +1,1164 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use chrono::{FixedOffset, TimeZone, Utc};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use tantivy::collector::{Collector, SegmentCollector};
+use tantivy::fastfield::{FacetReader, FastFieldReader};
+use tantivy::schema::{Facet, Field, FieldType, Schema};
+use tantivy::{DateTime, DocId, Score, SegmentLocalId, SegmentReader};
+
+use crate::server::cardinality::{self, CardinalityReader, CardinalitySource, HyperLogLog};
+use crate::server::date_math::{parse_date_math, parse_duration, parse_time_zone, round_timestamp};
+use crate::server::facet::FacetOrder;
+use crate::server::percentiles::{self, percentiles_result, TDigest};
+
+// Histograms fail with more buckets than this, as their number depends on the data.
+pub const DEFAULT_MAX_BUCKETS: usize = 10_000;
+
+// Searches fail with more buckets than this in all their aggregations, as nested histograms
+// multiply their buckets.
+pub const MAX_TOTAL_BUCKETS: usize = 65_536;
+
+fn default_max_buckets() -> usize {
+    DEFAULT_MAX_BUCKETS
+}
+
+fn default_precision() -> u8 {
+    cardinality::DEFAULT_PRECISION
+}
+
+fn default_percents() -> Vec<f64> {
+    percentiles::DEFAULT_PERCENTS.to_vec()
+}
+
+fn default_compression() -> f64 {
+    percentiles::DEFAULT_COMPRESSION
+}
+
+#[derive(Deserialize)]
+pub struct RangeSetting {
+    #[serde(default)]
+    pub key: Option<String>,
+    #[serde(default)]
+    pub from: Option<Value>,
+    #[serde(default)]
+    pub to: Option<Value>,
+}
+
+// Bucket aggregations may have sub-aggregations in "aggs", which are computed for the documents
+// of each bucket.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregationSetting {
+    Range {
+        field: String,
+        ranges: Vec<RangeSetting>,
+        #[serde(default)]
+        aggs: BTreeMap<String, AggregationSetting>,
+    },
+    Histogram {
+        field: String,
+        interval: f64,
+        #[serde(default)]
+        offset: f64,
+        #[serde(default = "default_max_buckets")]
+        max_buckets: usize,
+        #[serde(default)]
+        aggs: BTreeMap<String, AggregationSetting>,
+    },
+    DateHistogram {
+        field: String,
+        interval: String,
+        #[serde(default)]
+        time_zone: String,
+        #[serde(default = "default_max_buckets")]
+        max_buckets: usize,
+        #[serde(default)]
+        aggs: BTreeMap<String, AggregationSetting>,
+    },
+    Facet {
+        field: String,
+        #[serde(default)]
+        prefix: String,
+        #[serde(default)]
+        limit: Option<usize>,
+        #[serde(default)]
+        sort: String,
+        #[serde(default)]
+        aggs: BTreeMap<String, AggregationSetting>,
+    },
+    Stats {
+        field: String,
+    },
+    Min {
+        field: String,
+    },
+    Max {
+        field: String,
+    },
+    Avg {
+        field: String,
+    },
+    Sum {
+        field: String,
+    },
+    Cardinality {
+        field: String,
+        #[serde(default = "default_precision")]
+        precision: u8,
+    },
+    Percentiles {
+        field: String,
+        #[serde(default = "default_percents")]
+        percents: Vec<f64>,
+        #[serde(default = "default_compression")]
+        compression: f64,
+    },
+}
+
+#[derive(Clone, Copy)]
+pub enum ValueSource {
+    U64(Field),
+    I64(Field),
+    F64(Field),
+    Date(Field),
+}
+
+impl ValueSource {
+    pub fn new(schema: &Schema, field_name: &str) -> Result<ValueSource, String> {
+        let field = schema
+            .get_field(field_name)
+            .ok_or_else(|| format!("unknown field: {}", field_name))?;
+        match schema.get_field_entry(field).field_type() {
+            FieldType::U64(options) if options.is_fast() => Ok(ValueSource::U64(field)),
+            FieldType::I64(options) if options.is_fast() => Ok(ValueSource::I64(field)),
+            FieldType::F64(options) if options.is_fast() => Ok(ValueSource::F64(field)),
+            FieldType::Date(options) if options.is_fast() => Ok(ValueSource::Date(field)),
+            _ => Err(format!("not a numeric or date fast field: {}", field_name)),
+        }
+    }
+
+    fn is_date(self) -> bool {
+        match self {
+            ValueSource::Date(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn reader(self, segment_reader: &SegmentReader) -> ValueReader {
+        let fast_fields = segment_reader.fast_fields();
+        match self {
+            ValueSource::U64(field) => ValueReader::U64(fast_fields.u64(field)),
+            ValueSource::I64(field) => ValueReader::I64(fast_fields.i64(field)),
+            ValueSource::F64(field) => ValueReader::F64(fast_fields.f64(field)),
+            ValueSource::Date(field) => ValueReader::Date(fast_fields.date(field)),
+        }
+    }
+
+    // Dates are given as date math expressions and numbers as JSON numbers or strings.
+    fn parse_bound(
+        self,
+        bound: &Value,
+        now: chrono::DateTime<Utc>,
+        tz: &FixedOffset,
+    ) -> Result<f64, String> {
+        match bound {
+            Value::Number(number) => number
+                .as_f64()
+                .ok_or_else(|| format!("invalid bound: {}", number)),
+            Value::String(text) if self.is_date() => {
+                Ok(parse_date_math(text, now, tz)?.timestamp() as f64)
+            }
+            Value::String(text) => text.parse::<f64>().map_err(|e| e.to_string()),
+            _ => Err(format!("invalid bound: {}", bound)),
+        }
+    }
+
+    // Dates are formatted in RFC 3339, other values are numbers.
+    pub fn to_json(self, value: f64) -> Value {
+        if self.is_date() {
+            Value::from(Utc.timestamp(value as i64, 0).to_rfc3339())
+        } else {
+            Value::from(value)
+        }
+    }
+}
+
+// Reads the value of a document as a number. Dates are read as timestamps in seconds.
+pub enum ValueReader {
+    U64(Option<FastFieldReader<u64>>),
+    I64(Option<FastFieldReader<i64>>),
+    F64(Option<FastFieldReader<f64>>),
+    Date(Option<FastFieldReader<DateTime>>),
+}
+
+impl ValueReader {
+    pub fn get(&self, doc: DocId) -> Option<f64> {
+        match self {
+            ValueReader::U64(reader) => reader.as_ref().map(|r| r.get(doc) as f64),
+            ValueReader::I64(reader) => reader.as_ref().map(|r| r.get(doc) as f64),
+            ValueReader::F64(reader) => reader.as_ref().map(|r| r.get(doc)),
+            ValueReader::Date(reader) => reader.as_ref().map(|r| r.get(doc).timestamp() as f64),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Metric {
+    Stats,
+    Min,
+    Max,
+    Avg,
+    Sum,
+}
+
+#[derive(Clone, Copy)]
+enum DateInterval {
+    Calendar(char),
+    Fixed(i64),
+}
+
+impl DateInterval {
+    // Calendar units such as "1M" or "month" follow the calendar, other intervals such as "12h"
+    // are fixed durations.
+    fn parse(text: &str) -> Result<DateInterval, String> {
+        let unit = match text {
+            "1y" | "y" | "year" => Some('y'),
+            "1M" | "M" | "month" => Some('M'),
+            "1w" | "w" | "week" => Some('w'),
+            "1d" | "d" | "day" => Some('d'),
+            "1h" | "h" | "hour" => Some('h'),
+            "1m" | "m" | "minute" => Some('m'),
+            "1s" | "s" | "second" => Some('s'),
+            _ => None,
+        };
+        match unit {
+            Some(unit) => Ok(DateInterval::Calendar(unit)),
+            None => match parse_duration(text)? {
+                seconds if seconds > 0 => Ok(DateInterval::Fixed(seconds)),
+                _ => Err(format!("invalid interval: {}", text)),
+            },
+        }
+    }
+}
+
+enum AggregationKind {
+    Range(Vec<(String, Option<f64>, Option<f64>)>),
+    Histogram {
+        interval: f64,
+        offset: f64,
+        max_buckets: usize,
+    },
+    DateHistogram {
+        interval: DateInterval,
+        tz: FixedOffset,
+        max_buckets: usize,
+    },
+    // The prefix has no trailing slash, so the root is the empty prefix.
+    Facet {
+        prefix: String,
+        limit: Option<usize>,
+        order: FacetOrder,
+    },
+    Metric(Metric),
+    Cardinality {
+        precision: u8,
+    },
+    Percentiles {
+        percents: Vec<f64>,
+        compression: f64,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum AggregationSource {
+    Value(ValueSource),
+    Facet(Field),
+    Cardinality(CardinalitySource),
+}
+
+struct Aggregation {
+    name: String,
+    source: AggregationSource,
+    kind: AggregationKind,
+    subs: Vec<Aggregation>,
+}
+
+#[derive(Clone, Copy)]
+pub struct Stats {
+    count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for Stats {
+    fn default() -> Stats {
+        Stats {
+            count: 0,
+            sum: 0.0,
+            min: std::f64::INFINITY,
+            max: std::f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl Stats {
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    fn merge(&mut self, other: &Stats) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+}
+
+// A bucket counts its documents and holds the states of the sub-aggregations over them.
+pub struct Bucket {
+    doc_count: u64,
+    subs: Vec<AggregationState>,
+}
+
+// The partial result of an aggregation. Histogram buckets are keyed by their index for numeric
+// histograms, and by their start timestamp for date histograms. Facet buckets are keyed by the
+// child facet of the prefix.
+pub enum AggregationState {
+    Range(Vec<Bucket>),
+    Histogram(BTreeMap<i64, Bucket>),
+    Facet(HashMap<String, Bucket>),
+    Stats(Stats),
+    Cardinality(HyperLogLog),
+    Percentiles(TDigest),
+}
+
+// Counts the buckets created over a segment. One bucket over the limit is allowed to tell that
+// it was exceeded, and the rest are dropped so that the memory stays bounded.
+struct BucketBudget {
+    limit: usize,
+    used: usize,
+}
+
+impl BucketBudget {
+    fn take(&mut self) -> bool {
+        if self.used > self.limit {
+            return false;
+        }
+        self.used += 1;
+        true
+    }
+}
+
+// The readers of an aggregation and of its sub-aggregations over a segment.
+struct SegmentAggregation {
+    source: SegmentSource,
+    subs: Vec<SegmentAggregation>,
+}
+
+enum SegmentSource {
+    Value(ValueReader),
+    // Facet ordinals are local to the segment, so the child facets are cached by ordinal.
+    Facet(FacetReader, Vec<u64>, HashMap<u64, Option<String>>),
+    Cardinality(CardinalityReader),
+}
+
+// Returns the child of the prefix the facet is under, such as "/category/books" for the facet
+// "/category/books/fiction" under the prefix "/category".
+fn child_facet(facet: &str, prefix: &str) -> Option<String> {
+    if !facet.starts_with(prefix) || !facet[prefix.len()..].starts_with('/') {
+        return None;
+    }
+    let rest = &facet[prefix.len() + 1..];
+    match rest.split('/').next() {
+        Some(segment) if !segment.is_empty() => Some(format!("{}/{}", prefix, segment)),
+        _ => None,
+    }
+}
+
+impl Aggregation {
+    fn new(
+        schema: &Schema,
+        name: &str,
+        setting: &AggregationSetting,
+        default_tz: &FixedOffset,
+    ) -> Result<Aggregation, String> {
+        let (field_name, kind, aggs) = match setting {
+            AggregationSetting::Range {
+                field,
+                ranges,
+                aggs,
+            } => {
+                let source = ValueSource::new(schema, field)?;
+                let now = Utc::now();
+                let mut bounds = Vec::new();
+                for range in ranges {
+                    let from = match &range.from {
+                        Some(from) => Some(source.parse_bound(from, now, default_tz)?),
+                        None => None,
+                    };
+                    let to = match &range.to {
+                        Some(to) => Some(source.parse_bound(to, now, default_tz)?),
+                        None => None,
+                    };
+                    let key = range.key.clone().unwrap_or_else(|| {
+                        format!("{}-{}", bound_text(&range.from), bound_text(&range.to))
+                    });
+                    bounds.push((key, from, to));
+                }
+                (field, AggregationKind::Range(bounds), Some(aggs))
+            }
+            AggregationSetting::Histogram {
+                field,
+                interval,
+                offset,
+                max_buckets,
+                aggs,
+            } => {
+                if *interval <= 0.0 {
+                    return Err(format!("invalid interval: {}", interval));
+                }
+                (
+                    field,
+                    AggregationKind::Histogram {
+                        interval: *interval,
+                        offset: *offset,
+                        max_buckets: *max_buckets,
+                    },
+                    Some(aggs),
+                )
+            }
+            AggregationSetting::DateHistogram {
+                field,
+                interval,
+                time_zone,
+                max_buckets,
+                aggs,
+            } => {
+                let tz = if time_zone.is_empty() {
+                    *default_tz
+                } else {
+                    parse_time_zone(time_zone)?
+                };
+                (
+                    field,
+                    AggregationKind::DateHistogram {
+                        interval: DateInterval::parse(interval)?,
+                        tz,
+                        max_buckets: *max_buckets,
+                    },
+                    Some(aggs),
+                )
+            }
+            AggregationSetting::Facet {
+                field,
+                prefix,
+                limit,
+                sort,
+                aggs,
+            } => {
+                let prefix = prefix.trim_end_matches('/');
+                if !prefix.is_empty() && !prefix.starts_with('/') {
+                    return Err(format!("invalid facet prefix: {}", prefix));
+                }
+                (
+                    field,
+                    AggregationKind::Facet {
+                        prefix: prefix.to_string(),
+                        limit: *limit,
+                        order: FacetOrder::parse(sort)?,
+                    },
+                    Some(aggs),
+                )
+            }
+            AggregationSetting::Stats { field } => {
+                (field, AggregationKind::Metric(Metric::Stats), None)
+            }
+            AggregationSetting::Min { field } => {
+                (field, AggregationKind::Metric(Metric::Min), None)
+            }
+            AggregationSetting::Max { field } => {
+                (field, AggregationKind::Metric(Metric::Max), None)
+            }
+            AggregationSetting::Avg { field } => {
+                (field, AggregationKind::Metric(Metric::Avg), None)
+            }
+            AggregationSetting::Sum { field } => {
+                (field, AggregationKind::Metric(Metric::Sum), None)
+            }
+            AggregationSetting::Cardinality { field, precision } => {
+                HyperLogLog::new(*precision)?;
+                (
+                    field,
+                    AggregationKind::Cardinality {
+                        precision: *precision,
+                    },
+                    None,
+                )
+            }
+            AggregationSetting::Percentiles {
+                field,
+                percents,
+                compression,
+            } => {
+                if *compression <= 0.0 {
+                    return Err(format!("invalid compression: {}", compression));
+                }
+                (
+                    field,
+                    AggregationKind::Percentiles {
+                        percents: percents.clone(),
+                        compression: *compression,
+                    },
+                    None,
+                )
+            }
+        };
+        let source = match kind {
+            AggregationKind::Facet { .. } => {
+                let field = schema
+                    .get_field(field_name)
+                    .ok_or_else(|| format!("unknown field: {}", field_name))?;
+                match schema.get_field_entry(field).field_type() {
+                    FieldType::HierarchicalFacet => AggregationSource::Facet(field),
+                    _ => return Err(format!("not a hierarchical facet field: {}", field_name)),
+                }
+            }
+            AggregationKind::Cardinality { .. } => {
+                AggregationSource::Cardinality(CardinalitySource::new(schema, field_name)?)
+            }
+            AggregationKind::DateHistogram { .. } => {
+                let source = ValueSource::new(schema, field_name)?;
+                if !source.is_date() {
+                    return Err(format!("not a date fast field: {}", field_name));
+                }
+                AggregationSource::Value(source)
+            }
+            _ => AggregationSource::Value(ValueSource::new(schema, field_name)?),
+        };
+        let mut subs = Vec::new();
+        if let Some(aggs) = aggs {
+            for (sub_name, sub_setting) in aggs {
+                subs.push(Aggregation::new(schema, sub_name, sub_setting, default_tz)?);
+            }
+        }
+        Ok(Aggregation {
+            name: name.to_string(),
+            source,
+            kind,
+            subs,
+        })
+    }
+
+    fn new_state(&self) -> AggregationState {
+        match &self.kind {
+            AggregationKind::Range(ranges) => {
+                AggregationState::Range(ranges.iter().map(|_| self.new_bucket()).collect())
+            }
+            AggregationKind::Histogram { .. } | AggregationKind::DateHistogram { .. } => {
+                AggregationState::Histogram(BTreeMap::new())
+            }
+            AggregationKind::Facet { .. } => AggregationState::Facet(HashMap::new()),
+            AggregationKind::Metric(_) => AggregationState::Stats(Stats::default()),
+            AggregationKind::Cardinality { precision } => {
+                AggregationState::Cardinality(HyperLogLog::new(*precision).unwrap())
+            }
+            AggregationKind::Percentiles { compression, .. } => {
+                AggregationState::Percentiles(TDigest::new(*compression))
+            }
+        }
+    }
+
+    fn new_bucket(&self) -> Bucket {
+        Bucket {
+            doc_count: 0,
+            subs: self.subs.iter().map(|sub| sub.new_state()).collect(),
+        }
+    }
+
+    fn segment(&self, segment_reader: &SegmentReader) -> tantivy::Result<SegmentAggregation> {
+        let source = match self.source {
+            AggregationSource::Value(source) => SegmentSource::Value(source.reader(segment_reader)),
+            AggregationSource::Facet(field) => SegmentSource::Facet(
+                segment_reader.facet_reader(field)?,
+                Vec::new(),
+                HashMap::new(),
+            ),
+            AggregationSource::Cardinality(source) => {
+                SegmentSource::Cardinality(source.reader(segment_reader)?)
+            }
+        };
+        let mut subs = Vec::new();
+        for sub in &self.subs {
+            subs.push(sub.segment(segment_reader)?);
+        }
+        Ok(SegmentAggregation { source, subs })
+    }
+
+    fn bucket_key(&self, value: f64) -> Option<i64> {
+        match self.kind {
+            AggregationKind::Histogram {
+                interval, offset, ..
+            } => Some(((value - offset) / interval).floor() as i64),
+            AggregationKind::DateHistogram { interval, tz, .. } => match interval {
+                DateInterval::Calendar(unit) => round_timestamp(value as i64, unit, &tz).ok(),
+                DateInterval::Fixed(seconds) => {
+                    // buckets start at midnight in the time zone
+                    let local_offset = i64::from(tz.local_minus_utc());
+                    let local = value as i64 + local_offset;
+                    Some(local.div_euclid(seconds) * seconds - local_offset)
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn max_buckets(&self) -> usize {
+        match self.kind {
+            AggregationKind::Histogram { max_buckets, .. }
+            | AggregationKind::DateHistogram { max_buckets, .. } => max_buckets,
+            _ => std::usize::MAX,
+        }
+    }
+
+    fn collect(
+        &self,
+        segment: &mut SegmentAggregation,
+        state: &mut AggregationState,
+        doc: DocId,
+        budget: &mut BucketBudget,
+    ) {
+        match (&mut segment.source, state) {
+            (SegmentSource::Value(reader), state) => {
+                if let Some(value) = reader.get(doc) {
+                    self.add(&mut segment.subs, state, value, doc, budget);
+                }
+            }
+            (SegmentSource::Facet(reader, ords, children), AggregationState::Facet(buckets)) => {
+                let prefix = match &self.kind {
+                    AggregationKind::Facet { prefix, .. } => prefix.as_str(),
+                    _ => return,
+                };
+                reader.facet_ords(doc, ords);
+                // a document is counted once per child, even with several facets under it
+                let mut doc_children: Vec<String> = Vec::new();
+                for ord in ords.iter() {
+                    let child = children.entry(*ord).or_insert_with(|| {
+                        let mut facet = Facet::root();
+                        reader.facet_from_ord(*ord, &mut facet);
+                        child_facet(&facet.to_string(), prefix)
+                    });
+                    if let Some(child) = child {
+                        if !doc_children.contains(child) {
+                            doc_children.push(child.clone());
+                        }
+                    }
+                }
+                for child in doc_children {
+                    if !buckets.contains_key(&child) && !budget.take() {
+                        continue;
+                    }
+                    let bucket = buckets.entry(child).or_insert_with(|| self.new_bucket());
+                    self.collect_bucket(&mut segment.subs, bucket, doc, budget);
+                }
+            }
+            (SegmentSource::Cardinality(reader), AggregationState::Cardinality(sketch)) => {
+                reader.add(doc, sketch)
+            }
+            _ => {}
+        }
+    }
+
+    // Ranges include their lower bound and exclude their upper bound.
+    fn add(
+        &self,
+        subs: &mut [SegmentAggregation],
+        state: &mut AggregationState,
+        value: f64,
+        doc: DocId,
+        budget: &mut BucketBudget,
+    ) {
+        match (&self.kind, state) {
+            (AggregationKind::Range(ranges), AggregationState::Range(buckets)) => {
+                for ((_key, from, to), bucket) in ranges.iter().zip(buckets.iter_mut()) {
+                    if from.map_or(true, |from| value >= from) && to.map_or(true, |to| value < to) {
+                        self.collect_bucket(subs, bucket, doc, budget);
+                    }
+                }
+            }
+            // One bucket over the limit is kept to tell that it was exceeded, and the rest are
+            // dropped so that the memory stays bounded.
+            (_, AggregationState::Histogram(buckets)) => {
+                if let Some(key) = self.bucket_key(value) {
+                    if !buckets.contains_key(&key)
+                        && (buckets.len() > self.max_buckets() || !budget.take())
+                    {
+                        return;
+                    }
+                    let bucket = buckets.entry(key).or_insert_with(|| self.new_bucket());
+                    self.collect_bucket(subs, bucket, doc, budget);
+                }
+            }
+            (_, AggregationState::Stats(stats)) => stats.add(value),
+            (_, AggregationState::Percentiles(digest)) => digest.add(value),
+            _ => {}
+        }
+    }
+
+    fn collect_bucket(
+        &self,
+        subs: &mut [SegmentAggregation],
+        bucket: &mut Bucket,
+        doc: DocId,
+        budget: &mut BucketBudget,
+    ) {
+        bucket.doc_count += 1;
+        for ((sub, segment), state) in self
+            .subs
+            .iter()
+            .zip(subs.iter_mut())
+            .zip(bucket.subs.iter_mut())
+        {
+            sub.collect(segment, state, doc, budget);
+        }
+    }
+
+    fn merge(&self, state: &mut AggregationState, other: AggregationState) {
+        match (state, other) {
+            (AggregationState::Range(buckets), AggregationState::Range(other)) => {
+                for (bucket, other) in buckets.iter_mut().zip(other) {
+                    self.merge_bucket(bucket, other);
+                }
+            }
+            (AggregationState::Histogram(buckets), AggregationState::Histogram(other)) => {
+                for (key, other) in other {
+                    if let Some(bucket) = buckets.get_mut(&key) {
+                        self.merge_bucket(bucket, other);
+                    } else if buckets.len() <= self.max_buckets() {
+                        buckets.insert(key, other);
+                    }
+                }
+            }
+            (AggregationState::Facet(buckets), AggregationState::Facet(other)) => {
+                for (key, other) in other {
+                    if let Some(bucket) = buckets.get_mut(&key) {
+                        self.merge_bucket(bucket, other);
+                    } else {
+                        buckets.insert(key, other);
+                    }
+                }
+            }
+            (AggregationState::Stats(stats), AggregationState::Stats(other)) => stats.merge(&other),
+            (AggregationState::Cardinality(sketch), AggregationState::Cardinality(other)) => {
+                sketch.merge(&other)
+            }
+            (AggregationState::Percentiles(digest), AggregationState::Percentiles(other)) => {
+                digest.merge(&other)
+            }
+            _ => {}
+        }
+    }
+
+    fn merge_bucket(&self, bucket: &mut Bucket, other: Bucket) {
+        bucket.doc_count += other.doc_count;
+        for ((sub, state), other) in self.subs.iter().zip(bucket.subs.iter_mut()).zip(other.subs) {
+            sub.merge(state, other);
+        }
+    }
+
+    // Fails if a histogram, or one in a bucket, has more buckets than its limit, or if there are
+    // more buckets than the total limit, counted in the total.
+    fn check_buckets(
+        &self,
+        state: &AggregationState,
+        total: &mut usize,
+        max_total: usize,
+    ) -> Result<(), String> {
+        let buckets: Vec<&Bucket> = match state {
+            AggregationState::Range(buckets) => buckets.iter().collect(),
+            AggregationState::Histogram(buckets) => {
+                if buckets.len() > self.max_buckets() {
+                    return Err(format!(
+                        "too many buckets in {}: more than max_buckets {}",
+                        self.name,
+                        self.max_buckets()
+                    ));
+                }
+                buckets.values().collect()
+            }
+            AggregationState::Facet(buckets) => buckets.values().collect(),
+            _ => Vec::new(),
+        };
+        *total += buckets.len();
+        if *total > max_total {
+            return Err(format!(
+                "too many buckets: more than {} in all aggregations",
+                max_total
+            ));
+        }
+        for bucket in buckets {
+            for (sub, state) in self.subs.iter().zip(&bucket.subs) {
+                sub.check_buckets(state, total, max_total)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn to_json(&self, value: f64) -> Value {
+        match self.source {
+            AggregationSource::Value(source) => source.to_json(value),
+            _ => Value::from(value),
+        }
+    }
+
+    fn result(&self, state: AggregationState) -> Value {
+        match (&self.kind, state) {
+            (AggregationKind::Range(ranges), AggregationState::Range(buckets)) => {
+                let buckets: Vec<Value> = ranges
+                    .iter()
+                    .zip(buckets)
+                    .map(|((key, from, to), bucket)| {
+                        let mut object = Map::new();
+                        object.insert("key".to_string(), Value::from(key.as_str()));
+                        if let Some(from) = from {
+                            object.insert("from".to_string(), self.to_json(*from));
+                        }
+                        if let Some(to) = to {
+                            object.insert("to".to_string(), self.to_json(*to));
+                        }
+                        self.bucket_result(object, bucket)
+                    })
+                    .collect();
+                json!({ "buckets": buckets })
+            }
+            (
+                AggregationKind::Histogram {
+                    interval, offset, ..
+                },
+                AggregationState::Histogram(b),
+            ) => {
+                let buckets: Vec<Value> = b
+                    .into_iter()
+                    .map(|(index, bucket)| {
+                        let mut object = Map::new();
+                        let key = index as f64 * interval + offset;
+                        object.insert("key".to_string(), Value::from(key));
+                        self.bucket_result(object, bucket)
+                    })
+                    .collect();
+                json!({ "buckets": buckets })
+            }
+            (AggregationKind::DateHistogram { .. }, AggregationState::Histogram(b)) => {
+                let buckets: Vec<Value> = b
+                    .into_iter()
+                    .map(|(start, bucket)| {
+                        let mut object = Map::new();
+                        object.insert("key".to_string(), self.to_json(start as f64));
+                        self.bucket_result(object, bucket)
+                    })
+                    .collect();
+                json!({ "buckets": buckets })
+            }
+            (AggregationKind::Facet { limit, order, .. }, AggregationState::Facet(b)) => {
+                let mut b: Vec<(String, Bucket)> = b.into_iter().collect();
+                b.sort_by(|x, y| order.compare((&x.0, x.1.doc_count), (&y.0, y.1.doc_count)));
+                if let Some(limit) = limit {
+                    b.truncate(*limit);
+                }
+                let buckets: Vec<Value> = b
+                    .into_iter()
+                    .map(|(facet, bucket)| {
+                        let mut object = Map::new();
+                        object.insert("key".to_string(), Value::from(facet));
+                        self.bucket_result(object, bucket)
+                    })
+                    .collect();
+                json!({ "buckets": buckets })
+            }
+            (AggregationKind::Metric(metric), AggregationState::Stats(stats)) => {
+                self.metric_result(*metric, &stats)
+            }
+            (AggregationKind::Cardinality { .. }, AggregationState::Cardinality(sketch)) => {
+                json!({ "value": sketch.estimate() })
+            }
+            (
+                AggregationKind::Percentiles { percents, .. },
+                AggregationState::Percentiles(mut digest),
+            ) => match self.source {
+                AggregationSource::Value(source) => {
+                    percentiles_result(source, percents, &mut digest)
+                }
+                _ => Value::Null,
+            },
+            _ => Value::Null,
+        }
+    }
+
+    // Sub-aggregation results are added to the bucket by name.
+    fn bucket_result(&self, mut object: Map<String, Value>, bucket: Bucket) -> Value {
+        object.insert("doc_count".to_string(), Value::from(bucket.doc_count));
+        for (sub, state) in self.subs.iter().zip(bucket.subs) {
+            object.insert(sub.name.clone(), sub.result(state));
+        }
+        Value::Object(object)
+    }
+
+    // Metrics of no documents are null.
+    fn metric_result(&self, metric: Metric, stats: &Stats) -> Value {
+        let value = |value: f64| {
+            if stats.count == 0 {
+                Value::Null
+            } else {
+                self.to_json(value)
+            }
+        };
+        let avg = stats.sum / stats.count as f64;
+        match metric {
+            Metric::Stats => json!({
+                "count": stats.count,
+                "min": value(stats.min),
+                "max": value(stats.max),
+                "avg": value(avg),
+                "sum": Value::from(stats.sum),
+            }),
+            Metric::Min => json!({ "value": value(stats.min) }),
+            Metric::Max => json!({ "value": value(stats.max) }),
+            Metric::Avg => json!({ "value": value(avg) }),
+            Metric::Sum => json!({ "value": stats.sum }),
+        }
+    }
+}
+
+fn bound_text(bound: &Option<Value>) -> String {
+    match bound {
+        Some(Value::String(text)) => text.clone(),
+        Some(value) => value.to_string(),
+        None => String::from("*"),
+    }
+}
+
+// Computes named aggregations over the fast field and facet values of the hit documents. Each
+// segment collects into its own tree of states, which are merged bucket by bucket.
+#[derive(Clone)]
+pub struct AggregationCollector {
+    aggregations: Arc<Vec<Aggregation>>,
+    max_total_buckets: usize,
+}
+
+impl AggregationCollector {
+    pub fn new(
+        schema: &Schema,
+        settings: &BTreeMap<String, AggregationSetting>,
+        time_zone: &str,
+    ) -> Result<AggregationCollector, String> {
+        let tz = parse_time_zone(time_zone)?;
+        let mut aggregations = Vec::new();
+        for (name, setting) in settings {
+            aggregations.push(Aggregation::new(schema, name, setting, &tz)?);
+        }
+        Ok(AggregationCollector {
+            aggregations: Arc::new(aggregations),
+            max_total_buckets: MAX_TOTAL_BUCKETS,
+        })
+    }
+
+    pub fn results(&self, states: Vec<AggregationState>) -> Result<HashMap<String, Value>, String> {
+        let mut total = 0;
+        for (aggregation, state) in self.aggregations.iter().zip(&states) {
+            aggregation.check_buckets(state, &mut total, self.max_total_buckets)?;
+        }
+        Ok(self
+            .aggregations
+            .iter()
+            .zip(states)
+            .map(|(aggregation, state)| (aggregation.name.clone(), aggregation.result(state)))
+            .collect())
+    }
+}
+
+impl Collector for AggregationCollector {
+    type Fruit = Vec<AggregationState>;
+    type Child = AggregationSegmentCollector;
+
+    fn for_segment(
+        &self,
+        _segment_local_id: SegmentLocalId,
+        segment: &SegmentReader,
+    ) -> tantivy::Result<Self::Child> {
+        let mut segments = Vec::new();
+        for aggregation in self.aggregations.iter() {
+            segments.push(aggregation.segment(segment)?);
+        }
+        Ok(AggregationSegmentCollector {
+            aggregations: self.aggregations.clone(),
+            segments,
+            budget: BucketBudget {
+                limit: self.max_total_buckets,
+                used: 0,
+            },
+            states: self
+                .aggregations
+                .iter()
+                .map(|aggregation| aggregation.new_state())
+                .collect(),
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        false
+    }
+
+    fn merge_fruits(&self, segment_fruits: Vec<Self::Fruit>) -> tantivy::Result<Self::Fruit> {
+        let mut merged: Self::Fruit = self
+            .aggregations
+            .iter()
+            .map(|aggregation| aggregation.new_state())
+            .collect();
+        for states in segment_fruits {
+            for ((aggregation, merged_state), state) in
+                self.aggregations.iter().zip(merged.iter_mut()).zip(states)
+            {
+                aggregation.merge(merged_state, state);
+            }
+        }
+        Ok(merged)
+    }
+}
+
+pub struct AggregationSegmentCollector {
+    aggregations: Arc<Vec<Aggregation>>,
+    segments: Vec<SegmentAggregation>,
+    budget: BucketBudget,
+    states: Vec<AggregationState>,
+}
+
+impl SegmentCollector for AggregationSegmentCollector {
+    type Fruit = Vec<AggregationState>;
+
+    fn collect(&mut self, doc: DocId, _score: Score) {
+        for ((aggregation, segment), state) in self
+            .aggregations
+            .iter()
+            .zip(self.segments.iter_mut())
+            .zip(self.states.iter_mut())
+        {
+            aggregation.collect(segment, state, doc, &mut self.budget);
+        }
+    }
+
+    fn harvest(self) -> Self::Fruit {
+        self.states
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tantivy::doc;
+    use tantivy::query::AllQuery;
+    use tantivy::schema::{Schema, FAST};
+    use tantivy::Index;
+
+    use super::*;
+
+    // Two segments, so that buckets are merged across them.
+    fn create_index() -> (Index, Schema) {
+        let mut schema_builder = Schema::builder();
+        let x = schema_builder.add_i64_field("x", FAST);
+        let y = schema_builder.add_i64_field("y", FAST);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema.clone());
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+        index_writer.add_document(doc!(x => 1i64, y => 1i64));
+        index_writer.add_document(doc!(x => 2i64, y => 12i64));
+        index_writer.commit().unwrap();
+        index_writer.add_document(doc!(x => 5i64, y => 25i64));
+        index_writer.add_document(doc!(x => 12i64, y => 1i64));
+        index_writer.commit().unwrap();
+        (index, schema)
+    }
+
+    fn aggregate(collector: &AggregationCollector, index: &Index) -> Result<Value, String> {
+        let searcher = index.reader().unwrap().searcher();
+        assert_eq!(searcher.segment_readers().len(), 2);
+        let states = searcher.search(&AllQuery, collector).unwrap();
+        collector
+            .results(states)
+            .map(|results| serde_json::to_value(results).unwrap())
+    }
+
+    fn collector(schema: &Schema, settings: Value) -> AggregationCollector {
+        let settings: BTreeMap<String, AggregationSetting> =
+            serde_json::from_value(settings).unwrap();
+        AggregationCollector::new(schema, &settings, "").unwrap()
+    }
+
+    #[test]
+    fn test_histogram_merges_buckets_across_segments() {
+        let (index, schema) = create_index();
+        let collector = collector(
+            &schema,
+            json!({
+                "by_x": {
+                    "histogram": {
+                        "field": "x",
+                        "interval": 10.0,
+                        "aggs": { "sum_y": { "sum": { "field": "y" } } }
+                    }
+                }
+            }),
+        );
+
+        let results = aggregate(&collector, &index).unwrap();
+        assert_eq!(
+            results["by_x"]["buckets"],
+            json!([
+                { "key": 0.0, "doc_count": 3, "sum_y": { "value": 38.0 } },
+                { "key": 10.0, "doc_count": 1, "sum_y": { "value": 1.0 } }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_histogram_max_buckets() {
+        let (index, schema) = create_index();
+        let collector = collector(
+            &schema,
+            json!({
+                "by_x": { "histogram": { "field": "x", "interval": 1.0, "max_buckets": 3 } }
+            }),
+        );
+
+        let err = aggregate(&collector, &index).unwrap_err();
+        assert_eq!(err, "too many buckets in by_x: more than max_buckets 3");
+    }
+
+    #[test]
+    fn test_nested_histograms_share_the_total_bucket_limit() {
+        let (index, schema) = create_index();
+        let mut collector = collector(
+            &schema,
+            json!({
+                "by_x": {
+                    "histogram": {
+                        "field": "x",
+                        "interval": 1.0,
+                        "aggs": { "by_y": { "histogram": { "field": "y", "interval": 1.0 } } }
+                    }
+                }
+            }),
+        );
+
+        // 4 buckets of x with a bucket of y each
+        assert!(aggregate(&collector, &index).is_ok());
+        collector.max_total_buckets = 7;
+        let err = aggregate(&collector, &index).unwrap_err();
+        assert_eq!(err, "too many buckets: more than 7 in all aggregations");
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::fs;
+
+use serde::Deserialize;
+use tantivy::schema::FieldType;
+use tantivy::tokenizer::{
+    AsciiFoldingFilter, Language, LowerCaser, NgramTokenizer, RawTokenizer, RemoveLongFilter,
+    SimpleTokenizer, Stemmer, StopWordFilter, Token, TokenFilter, TokenStream, Tokenizer,
+};
+use tantivy::Index;
+
+// A copy of the analyzer file kept in the index directory, so that the index opens with the
+// analyzers it was built with.
+pub const ANALYZER_FILE_NAME: &str = "analyzers.json";
+
+#[derive(Deserialize)]
+#[serde(tag = "name", rename_all = "snake_case")]
+pub enum TokenizerSetting {
+    Simple,
+    Raw,
+    Ngram {
+        min_gram: usize,
+        max_gram: usize,
+        #[serde(default)]
+        prefix_only: bool,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "name", rename_all = "snake_case")]
+pub enum FilterSetting {
+    LowerCase,
+    StopWord { words: Vec<String> },
+    Stemmer { language: String },
+    AsciiFolding,
+    RemoveLong { length_limit: usize },
+}
+
+#[derive(Deserialize)]
+pub struct AnalyzerSetting {
+    pub tokenizer: TokenizerSetting,
+    #[serde(default)]
+    pub filters: Vec<FilterSetting>,
+}
+
+#[derive(Clone)]
+enum BaseTokenizer {
+    Simple(SimpleTokenizer),
+    Raw(RawTokenizer),
+    Ngram(NgramTokenizer),
+}
+
+#[derive(Clone)]
+enum Filter {
+    LowerCase(LowerCaser),
+    StopWord(StopWordFilter),
+    Stemmer(Stemmer),
+    AsciiFolding(AsciiFoldingFilter),
+    RemoveLong(RemoveLongFilter),
+}
+
+fn language(name: &str) -> Result<Language, String> {
+    match name.to_lowercase().as_str() {
+        "arabic" => Ok(Language::Arabic),
+        "danish" => Ok(Language::Danish),
+        "dutch" => Ok(Language::Dutch),
+        "english" => Ok(Language::English),
+        "finnish" => Ok(Language::Finnish),
+        "french" => Ok(Language::French),
+        "german" => Ok(Language::German),
+        "greek" => Ok(Language::Greek),
+        "hungarian" => Ok(Language::Hungarian),
+        "italian" => Ok(Language::Italian),
+        "norwegian" => Ok(Language::Norwegian),
+        "portuguese" => Ok(Language::Portuguese),
+        "romanian" => Ok(Language::Romanian),
+        "russian" => Ok(Language::Russian),
+        "spanish" => Ok(Language::Spanish),
+        "swedish" => Ok(Language::Swedish),
+        "tamil" => Ok(Language::Tamil),
+        "turkish" => Ok(Language::Turkish),
+        _ => Err(format!("unknown stemmer language: {}", name)),
+    }
+}
+
+fn collect_tokens<S: TokenStream>(mut token_stream: S) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    while token_stream.advance() {
+        tokens.push(token_stream.token().clone());
+    }
+    tokens
+}
+
+pub struct VecTokenStream {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl VecTokenStream {
+    pub fn new(tokens: Vec<Token>) -> VecTokenStream {
+        VecTokenStream { tokens, index: 0 }
+    }
+}
+
+impl TokenStream for VecTokenStream {
+    fn advance(&mut self) -> bool {
+        if self.index < self.tokens.len() {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.index - 1]
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.tokens[self.index - 1]
+    }
+}
+
+// Tantivy chains filters statically, so an analyzer built from a settings file buffers the
+// tokens and applies each filter in turn.
+#[derive(Clone)]
+pub struct Analyzer {
+    tokenizer: BaseTokenizer,
+    filters: Vec<Filter>,
+}
+
+impl Analyzer {
+    pub fn new(setting: &AnalyzerSetting) -> Result<Analyzer, String> {
+        let tokenizer = match setting.tokenizer {
+            TokenizerSetting::Simple => BaseTokenizer::Simple(SimpleTokenizer),
+            TokenizerSetting::Raw => BaseTokenizer::Raw(RawTokenizer),
+            TokenizerSetting::Ngram {
+                min_gram,
+                max_gram,
+                prefix_only,
+            } => {
+                // the ngram tokenizer panics on these
+                if min_gram == 0 || min_gram > max_gram {
+                    return Err(format!(
+                        "min_gram must be between 1 and max_gram: {} and {}",
+                        min_gram, max_gram
+                    ));
+                }
+                BaseTokenizer::Ngram(NgramTokenizer::new(min_gram, max_gram, prefix_only))
+            }
+        };
+
+        let mut filters = Vec::new();
+        for filter_setting in &setting.filters {
+            let filter = match filter_setting {
+                FilterSetting::LowerCase => Filter::LowerCase(LowerCaser),
+                FilterSetting::StopWord { words } => {
+                    Filter::StopWord(StopWordFilter::remove(words.clone()))
+                }
+                FilterSetting::Stemmer { language: name } => {
+                    Filter::Stemmer(Stemmer::new(language(name)?))
+                }
+                FilterSetting::AsciiFolding => Filter::AsciiFolding(AsciiFoldingFilter),
+                FilterSetting::RemoveLong { length_limit } => {
+                    Filter::RemoveLong(RemoveLongFilter::limit(*length_limit))
+                }
+            };
+            filters.push(filter);
+        }
+
+        Ok(Analyzer { tokenizer, filters })
+    }
+}
+
+impl<'a> Tokenizer<'a> for Analyzer {
+    type TokenStreamImpl = VecTokenStream;
+
+    fn token_stream(&self, text: &'a str) -> VecTokenStream {
+        let mut tokens = match &self.tokenizer {
+            BaseTokenizer::Simple(t) => collect_tokens(t.token_stream(text)),
+            BaseTokenizer::Raw(t) => collect_tokens(t.token_stream(text)),
+            BaseTokenizer::Ngram(t) => collect_tokens(t.token_stream(text)),
+        };
+        for filter in &self.filters {
+            let token_stream = VecTokenStream::new(tokens);
+            tokens = match filter {
+                Filter::LowerCase(f) => collect_tokens(f.transform(token_stream)),
+                Filter::StopWord(f) => collect_tokens(f.transform(token_stream)),
+                Filter::Stemmer(f) => collect_tokens(f.transform(token_stream)),
+                Filter::AsciiFolding(f) => collect_tokens(f.transform(token_stream)),
+                Filter::RemoveLong(f) => collect_tokens(f.transform(token_stream)),
+            };
+        }
+        VecTokenStream::new(tokens)
+    }
+}
+
+pub fn register_analyzers(index: &Index, analyzer_file: &str) -> Result<(), String> {
+    let analyzer_content = fs::read_to_string(analyzer_file).map_err(|e| e.to_string())?;
+    let settings: HashMap<String, AnalyzerSetting> =
+        serde_json::from_str(&analyzer_content).map_err(|e| e.to_string())?;
+    for (name, setting) in settings.iter() {
+        index.tokenizers().register(name, Analyzer::new(setting)?);
+    }
+    Ok(())
+}
+
+// Fails if a text field of the schema references a tokenizer that is not registered.
+pub fn check_tokenizers(index: &Index) -> Result<(), String> {
+    let schema = index.schema();
+    for (_field, field_entry) in schema.fields() {
+        if let FieldType::Str(text_field_options) = field_entry.field_type() {
+            if let Some(indexing_options) = text_field_options.get_indexing_options() {
+                let tokenizer_name = indexing_options.tokenizer();
+                if index.tokenizers().get(tokenizer_name).is_none() {
+                    return Err(format!(
+                        "unknown tokenizer: {}: referenced by {}",
+                        tokenizer_name,
+                        field_entry.name()
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ngram_setting(min_gram: usize, max_gram: usize) -> AnalyzerSetting {
+        AnalyzerSetting {
+            tokenizer: TokenizerSetting::Ngram {
+                min_gram,
+                max_gram,
+                prefix_only: false,
+            },
+            filters: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_ngram_parameters() {
+        assert!(Analyzer::new(&ngram_setting(1, 3)).is_ok());
+        assert!(Analyzer::new(&ngram_setting(2, 2)).is_ok());
+        assert!(Analyzer::new(&ngram_setting(0, 3)).is_err());
+        assert!(Analyzer::new(&ngram_setting(3, 2)).is_err());
+    }
+}
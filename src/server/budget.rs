@@ -0,0 +1,227 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tantivy::collector::{Collector, SegmentCollector};
+use tantivy::query::{Query, Scorer};
+use tantivy::{DocId, DocSet, Score, Searcher, SegmentLocalId, SegmentReader};
+
+// Checking the clock on every document would be too costly.
+const DEADLINE_CHECK_INTERVAL: u64 = 256;
+
+// Limits the time and the number of documents a search may spend. Once exhausted, the search
+// stops matching documents, so the results are partial.
+pub struct SearchBudget {
+    deadline: Option<Instant>,
+    terminate_after: u64,
+    timed_out: AtomicBool,
+    terminated_early: AtomicBool,
+}
+
+impl SearchBudget {
+    pub fn new(timeout: Duration, terminate_after: u64) -> SearchBudget {
+        SearchBudget {
+            deadline: if timeout.as_millis() > 0 {
+                Some(Instant::now() + timeout)
+            } else {
+                None
+            },
+            terminate_after,
+            timed_out: AtomicBool::new(false),
+            terminated_early: AtomicBool::new(false),
+        }
+    }
+
+    pub fn timed_out(&self) -> bool {
+        self.timed_out.load(Ordering::Relaxed)
+    }
+
+    pub fn terminated_early(&self) -> bool {
+        self.terminated_early.load(Ordering::Relaxed)
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.timed_out() || self.terminated_early()
+    }
+
+    fn check_deadline(&self) {
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                self.timed_out.store(true, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+// Searches the segments one by one like Searcher::search, but drives the scorers by hand so that
+// no more documents are matched or scored once the budget is exhausted. The remaining segments
+// are skipped.
+pub fn search_with_budget<C: Collector>(
+    searcher: &Searcher,
+    query: &dyn Query,
+    collector: &C,
+    budget: &SearchBudget,
+) -> tantivy::Result<C::Fruit> {
+    let weight = query.weight(searcher, collector.requires_scoring())?;
+    let mut segment_fruits = Vec::new();
+    for (segment_ord, segment_reader) in searcher.segment_readers().iter().enumerate() {
+        let mut segment_collector =
+            collector.for_segment(segment_ord as SegmentLocalId, segment_reader)?;
+        budget.check_deadline();
+        if !budget.is_exhausted() {
+            let mut scorer = weight.scorer(segment_reader)?;
+            let mut matched: u64 = 0;
+            while scorer.advance() {
+                let doc = scorer.doc();
+                if !segment_reader.is_deleted(doc) {
+                    segment_collector.collect(doc, scorer.score());
+                }
+                matched += 1;
+                if matched % DEADLINE_CHECK_INTERVAL == 0 {
+                    budget.check_deadline();
+                }
+                if budget.is_exhausted() {
+                    break;
+                }
+            }
+        }
+        segment_fruits.push(segment_collector.harvest());
+    }
+    collector.merge_fruits(segment_fruits)
+}
+
+// Counts the collected documents against terminate_after. The count belongs to a single search,
+// while the budget is shared by all the searches made for a request.
+pub struct BudgetCollector<'a, C: Collector> {
+    budget: &'a Arc<SearchBudget>,
+    collected: Arc<AtomicU64>,
+    collector: &'a C,
+}
+
+impl<'a, C: Collector> BudgetCollector<'a, C> {
+    pub fn new(budget: &'a Arc<SearchBudget>, collector: &'a C) -> BudgetCollector<'a, C> {
+        BudgetCollector {
+            budget,
+            collected: Arc::new(AtomicU64::new(0)),
+            collector,
+        }
+    }
+}
+
+impl<'a, C: Collector> Collector for BudgetCollector<'a, C> {
+    type Fruit = C::Fruit;
+    type Child = BudgetSegmentCollector<C::Child>;
+
+    fn for_segment(
+        &self,
+        segment_local_id: SegmentLocalId,
+        segment: &SegmentReader,
+    ) -> tantivy::Result<Self::Child> {
+        Ok(BudgetSegmentCollector {
+            budget: self.budget.clone(),
+            collected: self.collected.clone(),
+            collector: self.collector.for_segment(segment_local_id, segment)?,
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        self.collector.requires_scoring()
+    }
+
+    fn merge_fruits(&self, segment_fruits: Vec<Self::Fruit>) -> tantivy::Result<Self::Fruit> {
+        self.collector.merge_fruits(segment_fruits)
+    }
+}
+
+pub struct BudgetSegmentCollector<C: SegmentCollector> {
+    budget: Arc<SearchBudget>,
+    collected: Arc<AtomicU64>,
+    collector: C,
+}
+
+impl<C: SegmentCollector> SegmentCollector for BudgetSegmentCollector<C> {
+    type Fruit = C::Fruit;
+
+    fn collect(&mut self, doc: DocId, score: Score) {
+        if self.budget.is_exhausted() {
+            return;
+        }
+        let collected = self.collected.fetch_add(1, Ordering::Relaxed);
+        if self.budget.terminate_after > 0 && collected >= self.budget.terminate_after {
+            self.budget.terminated_early.store(true, Ordering::Relaxed);
+            return;
+        }
+        self.collector.collect(doc, score);
+    }
+
+    fn harvest(self) -> Self::Fruit {
+        self.collector.harvest()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::collector::Count;
+    use tantivy::doc;
+    use tantivy::query::AllQuery;
+    use tantivy::schema::{Schema, STRING};
+    use tantivy::Index;
+
+    use super::*;
+
+    // Two segments of 3 and 2 documents.
+    fn create_index() -> Index {
+        let mut schema_builder = Schema::builder();
+        let id = schema_builder.add_text_field("id", STRING);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+        for value in &["1", "2", "3"] {
+            index_writer.add_document(doc!(id => *value));
+        }
+        index_writer.commit().unwrap();
+        for value in &["4", "5"] {
+            index_writer.add_document(doc!(id => *value));
+        }
+        index_writer.commit().unwrap();
+        index
+    }
+
+    fn count(index: &Index, budget: &Arc<SearchBudget>) -> usize {
+        let searcher = index.reader().unwrap().searcher();
+        let collector = BudgetCollector::new(budget, &Count);
+        search_with_budget(&searcher, &AllQuery, &collector, budget).unwrap()
+    }
+
+    #[test]
+    fn test_unlimited_budget() {
+        let index = create_index();
+        let budget = Arc::new(SearchBudget::new(Duration::from_millis(0), 0));
+        assert_eq!(count(&index, &budget), 5);
+        assert!(!budget.timed_out());
+        assert!(!budget.terminated_early());
+    }
+
+    #[test]
+    fn test_terminate_after_across_segments() {
+        let index = create_index();
+        let budget = Arc::new(SearchBudget::new(Duration::from_millis(0), 4));
+        assert_eq!(count(&index, &budget), 4);
+        assert!(budget.terminated_early());
+        assert!(!budget.timed_out());
+        // the budget is shared by the searches of a request, so it stays exhausted
+        assert_eq!(count(&index, &budget), 0);
+
+        let budget = Arc::new(SearchBudget::new(Duration::from_millis(0), 5));
+        assert_eq!(count(&index, &budget), 5);
+        assert!(!budget.terminated_early());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let index = create_index();
+        let budget = Arc::new(SearchBudget::new(Duration::from_nanos(1), 0));
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(count(&index, &budget), 0);
+        assert!(budget.timed_out());
+    }
+}
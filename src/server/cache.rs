@@ -0,0 +1,126 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use lru::LruCache;
+use protobuf::Message;
+use tantivy::Searcher;
+
+use crate::proto::indexrpcpb::SearchReq;
+
+// Identifies the state of the index a searcher sees. Every commit and merge replaces segments
+// or their deletes, so the generation changes whenever search results may change.
+pub fn searcher_generation(searcher: &Searcher) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for segment_reader in searcher.segment_readers() {
+        segment_reader.segment_id().hash(&mut hasher);
+        segment_reader.num_deleted_docs().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+// Drops the fields that identify the caller rather than the search, so that identical searches
+// from different clients share an entry.
+pub fn search_cache_key(req: &SearchReq) -> Vec<u8> {
+    let mut req = req.clone();
+    req.clear_client_id();
+    req.clear_seq();
+    req.clear_timeout();
+    req.write_to_bytes().unwrap()
+}
+
+// Date math relative to "now" resolves to a different date on every search, so such searches
+// are not cached. Any "now" in the parameters that take date math skips the cache, even one that
+// is not date math.
+pub fn is_cacheable(req: &SearchReq) -> bool {
+    ![
+        req.get_date_from(),
+        req.get_date_to(),
+        req.get_query_dsl(),
+        req.get_aggregations(),
+        req.get_function_score(),
+    ]
+    .iter()
+    .any(|param| param.contains("now"))
+}
+
+// Caches serialized search results of a single searcher generation. Entries of older
+// generations can never be hit again, so they are all dropped as soon as the generation changes.
+pub struct SearchCache {
+    generation: u64,
+    entries: LruCache<Vec<u8>, String>,
+}
+
+impl SearchCache {
+    pub fn new(size: usize) -> SearchCache {
+        SearchCache {
+            generation: 0,
+            entries: LruCache::new(size),
+        }
+    }
+
+    pub fn get(&mut self, generation: u64, key: &[u8]) -> Option<String> {
+        if generation != self.generation {
+            return None;
+        }
+        self.entries.get(&key.to_vec()).cloned()
+    }
+
+    pub fn put(&mut self, generation: u64, key: Vec<u8>, value: String) {
+        if generation != self.generation {
+            self.entries.clear();
+            self.generation = generation;
+        }
+        self.entries.put(key, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search_req(query: &str) -> SearchReq {
+        let mut req = SearchReq::new();
+        req.set_query(query.to_string());
+        req.set_limit(10);
+        req
+    }
+
+    #[test]
+    fn test_search_cache_key_ignores_the_caller() {
+        let mut req = search_req("rust");
+        let key = search_cache_key(&req);
+        req.set_client_id(1);
+        req.set_seq(2);
+        req.set_timeout(100);
+        assert_eq!(search_cache_key(&req), key);
+        req.set_limit(20);
+        assert_ne!(search_cache_key(&req), key);
+    }
+
+    #[test]
+    fn test_is_cacheable() {
+        let mut req = search_req("rust");
+        assert!(is_cacheable(&req));
+        req.set_date_from(String::from("2020-01-01"));
+        assert!(is_cacheable(&req));
+        req.set_date_from(String::from("now-1d/d"));
+        assert!(!is_cacheable(&req));
+    }
+
+    #[test]
+    fn test_search_cache_generations() {
+        let mut cache = SearchCache::new(2);
+        cache.put(1, b"a".to_vec(), String::from("A"));
+        cache.put(1, b"b".to_vec(), String::from("B"));
+        assert_eq!(cache.get(1, b"a"), Some(String::from("A")));
+        // the least recently used entry is evicted
+        cache.put(1, b"c".to_vec(), String::from("C"));
+        assert_eq!(cache.get(1, b"b"), None);
+        // entries of another generation are never returned, and dropped on the next put
+        assert_eq!(cache.get(2, b"a"), None);
+        cache.put(2, b"c".to_vec(), String::from("C2"));
+        assert_eq!(cache.get(2, b"a"), None);
+        assert_eq!(cache.get(2, b"c"), Some(String::from("C2")));
+        assert_eq!(cache.get(1, b"c"), None);
+    }
+}
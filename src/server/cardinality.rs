@@ -0,0 +1,234 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use tantivy::fastfield::{FacetReader, FastFieldReader};
+use tantivy::schema::{Facet, Field, FieldType, Schema};
+use tantivy::{DocId, SegmentReader};
+
+pub const DEFAULT_PRECISION: u8 = 14;
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    // The default hasher has fixed keys, so the hashes agree across segments.
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Estimates the number of distinct values with 2^precision registers of 6 bits each. The
+// standard error is about 1.04 / sqrt(2^precision), 0.8% with the default precision.
+#[derive(Clone)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    pub fn new(precision: u8) -> Result<HyperLogLog, String> {
+        if precision < 4 || precision > 16 {
+            return Err(format!("precision must be between 4 and 16: {}", precision));
+        }
+        Ok(HyperLogLog {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.precision)) as usize;
+        let rest = hash << self.precision;
+        let rank = (rest.leading_zeros() as u8).min(64 - self.precision) + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        for (register, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    pub fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-i32::from(*register)))
+            .sum();
+        let estimate = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|register| **register == 0).count();
+        // linear counting is more accurate for small cardinalities
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum CardinalitySource {
+    FastField(Field),
+    Facet(Field),
+}
+
+impl CardinalitySource {
+    pub fn new(schema: &Schema, field_name: &str) -> Result<CardinalitySource, String> {
+        let field = schema
+            .get_field(field_name)
+            .ok_or_else(|| format!("unknown field: {}", field_name))?;
+        match schema.get_field_entry(field).field_type() {
+            FieldType::U64(options) | FieldType::I64(options) | FieldType::F64(options)
+                if options.is_fast() =>
+            {
+                Ok(CardinalitySource::FastField(field))
+            }
+            FieldType::Date(options) if options.is_fast() => {
+                Ok(CardinalitySource::FastField(field))
+            }
+            FieldType::HierarchicalFacet => Ok(CardinalitySource::Facet(field)),
+            _ => Err(format!("not a fast field nor a facet field: {}", field_name)),
+        }
+    }
+
+    pub fn reader(self, segment: &SegmentReader) -> tantivy::Result<CardinalityReader> {
+        Ok(match self {
+            CardinalitySource::FastField(field) => {
+                CardinalityReader::FastField(segment.fast_fields().u64_lenient(field))
+            }
+            CardinalitySource::Facet(field) => {
+                CardinalityReader::Facet(segment.facet_reader(field)?, Vec::new(), HashMap::new())
+            }
+        })
+    }
+}
+
+pub enum CardinalityReader {
+    // Fast fields store values of every type as order preserving u64, which are hashed as is.
+    FastField(Option<FastFieldReader<u64>>),
+    // Facet ordinals are local to the segment, so the facets are hashed and cached by ordinal.
+    Facet(FacetReader, Vec<u64>, HashMap<u64, u64>),
+}
+
+impl CardinalityReader {
+    pub fn add(&mut self, doc: DocId, sketch: &mut HyperLogLog) {
+        match self {
+            CardinalityReader::FastField(reader) => {
+                if let Some(reader) = reader {
+                    sketch.add_hash(hash(&reader.get(doc)));
+                }
+            }
+            CardinalityReader::Facet(reader, ords, hashes) => {
+                reader.facet_ords(doc, ords);
+                for ord in ords.iter() {
+                    let facet_hash = *hashes.entry(*ord).or_insert_with(|| {
+                        let mut facet = Facet::root();
+                        reader.facet_from_ord(*ord, &mut facet);
+                        hash(&facet.to_string())
+                    });
+                    sketch.add_hash(facet_hash);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::schema::{FAST, INDEXED, TEXT};
+
+    use super::*;
+
+    // Checks the estimate against four times the standard error, allowing an off by one for
+    // registers shared by small counts.
+    fn assert_estimate(sketch: &HyperLogLog, expected: u64) {
+        let m = f64::from(1u32 << sketch.precision);
+        let tolerance = (4.0 * 1.04 / m.sqrt() * expected as f64).max(1.0);
+        let estimate = sketch.estimate();
+        assert!(
+            (estimate as f64 - expected as f64).abs() <= tolerance,
+            "estimate {} is not within {} of {}",
+            estimate,
+            tolerance,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_precision() {
+        assert!(HyperLogLog::new(3).is_err());
+        assert!(HyperLogLog::new(4).is_ok());
+        assert!(HyperLogLog::new(16).is_ok());
+        assert!(HyperLogLog::new(17).is_err());
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(HyperLogLog::new(DEFAULT_PRECISION).unwrap().estimate(), 0);
+    }
+
+    #[test]
+    fn test_error_bounds() {
+        for &precision in &[4, 10, DEFAULT_PRECISION, 16] {
+            for &count in &[10u64, 1000, 100_000] {
+                let mut sketch = HyperLogLog::new(precision).unwrap();
+                for value in 0..count {
+                    sketch.add_hash(hash(&value));
+                }
+                assert_estimate(&sketch, count);
+            }
+        }
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut sketch = HyperLogLog::new(DEFAULT_PRECISION).unwrap();
+        for value in 0..10_000u64 {
+            sketch.add_hash(hash(&(value % 1000)));
+        }
+        assert_estimate(&sketch, 1000);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut left = HyperLogLog::new(DEFAULT_PRECISION).unwrap();
+        let mut right = HyperLogLog::new(DEFAULT_PRECISION).unwrap();
+        for value in 0..60_000u64 {
+            left.add_hash(hash(&value));
+        }
+        for value in 40_000..100_000u64 {
+            right.add_hash(hash(&value));
+        }
+        left.merge(&right);
+        assert_estimate(&left, 100_000);
+    }
+
+    #[test]
+    fn test_source() {
+        let mut schema_builder = Schema::builder();
+        schema_builder.add_u64_field("count", FAST);
+        schema_builder.add_i64_field("rank", INDEXED);
+        schema_builder.add_text_field("title", TEXT);
+        schema_builder.add_facet_field("category");
+        let schema = schema_builder.build();
+
+        match CardinalitySource::new(&schema, "count") {
+            Ok(CardinalitySource::FastField(_)) => {}
+            _ => panic!("count is a fast field"),
+        }
+        match CardinalitySource::new(&schema, "category") {
+            Ok(CardinalitySource::Facet(_)) => {}
+            _ => panic!("category is a facet field"),
+        }
+        assert!(CardinalitySource::new(&schema, "rank").is_err());
+        assert!(CardinalitySource::new(&schema, "title").is_err());
+        assert!(CardinalitySource::new(&schema, "unknown").is_err());
+    }
+}
@@ -0,0 +1,298 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use tantivy::collector::{Collector, SegmentCollector};
+use tantivy::fastfield::{FacetReader, FastFieldReader};
+use tantivy::schema::{Facet, Field, FieldType, IndexRecordOption, Schema};
+use tantivy::{DocAddress, DocId, DocSet, Score, SegmentLocalId, SegmentReader, Term};
+
+use crate::server::ranking::{DocRank, DocRanker, SegmentDocRanker};
+
+#[derive(Clone, Copy)]
+enum GroupSource {
+    FastField(Field),
+    Facet(Field),
+}
+
+impl GroupSource {
+    // Numeric fields must be indexed, so that documents without a value can be told apart from
+    // documents with the default value.
+    fn new(schema: &Schema, field_name: &str) -> Result<GroupSource, String> {
+        let field = schema
+            .get_field(field_name)
+            .ok_or_else(|| format!("unknown field: {}", field_name))?;
+        match schema.get_field_entry(field).field_type() {
+            FieldType::U64(options)
+            | FieldType::I64(options)
+            | FieldType::F64(options)
+            | FieldType::Date(options)
+                if options.is_fast() =>
+            {
+                if !options.is_indexed() {
+                    return Err(format!("not an indexed fast field: {}", field_name));
+                }
+                Ok(GroupSource::FastField(field))
+            }
+            FieldType::HierarchicalFacet => Ok(GroupSource::Facet(field)),
+            _ => Err(format!("not a fast field nor a facet field: {}", field_name)),
+        }
+    }
+
+    fn reader(self, segment_reader: &SegmentReader) -> tantivy::Result<GroupKeyReader> {
+        Ok(match self {
+            GroupSource::FastField(field) => {
+                // documents without a value read as 0, like those with the value stored as 0
+                let term = Term::from_field_u64(field, 0);
+                let mut zero_docs = Vec::new();
+                if let Some(mut postings) = segment_reader
+                    .inverted_index(field)
+                    .read_postings(&term, IndexRecordOption::Basic)
+                {
+                    while postings.advance() {
+                        zero_docs.push(postings.doc());
+                    }
+                }
+                GroupKeyReader::FastField(
+                    segment_reader.fast_fields().u64_lenient(field),
+                    zero_docs,
+                )
+            }
+            GroupSource::Facet(field) => {
+                GroupKeyReader::Facet(segment_reader.facet_reader(field)?, Vec::new())
+            }
+        })
+    }
+}
+
+// Fast fields store values of every type as order preserving u64, which are the same across
+// segments. Facets are read as ordinals, which are local to the segment.
+enum GroupKeyReader {
+    FastField(Option<FastFieldReader<u64>>, Vec<DocId>),
+    Facet(FacetReader, Vec<u64>),
+}
+
+impl GroupKeyReader {
+    // Returns None for a document without a value.
+    fn key(&mut self, doc: DocId) -> Option<u64> {
+        match self {
+            GroupKeyReader::FastField(reader, zero_docs) => {
+                let value = reader.as_ref()?.get(doc);
+                if value == 0 && zero_docs.binary_search(&doc).is_err() {
+                    return None;
+                }
+                Some(value)
+            }
+            GroupKeyReader::Facet(reader, ords) => {
+                reader.facet_ords(doc, ords);
+                ords.first().cloned()
+            }
+        }
+    }
+
+    // Facet ordinals are resolved once per group, for the groups of the segment only.
+    fn group_key(&self, key: u64) -> GroupKey {
+        match self {
+            GroupKeyReader::FastField(..) => GroupKey::Value(key),
+            GroupKeyReader::Facet(reader, _) => {
+                let mut facet = Facet::root();
+                reader.facet_from_ord(key, &mut facet);
+                GroupKey::Facet(facet.to_string())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GroupKey {
+    Value(u64),
+    Facet(String),
+}
+
+#[derive(Clone)]
+pub struct CollapsedGroup {
+    pub rank: DocRank,
+    pub doc_address: DocAddress,
+    pub size: u64,
+}
+
+fn compare_rank(a: &DocRank, b: &DocRank) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+// Keeps the best ranked document for each distinct value of the field, along with the number of
+// documents sharing that value. Documents without a value are skipped.
+pub struct CollapseCollector {
+    source: GroupSource,
+    ranker: DocRanker,
+}
+
+impl CollapseCollector {
+    pub fn new(
+        schema: &Schema,
+        field_name: &str,
+        ranker: DocRanker,
+    ) -> Result<CollapseCollector, String> {
+        Ok(CollapseCollector {
+            source: GroupSource::new(schema, field_name)?,
+            ranker,
+        })
+    }
+
+    // Sorts the groups by the rank of their best documents.
+    pub fn sorted_groups(groups: HashMap<GroupKey, CollapsedGroup>) -> Vec<CollapsedGroup> {
+        let mut groups: Vec<CollapsedGroup> = groups.into_iter().map(|(_key, group)| group).collect();
+        groups.sort_by(|a, b| compare_rank(&b.rank, &a.rank));
+        groups
+    }
+}
+
+impl Collector for CollapseCollector {
+    type Fruit = HashMap<GroupKey, CollapsedGroup>;
+    type Child = CollapseSegmentCollector;
+
+    fn for_segment(
+        &self,
+        segment_local_id: SegmentLocalId,
+        segment: &SegmentReader,
+    ) -> tantivy::Result<Self::Child> {
+        Ok(CollapseSegmentCollector {
+            segment_local_id,
+            key_reader: self.source.reader(segment)?,
+            ranker: self.ranker.segment_ranker(segment),
+            groups: HashMap::new(),
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        true
+    }
+
+    fn merge_fruits(&self, segment_fruits: Vec<Self::Fruit>) -> tantivy::Result<Self::Fruit> {
+        let mut merged: Self::Fruit = HashMap::new();
+        for groups in segment_fruits {
+            for (key, group) in groups {
+                match merged.get_mut(&key) {
+                    Some(merged_group) => {
+                        merged_group.size += group.size;
+                        if compare_rank(&group.rank, &merged_group.rank) == Ordering::Greater {
+                            merged_group.rank = group.rank;
+                            merged_group.doc_address = group.doc_address;
+                        }
+                    }
+                    None => {
+                        merged.insert(key, group);
+                    }
+                }
+            }
+        }
+        Ok(merged)
+    }
+}
+
+pub struct CollapseSegmentCollector {
+    segment_local_id: SegmentLocalId,
+    key_reader: GroupKeyReader,
+    ranker: SegmentDocRanker,
+    groups: HashMap<u64, CollapsedGroup>,
+}
+
+impl SegmentCollector for CollapseSegmentCollector {
+    type Fruit = HashMap<GroupKey, CollapsedGroup>;
+
+    fn collect(&mut self, doc: DocId, score: Score) {
+        let key = match self.key_reader.key(doc) {
+            Some(key) => key,
+            None => return,
+        };
+        let rank = self.ranker.rank(doc, score);
+        let doc_address = DocAddress(self.segment_local_id, doc);
+        let group = self.groups.entry(key).or_insert_with(|| CollapsedGroup {
+            rank: rank.clone(),
+            doc_address,
+            size: 0,
+        });
+        group.size += 1;
+        if compare_rank(&rank, &group.rank) == Ordering::Greater {
+            group.rank = rank;
+            group.doc_address = doc_address;
+        }
+    }
+
+    fn harvest(self) -> Self::Fruit {
+        let key_reader = self.key_reader;
+        self.groups
+            .into_iter()
+            .map(|(key, group)| (key_reader.group_key(key), group))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::doc;
+    use tantivy::query::AllQuery;
+    use tantivy::schema::{Schema, FAST, INDEXED};
+    use tantivy::Index;
+
+    use super::*;
+
+    fn group_sizes(index: &Index, field_name: &str) -> HashMap<GroupKey, u64> {
+        let searcher = index.reader().unwrap().searcher();
+        assert_eq!(searcher.segment_readers().len(), 2);
+        let collector =
+            CollapseCollector::new(&index.schema(), field_name, DocRanker::default()).unwrap();
+        searcher
+            .search(&AllQuery, &collector)
+            .unwrap()
+            .into_iter()
+            .map(|(key, group)| (key, group.size))
+            .collect()
+    }
+
+    #[test]
+    fn test_collapse_by_fast_field_across_segments() {
+        let mut schema_builder = Schema::builder();
+        let group = schema_builder.add_u64_field("group", INDEXED | FAST);
+        let category = schema_builder.add_facet_field("category");
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+        index_writer.add_document(doc!(group => 1u64));
+        index_writer.add_document(doc!(group => 0u64));
+        index_writer.add_document(doc!(category => Facet::from("/a")));
+        index_writer.commit().unwrap();
+        index_writer.add_document(doc!(group => 1u64));
+        index_writer.add_document(doc!(category => Facet::from("/b")));
+        index_writer.commit().unwrap();
+
+        // documents without a value are not collapsed into the group of 0
+        let mut expected = HashMap::new();
+        expected.insert(GroupKey::Value(1), 2);
+        expected.insert(GroupKey::Value(0), 1);
+        assert_eq!(group_sizes(&index, "group"), expected);
+    }
+
+    #[test]
+    fn test_collapse_by_facet_across_segments() {
+        let mut schema_builder = Schema::builder();
+        let group = schema_builder.add_u64_field("group", INDEXED | FAST);
+        let category = schema_builder.add_facet_field("category");
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema);
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+        index_writer.add_document(doc!(category => Facet::from("/a")));
+        index_writer.add_document(doc!(category => Facet::from("/b")));
+        index_writer.commit().unwrap();
+        // the ordinal of "/a" differs in this segment
+        index_writer.add_document(doc!(category => Facet::from("/0")));
+        index_writer.add_document(doc!(category => Facet::from("/a")));
+        index_writer.add_document(doc!(group => 1u64));
+        index_writer.commit().unwrap();
+
+        let mut expected = HashMap::new();
+        expected.insert(GroupKey::Facet(String::from("/a")), 2);
+        expected.insert(GroupKey::Facet(String::from("/b")), 1);
+        expected.insert(GroupKey::Facet(String::from("/0")), 1);
+        assert_eq!(group_sizes(&index, "category"), expected);
+    }
+}
@@ -0,0 +1,371 @@
+use std::convert::TryFrom;
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc,
+};
+
+// Parses a fixed offset such as "+09:00", "-0500" or "Z". Time zone names such as "Asia/Tokyo"
+// are not supported, as their offsets change with daylight saving time.
+pub fn parse_time_zone(text: &str) -> Result<FixedOffset, String> {
+    if text.is_empty() || text == "Z" || text.eq_ignore_ascii_case("UTC") {
+        return Ok(FixedOffset::east(0));
+    }
+    if !text.is_ascii() {
+        return Err(format!("invalid time zone: {}", text));
+    }
+    let (sign, rest) = match text.chars().next() {
+        Some('+') => (1, &text[1..]),
+        Some('-') => (-1, &text[1..]),
+        _ => {
+            return Err(format!(
+                "unsupported time zone: {}: only offsets such as +09:00 are supported",
+                text
+            ))
+        }
+    };
+    let (hours, minutes) = match rest.find(':') {
+        Some(pos) => (&rest[..pos], &rest[pos + 1..]),
+        None if rest.len() == 4 => (&rest[..2], &rest[2..]),
+        None => (rest, "0"),
+    };
+    let hours = hours.parse::<i32>().map_err(|e| e.to_string())?;
+    let minutes = minutes.parse::<i32>().map_err(|e| e.to_string())?;
+    if hours > 23 || minutes > 59 {
+        return Err(format!("invalid time zone: {}", text));
+    }
+    Ok(FixedOffset::east(sign * (hours * 3600 + minutes * 60)))
+}
+
+fn from_local(tz: &FixedOffset, naive: &NaiveDateTime) -> Result<DateTime<FixedOffset>, String> {
+    tz.from_local_datetime(naive)
+        .single()
+        .ok_or_else(|| format!("invalid local date: {}", naive))
+}
+
+// Dates without an offset are taken as local dates in the given time zone.
+fn parse_date(text: &str, tz: &FixedOffset) -> Result<DateTime<FixedOffset>, String> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(text) {
+        return Ok(date);
+    }
+    if let Ok(naive) = NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S") {
+        return from_local(tz, &naive);
+    }
+    if let Ok(naive) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        return from_local(tz, &naive.and_hms(0, 0, 0));
+    }
+    Err(format!("invalid date: {}", text))
+}
+
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let (next_year, next_month) = if month == 12 {
+        (year.checked_add(1)?, 1)
+    } else {
+        (year, month + 1)
+    };
+    Some(
+        NaiveDate::from_ymd_opt(next_year, next_month, 1)?
+            .pred()
+            .day(),
+    )
+}
+
+fn add_months(date: DateTime<FixedOffset>, months: i64) -> Result<DateTime<FixedOffset>, String> {
+    let out_of_range = || format!("date out of range: {} + {} months", date, months);
+    let total = (i64::from(date.year()) * 12 + i64::from(date.month0()))
+        .checked_add(months)
+        .ok_or_else(out_of_range)?;
+    let year = i32::try_from(total.div_euclid(12)).map_err(|_| out_of_range())?;
+    let month = total.rem_euclid(12) as u32 + 1;
+    let day = date
+        .day()
+        .min(days_in_month(year, month).ok_or_else(out_of_range)?);
+    let naive = NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(out_of_range)?
+        .and_time(date.time());
+    from_local(date.offset(), &naive)
+}
+
+fn add(
+    date: DateTime<FixedOffset>,
+    amount: i64,
+    unit: char,
+) -> Result<DateTime<FixedOffset>, String> {
+    let millis_per_unit: i64 = match unit {
+        'y' => {
+            let months = amount
+                .checked_mul(12)
+                .ok_or_else(|| format!("date out of range: {} + {} years", date, amount))?;
+            return add_months(date, months);
+        }
+        'M' => return add_months(date, amount),
+        'w' => 604_800_000,
+        'd' => 86_400_000,
+        'h' | 'H' => 3_600_000,
+        'm' => 60_000,
+        's' => 1_000,
+        _ => return Err(format!("unknown date math unit: {}", unit)),
+    };
+    amount
+        .checked_mul(millis_per_unit)
+        .and_then(|millis| date.checked_add_signed(Duration::milliseconds(millis)))
+        .ok_or_else(|| format!("date out of range: {} + {}{}", date, amount, unit))
+}
+
+fn round_down(date: DateTime<FixedOffset>, unit: char) -> Result<DateTime<FixedOffset>, String> {
+    let local = date.naive_local();
+    let day = local.date();
+    let naive = match unit {
+        'y' => NaiveDate::from_ymd(day.year(), 1, 1).and_hms(0, 0, 0),
+        'M' => NaiveDate::from_ymd(day.year(), day.month(), 1).and_hms(0, 0, 0),
+        'w' => {
+            let monday = day - Duration::days(i64::from(day.weekday().num_days_from_monday()));
+            monday.and_hms(0, 0, 0)
+        }
+        'd' => day.and_hms(0, 0, 0),
+        'h' | 'H' => day.and_hms(local.hour(), 0, 0),
+        'm' => day.and_hms(local.hour(), local.minute(), 0),
+        's' => day.and_hms(local.hour(), local.minute(), local.second()),
+        _ => return Err(format!("unknown date math unit: {}", unit)),
+    };
+    from_local(date.offset(), &naive)
+}
+
+// Rounds a timestamp in seconds down to the start of the unit in the given time zone.
+pub fn round_timestamp(timestamp: i64, unit: char, tz: &FixedOffset) -> Result<i64, String> {
+    let date = Utc
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .ok_or_else(|| format!("timestamp out of range: {}", timestamp))?
+        .with_timezone(tz);
+    Ok(round_down(date, unit)?.timestamp())
+}
+
+// Evaluates a date math expression such as "now-7d/d" or "2020-01-01||+1M". The anchor is "now"
+// or a date followed by "||", and is followed by any number of "+<n><unit>", "-<n><unit>" and
+// "/<unit>" operations. Rounding is done in the given time zone.
+pub fn parse_date_math(
+    expr: &str,
+    now: DateTime<Utc>,
+    tz: &FixedOffset,
+) -> Result<DateTime<Utc>, String> {
+    let (mut date, mut math) = if expr.starts_with("now") {
+        (now.with_timezone(tz), &expr[3..])
+    } else {
+        match expr.find("||") {
+            Some(pos) => (parse_date(&expr[..pos], tz)?, &expr[pos + 2..]),
+            None => (parse_date(expr, tz)?, ""),
+        }
+    };
+    date = date.with_timezone(tz);
+
+    while let Some(op) = math.chars().next() {
+        math = &math[op.len_utf8()..];
+        match op {
+            '+' | '-' => {
+                let digits = math
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or_else(|| math.len());
+                let amount = if digits == 0 {
+                    1
+                } else {
+                    math[..digits].parse::<i64>().map_err(|e| e.to_string())?
+                };
+                math = &math[digits..];
+                let unit = math
+                    .chars()
+                    .next()
+                    .ok_or_else(|| format!("missing date math unit: {}", expr))?;
+                math = &math[unit.len_utf8()..];
+                date = add(date, if op == '-' { -amount } else { amount }, unit)?;
+            }
+            '/' => {
+                let unit = math
+                    .chars()
+                    .next()
+                    .ok_or_else(|| format!("missing date math unit: {}", expr))?;
+                math = &math[unit.len_utf8()..];
+                date = round_down(date, unit)?;
+            }
+            _ => return Err(format!("invalid date math: {}", expr)),
+        }
+    }
+
+    Ok(date.with_timezone(&Utc))
+}
+
+// Parses a duration such as "7d" or "12h" into seconds.
+pub fn parse_duration(text: &str) -> Result<i64, String> {
+    let text = text.trim();
+    let (value, unit) = match text.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(pos) => text.split_at(pos),
+        None => (text, "s"),
+    };
+    let value = value.parse::<i64>().map_err(|e| e.to_string())?;
+    let seconds_per_unit: i64 = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" | "H" => 3600,
+        "d" => 86400,
+        "w" => 604_800,
+        _ => return Err(format!("unknown duration unit: {}", unit)),
+    };
+    value
+        .checked_mul(seconds_per_unit)
+        .ok_or_else(|| format!("duration out of range: {}", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        // a Sunday
+        Utc.ymd(2020, 3, 15).and_hms(10, 30, 0)
+    }
+
+    #[test]
+    fn test_parse_time_zone() {
+        assert_eq!(parse_time_zone("").unwrap(), FixedOffset::east(0));
+        assert_eq!(parse_time_zone("Z").unwrap(), FixedOffset::east(0));
+        assert_eq!(
+            parse_time_zone("+09:00").unwrap(),
+            FixedOffset::east(9 * 3600)
+        );
+        assert_eq!(
+            parse_time_zone("-0530").unwrap(),
+            FixedOffset::east(-(5 * 3600 + 1800))
+        );
+        assert_eq!(parse_time_zone("+3").unwrap(), FixedOffset::east(3 * 3600));
+        assert!(parse_time_zone("Asia/Tokyo").is_err());
+        assert!(parse_time_zone("+24:00").is_err());
+        assert!(parse_time_zone("+09:60").is_err());
+    }
+
+    #[test]
+    fn test_parse_date_math() {
+        let utc = FixedOffset::east(0);
+        assert_eq!(parse_date_math("now", now(), &utc).unwrap(), now());
+        assert_eq!(
+            parse_date_math("now-7d", now(), &utc).unwrap(),
+            Utc.ymd(2020, 3, 8).and_hms(10, 30, 0)
+        );
+        assert_eq!(
+            parse_date_math("now+1h-30m", now(), &utc).unwrap(),
+            Utc.ymd(2020, 3, 15).and_hms(11, 0, 0)
+        );
+        assert_eq!(
+            parse_date_math("2020-01-01T12:00:00Z", now(), &utc).unwrap(),
+            Utc.ymd(2020, 1, 1).and_hms(12, 0, 0)
+        );
+        // the last day of the month is kept when the next month is shorter
+        assert_eq!(
+            parse_date_math("2020-01-31||+1M", now(), &utc).unwrap(),
+            Utc.ymd(2020, 2, 29).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            parse_date_math("2020-02-29||+1y", now(), &utc).unwrap(),
+            Utc.ymd(2021, 2, 28).and_hms(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_date_math_with_time_zone() {
+        let tz = FixedOffset::east(9 * 3600);
+        // dates without an offset are local dates
+        assert_eq!(
+            parse_date_math("2020-01-01", now(), &tz).unwrap(),
+            Utc.ymd(2019, 12, 31).and_hms(15, 0, 0)
+        );
+        // dates with an offset keep it
+        assert_eq!(
+            parse_date_math("2020-01-01T00:00:00Z", now(), &tz).unwrap(),
+            Utc.ymd(2020, 1, 1).and_hms(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_date_math_rounding() {
+        let utc = FixedOffset::east(0);
+        assert_eq!(
+            parse_date_math("now-7d/d", now(), &utc).unwrap(),
+            Utc.ymd(2020, 3, 8).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            parse_date_math("now/w", now(), &utc).unwrap(),
+            Utc.ymd(2020, 3, 9).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            parse_date_math("now/M", now(), &utc).unwrap(),
+            Utc.ymd(2020, 3, 1).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            parse_date_math("now/y", now(), &utc).unwrap(),
+            Utc.ymd(2020, 1, 1).and_hms(0, 0, 0)
+        );
+        assert_eq!(
+            parse_date_math("now/h", now(), &utc).unwrap(),
+            Utc.ymd(2020, 3, 15).and_hms(10, 0, 0)
+        );
+        // rounding is done in the time zone, where it is already the evening
+        let tz = FixedOffset::east(9 * 3600);
+        assert_eq!(
+            parse_date_math("now/d", now(), &tz).unwrap(),
+            Utc.ymd(2020, 3, 14).and_hms(15, 0, 0)
+        );
+        let tz = FixedOffset::east(-12 * 3600);
+        assert_eq!(
+            parse_date_math("now/d", now(), &tz).unwrap(),
+            Utc.ymd(2020, 3, 14).and_hms(12, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_parse_date_math_errors() {
+        let utc = FixedOffset::east(0);
+        assert!(parse_date_math("yesterday", now(), &utc).is_err());
+        assert!(parse_date_math("now+1x", now(), &utc).is_err());
+        assert!(parse_date_math("now+1", now(), &utc).is_err());
+        assert!(parse_date_math("now/", now(), &utc).is_err());
+        assert!(parse_date_math("now*2d", now(), &utc).is_err());
+        assert!(parse_date_math("2020-13-01||+1d", now(), &utc).is_err());
+    }
+
+    #[test]
+    fn test_parse_date_math_overflow() {
+        let utc = FixedOffset::east(0);
+        assert!(parse_date_math("now+9223372036854775807d", now(), &utc).is_err());
+        assert!(parse_date_math("now+9223372036854775807y", now(), &utc).is_err());
+        assert!(parse_date_math("now+9223372036854775807M", now(), &utc).is_err());
+        assert!(parse_date_math("now-1000000000y", now(), &utc).is_err());
+        assert!(parse_date_math("now+99999999999999999999s", now(), &utc).is_err());
+    }
+
+    #[test]
+    fn test_round_timestamp() {
+        let timestamp = now().timestamp();
+        let utc = FixedOffset::east(0);
+        assert_eq!(
+            round_timestamp(timestamp, 'd', &utc).unwrap(),
+            Utc.ymd(2020, 3, 15).and_hms(0, 0, 0).timestamp()
+        );
+        let tz = FixedOffset::east(-5 * 3600);
+        assert_eq!(
+            round_timestamp(timestamp, 'd', &tz).unwrap(),
+            Utc.ymd(2020, 3, 15).and_hms(5, 0, 0).timestamp()
+        );
+        assert!(round_timestamp(timestamp, 'x', &utc).is_err());
+        assert!(round_timestamp(std::i64::MAX, 'd', &utc).is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30").unwrap(), 30);
+        assert_eq!(parse_duration("5m").unwrap(), 300);
+        assert_eq!(parse_duration("12h").unwrap(), 43_200);
+        assert_eq!(parse_duration("7d").unwrap(), 604_800);
+        assert_eq!(parse_duration("1w").unwrap(), 604_800);
+        assert!(parse_duration("1y").is_err());
+        assert!(parse_duration("d").is_err());
+        assert!(parse_duration("9223372036854775807w").is_err());
+    }
+}
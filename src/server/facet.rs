@@ -0,0 +1,201 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::Value;
+use tantivy::schema::{FieldType, Schema};
+
+use crate::server::aggregation::{AggregationCollector, AggregationSetting};
+
+#[derive(Clone, Copy)]
+pub enum FacetOrder {
+    CountDesc,
+    CountAsc,
+    ValueAsc,
+    ValueDesc,
+}
+
+impl FacetOrder {
+    pub fn parse(order: &str) -> Result<FacetOrder, String> {
+        let mut parts = order.split_whitespace();
+        let key = parts.next().unwrap_or("count").to_lowercase();
+        let direction = parts.next().map(|direction| direction.to_lowercase());
+        match (key.as_str(), direction.as_ref().map(|d| d.as_str())) {
+            ("count", None) | ("count", Some("desc")) => Ok(FacetOrder::CountDesc),
+            ("count", Some("asc")) => Ok(FacetOrder::CountAsc),
+            ("value", None) | ("value", Some("asc")) => Ok(FacetOrder::ValueAsc),
+            ("value", Some("desc")) => Ok(FacetOrder::ValueDesc),
+            _ => Err(format!("unknown facet sort order: {}", order)),
+        }
+    }
+
+    // Compares (facet, count) pairs.
+    pub fn compare(self, a: (&str, u64), b: (&str, u64)) -> Ordering {
+        match self {
+            FacetOrder::CountDesc => b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)),
+            FacetOrder::CountAsc => a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)),
+            FacetOrder::ValueAsc => a.0.cmp(&b.0),
+            FacetOrder::ValueDesc => b.0.cmp(&a.0),
+        }
+    }
+}
+
+// Counts the facets of a hierarchical facet field under the given prefixes, sorted and limited
+// to the top N. The counts are derived from a facet aggregation per prefix, so that they agree
+// with the `facet` aggregation.
+pub struct FacetSetting {
+    pub field_name: String,
+    prefixes: Vec<String>,
+    limit: Option<usize>,
+    order: FacetOrder,
+}
+
+impl FacetSetting {
+    fn new(schema: &Schema, field_name: &str) -> Result<FacetSetting, String> {
+        let field = schema
+            .get_field(field_name)
+            .ok_or_else(|| format!("unknown field: {}", field_name))?;
+        match schema.get_field_entry(field).field_type() {
+            FieldType::HierarchicalFacet => Ok(FacetSetting {
+                field_name: field_name.to_string(),
+                prefixes: Vec::new(),
+                limit: None,
+                order: FacetOrder::CountDesc,
+            }),
+            _ => Err(format!("not a hierarchical facet field: {}", field_name)),
+        }
+    }
+
+    // A document under nested prefixes would be counted twice, so they are rejected here.
+    fn add_prefix(&mut self, prefix: &str) -> Result<(), String> {
+        if !prefix.starts_with('/') {
+            return Err(format!("invalid facet prefix: {}", prefix));
+        }
+        let prefix = match prefix.trim_end_matches('/') {
+            "" => "/",
+            prefix => prefix,
+        };
+        let is_ancestor =
+            |a: &str, b: &str| (a == "/" && b != "/") || b.starts_with(&format!("{}/", a));
+        for other in &self.prefixes {
+            if other == prefix {
+                return Ok(());
+            }
+            if is_ancestor(other, prefix) || is_ancestor(prefix, other) {
+                return Err(format!("nested facet prefixes: {} and {}", other, prefix));
+            }
+        }
+        self.prefixes.push(prefix.to_string());
+        Ok(())
+    }
+
+    fn prefixes(&self) -> Vec<String> {
+        if self.prefixes.is_empty() {
+            vec![String::from("/")]
+        } else {
+            self.prefixes.clone()
+        }
+    }
+
+    // Counts the children of the root if no prefix is given. The aggregations are named by their
+    // prefixes.
+    pub fn collector(&self, schema: &Schema) -> Result<AggregationCollector, String> {
+        let mut settings = BTreeMap::new();
+        for prefix in self.prefixes() {
+            let setting = AggregationSetting::Facet {
+                field: self.field_name.clone(),
+                prefix: prefix.clone(),
+                limit: None,
+                sort: String::new(),
+                aggs: BTreeMap::new(),
+            };
+            settings.insert(prefix, setting);
+        }
+        AggregationCollector::new(schema, &settings, "")
+    }
+
+    pub fn counts(&self, results: &HashMap<String, Value>) -> Vec<(String, u64)> {
+        let mut counts: Vec<(String, u64)> = Vec::new();
+        for prefix in self.prefixes() {
+            let buckets = results
+                .get(&prefix)
+                .and_then(|result| result["buckets"].as_array());
+            for bucket in buckets.into_iter().flatten() {
+                if let (Some(facet), Some(count)) =
+                    (bucket["key"].as_str(), bucket["doc_count"].as_u64())
+                {
+                    if !counts.iter().any(|(f, _)| f == facet) {
+                        counts.push((facet.to_string(), count));
+                    }
+                }
+            }
+        }
+        counts.sort_by(|a, b| self.order.compare((&a.0, a.1), (&b.0, b.1)));
+        if let Some(limit) = self.limit {
+            counts.truncate(limit);
+        }
+        counts
+    }
+}
+
+fn split_field_name(spec: &str) -> (&str, &str) {
+    let mut parts = spec.splitn(2, ':');
+    let field_name = parts.next().unwrap_or("");
+    (field_name, parts.next().unwrap_or(""))
+}
+
+fn setting_for<'a>(
+    settings: &'a mut Vec<FacetSetting>,
+    schema: &Schema,
+    field_name: &str,
+) -> Result<&'a mut FacetSetting, String> {
+    match settings.iter().position(|s| s.field_name == field_name) {
+        Some(pos) => Ok(&mut settings[pos]),
+        None => {
+            settings.push(FacetSetting::new(schema, field_name)?);
+            Ok(settings.last_mut().unwrap())
+        }
+    }
+}
+
+// Builds the facet settings of a search. Facets are given as "field:prefix", limits as
+// "field:N" and sort orders as "field:count desc" or "field:value asc". A field may be repeated
+// to count several prefixes. The single facet field and its prefixes are also accepted.
+pub fn parse_facet_settings(
+    schema: &Schema,
+    facet_field: &str,
+    facet_prefixes: &[String],
+    facets: &[String],
+    facet_limits: &[String],
+    facet_sorts: &[String],
+) -> Result<Vec<FacetSetting>, String> {
+    let mut settings: Vec<FacetSetting> = Vec::new();
+    if !facet_field.is_empty() {
+        let setting = setting_for(&mut settings, schema, facet_field)?;
+        for prefix in facet_prefixes {
+            setting.add_prefix(prefix)?;
+        }
+    }
+    for facet in facets {
+        let (field_name, prefix) = split_field_name(facet);
+        let setting = setting_for(&mut settings, schema, field_name)?;
+        if !prefix.is_empty() {
+            setting.add_prefix(prefix)?;
+        }
+    }
+    for facet_limit in facet_limits {
+        let (field_name, limit) = split_field_name(facet_limit);
+        let setting = setting_for(&mut settings, schema, field_name)?;
+        setting.limit = Some(
+            limit
+                .trim()
+                .parse::<usize>()
+                .map_err(|e| format!("invalid facet limit: {}: {}", facet_limit, e))?,
+        );
+    }
+    for facet_sort in facet_sorts {
+        let (field_name, order) = split_field_name(facet_sort);
+        let setting = setting_for(&mut settings, schema, field_name)?;
+        setting.order = FacetOrder::parse(order)?;
+    }
+    Ok(settings)
+}
@@ -0,0 +1,540 @@
+use chrono::{FixedOffset, Utc};
+use serde::Deserialize;
+use tantivy::fastfield::FastFieldReader;
+use tantivy::schema::{Field, FieldType, Schema};
+use tantivy::{DateTime, DocId, Score, SegmentReader};
+
+use crate::server::date_math::{parse_date_math, parse_duration, parse_time_zone};
+use crate::server::geo::{self, decode_point, is_geo_point_field, parse_distance, parse_point};
+
+fn default_one() -> f64 {
+    1.0
+}
+
+fn default_decay() -> f64 {
+    0.5
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Modifier {
+    None,
+    Log,
+    Log1p,
+    Log2p,
+    Ln,
+    Ln1p,
+    Ln2p,
+    Square,
+    Sqrt,
+    Reciprocal,
+}
+
+impl Default for Modifier {
+    fn default() -> Modifier {
+        Modifier::None
+    }
+}
+
+impl Modifier {
+    // Inputs are clamped so that the logarithms, the square root and the reciprocal never return
+    // an infinity or NaN, which would break the ordering of the hits.
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Modifier::None => value,
+            Modifier::Log => value.max(1.0).log10(),
+            Modifier::Log1p => value.max(0.0).ln_1p() / std::f64::consts::LN_10,
+            Modifier::Log2p => (value.max(0.0) + 2.0).log10(),
+            Modifier::Ln => value.max(1.0).ln(),
+            Modifier::Ln1p => value.max(0.0).ln_1p(),
+            Modifier::Ln2p => (value.max(0.0) + 2.0).ln(),
+            Modifier::Square => value * value,
+            Modifier::Sqrt => value.max(0.0).sqrt(),
+            Modifier::Reciprocal => 1.0 / value.max(1.0),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DecayCurve {
+    Gauss,
+    Exp,
+    Linear,
+}
+
+impl Default for DecayCurve {
+    fn default() -> DecayCurve {
+        DecayCurve::Gauss
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScoreFunctionSetting {
+    FieldValueFactor {
+        field: String,
+        #[serde(default = "default_one")]
+        factor: f64,
+        #[serde(default)]
+        modifier: Modifier,
+        #[serde(default = "default_one")]
+        weight: f64,
+    },
+    Decay {
+        field: String,
+        #[serde(default)]
+        curve: DecayCurve,
+        origin: String,
+        scale: String,
+        #[serde(default)]
+        offset: String,
+        #[serde(default = "default_decay")]
+        decay: f64,
+        #[serde(default = "default_one")]
+        weight: f64,
+    },
+    Weight {
+        weight: f64,
+    },
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreMode {
+    Multiply,
+    Sum,
+    Avg,
+    Max,
+    Min,
+}
+
+impl Default for ScoreMode {
+    fn default() -> ScoreMode {
+        ScoreMode::Multiply
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum BoostMode {
+    Multiply,
+    Sum,
+    Replace,
+}
+
+impl Default for BoostMode {
+    fn default() -> BoostMode {
+        BoostMode::Multiply
+    }
+}
+
+#[derive(Deserialize)]
+pub struct FunctionScoreSetting {
+    pub functions: Vec<ScoreFunctionSetting>,
+    #[serde(default)]
+    pub score_mode: ScoreMode,
+    #[serde(default)]
+    pub boost_mode: BoostMode,
+}
+
+#[derive(Clone, Copy)]
+enum Source {
+    U64(Field),
+    I64(Field),
+    F64(Field),
+    Date(Field),
+    Point(Field, (f64, f64)),
+}
+
+impl Source {
+    fn new(schema: &Schema, field_name: &str) -> Result<Source, String> {
+        let field = schema
+            .get_field(field_name)
+            .ok_or_else(|| format!("unknown field: {}", field_name))?;
+        let field_entry = schema.get_field_entry(field);
+        if is_geo_point_field(field_entry) {
+            let point_field = schema
+                .get_field(&geo::point_field_name(field_name))
+                .ok_or_else(|| format!("no point field for: {}", field_name))?;
+            return Ok(Source::Point(point_field, (0.0, 0.0)));
+        }
+        match field_entry.field_type() {
+            FieldType::U64(options) if options.is_fast() => Ok(Source::U64(field)),
+            FieldType::I64(options) if options.is_fast() => Ok(Source::I64(field)),
+            FieldType::F64(options) if options.is_fast() => Ok(Source::F64(field)),
+            FieldType::Date(options) if options.is_fast() => Ok(Source::Date(field)),
+            _ => Err(format!("not a numeric fast field: {}", field_name)),
+        }
+    }
+
+    fn reader(self, segment_reader: &SegmentReader) -> SourceReader {
+        let fast_fields = segment_reader.fast_fields();
+        match self {
+            Source::U64(field) => SourceReader::U64(fast_fields.u64(field)),
+            Source::I64(field) => SourceReader::I64(fast_fields.i64(field)),
+            Source::F64(field) => SourceReader::F64(fast_fields.f64(field)),
+            Source::Date(field) => SourceReader::Date(fast_fields.date(field)),
+            Source::Point(field, origin) => SourceReader::Point(fast_fields.u64(field), origin),
+        }
+    }
+}
+
+// Reads the value of a document as a number. Dates are read as timestamps in seconds and geo
+// points as the distance in meters from the origin of the decay function.
+enum SourceReader {
+    U64(Option<FastFieldReader<u64>>),
+    I64(Option<FastFieldReader<i64>>),
+    F64(Option<FastFieldReader<f64>>),
+    Date(Option<FastFieldReader<DateTime>>),
+    Point(Option<FastFieldReader<u64>>, (f64, f64)),
+}
+
+impl SourceReader {
+    fn get(&self, doc: DocId) -> Option<f64> {
+        match self {
+            SourceReader::U64(reader) => reader.as_ref().map(|r| r.get(doc) as f64),
+            SourceReader::I64(reader) => reader.as_ref().map(|r| r.get(doc) as f64),
+            SourceReader::F64(reader) => reader.as_ref().map(|r| r.get(doc)),
+            SourceReader::Date(reader) => reader.as_ref().map(|r| r.get(doc).timestamp() as f64),
+            SourceReader::Point(reader, origin) => reader
+                .as_ref()
+                .and_then(|r| decode_point(r.get(doc)))
+                .map(|(lat, lon)| geo::distance(origin.0, origin.1, lat, lon)),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum ScoreFunction {
+    FieldValueFactor {
+        source: Source,
+        factor: f64,
+        modifier: Modifier,
+        weight: f64,
+    },
+    Decay {
+        source: Source,
+        curve: DecayCurve,
+        origin: f64,
+        scale: f64,
+        offset: f64,
+        decay: f64,
+        weight: f64,
+    },
+    Weight {
+        weight: f64,
+    },
+}
+
+impl ScoreFunction {
+    fn new(
+        schema: &Schema,
+        setting: &ScoreFunctionSetting,
+        tz: &FixedOffset,
+    ) -> Result<ScoreFunction, String> {
+        match setting {
+            ScoreFunctionSetting::FieldValueFactor {
+                field,
+                factor,
+                modifier,
+                weight,
+            } => Ok(ScoreFunction::FieldValueFactor {
+                source: Source::new(schema, field)?,
+                factor: *factor,
+                modifier: *modifier,
+                weight: *weight,
+            }),
+            ScoreFunctionSetting::Decay {
+                field,
+                curve,
+                origin,
+                scale,
+                offset,
+                decay,
+                weight,
+            } => {
+                if *decay <= 0.0 || *decay >= 1.0 {
+                    return Err(format!("decay must be between 0 and 1: {}", decay));
+                }
+                let source = Source::new(schema, field)?;
+                // the origin of the scale is moved into the source for geo points, since their
+                // values are distances from it
+                let (source, origin, scale, offset) = match source {
+                    Source::Point(point_field, _) => (
+                        Source::Point(point_field, parse_point(origin)?),
+                        0.0,
+                        parse_distance(scale)?,
+                        if offset.is_empty() {
+                            0.0
+                        } else {
+                            parse_distance(offset)?
+                        },
+                    ),
+                    Source::Date(_) => (
+                        source,
+                        parse_date_math(origin, Utc::now(), tz)?.timestamp() as f64,
+                        parse_duration(scale)? as f64,
+                        if offset.is_empty() {
+                            0.0
+                        } else {
+                            parse_duration(offset)? as f64
+                        },
+                    ),
+                    _ => (
+                        source,
+                        origin.parse::<f64>().map_err(|e| e.to_string())?,
+                        scale.parse::<f64>().map_err(|e| e.to_string())?,
+                        if offset.is_empty() {
+                            0.0
+                        } else {
+                            offset.parse::<f64>().map_err(|e| e.to_string())?
+                        },
+                    ),
+                };
+                if scale <= 0.0 {
+                    return Err(format!("scale must be positive: {}", scale));
+                }
+                Ok(ScoreFunction::Decay {
+                    source,
+                    curve: *curve,
+                    origin,
+                    scale,
+                    offset,
+                    decay: *decay,
+                    weight: *weight,
+                })
+            }
+            ScoreFunctionSetting::Weight { weight } => Ok(ScoreFunction::Weight { weight: *weight }),
+        }
+    }
+
+    fn source(&self) -> Option<Source> {
+        match self {
+            ScoreFunction::FieldValueFactor { source, .. } => Some(*source),
+            ScoreFunction::Decay { source, .. } => Some(*source),
+            ScoreFunction::Weight { .. } => None,
+        }
+    }
+
+    fn score(&self, value: Option<f64>) -> f64 {
+        match *self {
+            ScoreFunction::FieldValueFactor {
+                factor,
+                modifier,
+                weight,
+                ..
+            } => value.map_or(1.0, |v| modifier.apply(v * factor) * weight),
+            ScoreFunction::Decay {
+                curve,
+                origin,
+                scale,
+                offset,
+                decay,
+                weight,
+                ..
+            } => {
+                let value = match value {
+                    Some(value) => value,
+                    None => return 1.0,
+                };
+                let distance = ((value - origin).abs() - offset).max(0.0);
+                let score = match curve {
+                    DecayCurve::Gauss => {
+                        let variance = -scale * scale / (2.0 * decay.ln());
+                        (-distance * distance / (2.0 * variance)).exp()
+                    }
+                    DecayCurve::Exp => (decay.ln() / scale * distance).exp(),
+                    DecayCurve::Linear => {
+                        let s = scale / (1.0 - decay);
+                        ((s - distance) / s).max(0.0)
+                    }
+                };
+                score * weight
+            }
+            ScoreFunction::Weight { weight } => weight,
+        }
+    }
+}
+
+// Combines the text score with scores computed from fast fields. Documents without a value for a
+// function are given a neutral score of 1.0 for it, regardless of its weight. Date origins are
+// evaluated in the time zone of the search.
+#[derive(Clone)]
+pub struct FunctionScore {
+    functions: Vec<ScoreFunction>,
+    score_mode: ScoreMode,
+    boost_mode: BoostMode,
+}
+
+impl FunctionScore {
+    pub fn new(
+        schema: &Schema,
+        setting: &FunctionScoreSetting,
+        time_zone: &str,
+    ) -> Result<FunctionScore, String> {
+        let tz = parse_time_zone(time_zone)?;
+        let functions = setting
+            .functions
+            .iter()
+            .map(|function| ScoreFunction::new(schema, function, &tz))
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(FunctionScore {
+            functions,
+            score_mode: setting.score_mode,
+            boost_mode: setting.boost_mode,
+        })
+    }
+
+    pub fn segment_scorer(&self, segment_reader: &SegmentReader) -> SegmentFunctionScore {
+        SegmentFunctionScore {
+            readers: self
+                .functions
+                .iter()
+                .map(|function| function.source().map(|source| source.reader(segment_reader)))
+                .collect(),
+            function_score: self.clone(),
+        }
+    }
+}
+
+pub struct SegmentFunctionScore {
+    function_score: FunctionScore,
+    readers: Vec<Option<SourceReader>>,
+}
+
+impl SegmentFunctionScore {
+    pub fn score(&self, doc: DocId, score: Score) -> Score {
+        let function_score = &self.function_score;
+        if function_score.functions.is_empty() {
+            return score;
+        }
+        // Called for every hit, so the function scores are folded without collecting them.
+        let scores = function_score
+            .functions
+            .iter()
+            .zip(self.readers.iter())
+            .map(|(function, reader)| {
+                function.score(reader.as_ref().and_then(|reader| reader.get(doc)))
+            });
+        let combined = match function_score.score_mode {
+            ScoreMode::Multiply => scores.product(),
+            ScoreMode::Sum => scores.sum(),
+            ScoreMode::Avg => scores.sum::<f64>() / function_score.functions.len() as f64,
+            ScoreMode::Max => scores.fold(std::f64::MIN, f64::max),
+            ScoreMode::Min => scores.fold(std::f64::MAX, f64::min),
+        };
+        let score = f64::from(score);
+        let boosted = match function_score.boost_mode {
+            BoostMode::Multiply => score * combined,
+            BoostMode::Sum => score + combined,
+            BoostMode::Replace => combined,
+        };
+        boosted as Score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use serde_json::json;
+    use tantivy::schema::{Schema, FAST};
+
+    use super::*;
+
+    fn score_function(schema: &Schema, setting: serde_json::Value, tz: &str) -> ScoreFunction {
+        let setting: ScoreFunctionSetting = serde_json::from_value(setting).unwrap();
+        ScoreFunction::new(schema, &setting, &parse_time_zone(tz).unwrap()).unwrap()
+    }
+
+    fn schema() -> Schema {
+        let mut schema_builder = Schema::builder();
+        schema_builder.add_f64_field("price", FAST);
+        schema_builder.add_date_field("timestamp", FAST);
+        schema_builder.build()
+    }
+
+    #[test]
+    fn test_modifiers_stay_finite() {
+        assert_eq!(Modifier::Log.apply(100.0), 2.0);
+        assert_eq!(Modifier::Log.apply(0.0), 0.0);
+        assert_eq!(Modifier::Ln1p.apply(-1.0), 0.0);
+        assert_eq!(Modifier::Sqrt.apply(-4.0), 0.0);
+        assert_eq!(Modifier::Square.apply(-3.0), 9.0);
+        assert_eq!(Modifier::Reciprocal.apply(4.0), 0.25);
+        assert_eq!(Modifier::Reciprocal.apply(0.0), 1.0);
+        assert_eq!(Modifier::Reciprocal.apply(-2.0), 1.0);
+    }
+
+    #[test]
+    fn test_field_value_factor() {
+        let schema = schema();
+        let function = score_function(
+            &schema,
+            json!({
+                "type": "field_value_factor",
+                "field": "price",
+                "factor": 10.0,
+                "modifier": "log",
+                "weight": 2.0
+            }),
+            "",
+        );
+        assert_eq!(function.score(Some(10.0)), 4.0);
+        // a missing value is neutral, regardless of the weight
+        assert_eq!(function.score(None), 1.0);
+    }
+
+    #[test]
+    fn test_decay_curves() {
+        let schema = schema();
+        for curve in &["gauss", "exp", "linear"] {
+            let function = score_function(
+                &schema,
+                json!({
+                    "type": "decay",
+                    "field": "price",
+                    "curve": curve,
+                    "origin": "100",
+                    "scale": "10",
+                    "offset": "5",
+                    "decay": 0.5,
+                    "weight": 2.0
+                }),
+                "",
+            );
+            // within the offset on either side of the origin
+            assert_eq!(function.score(Some(103.0)), 2.0);
+            assert_eq!(function.score(Some(95.0)), 2.0);
+            // the decay at offset + scale
+            let is_decay = |score: f64| (score - 1.0).abs() < 1e-9;
+            assert!(is_decay(function.score(Some(115.0))), "{}", curve);
+            assert!(is_decay(function.score(Some(85.0))), "{}", curve);
+            assert!(function.score(Some(120.0)) < 1.0);
+            assert_eq!(function.score(None), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_date_decay_origin_in_time_zone() {
+        let schema = schema();
+        let setting = json!({
+            "type": "decay",
+            "field": "timestamp",
+            "origin": "2020-01-01",
+            "scale": "1d"
+        });
+        let origin = |function: ScoreFunction| match function {
+            ScoreFunction::Decay { origin, .. } => origin,
+            _ => panic!("not a decay function"),
+        };
+        assert_eq!(
+            origin(score_function(&schema, setting.clone(), "")),
+            Utc.ymd(2020, 1, 1).and_hms(0, 0, 0).timestamp() as f64
+        );
+        assert_eq!(
+            origin(score_function(&schema, setting, "+09:00")),
+            Utc.ymd(2019, 12, 31).and_hms(15, 0, 0).timestamp() as f64
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use tantivy::query::{
+    BooleanQuery, BoostQuery, FuzzyTermQuery, Occur, PhraseQuery, Query, TermQuery,
+};
+use tantivy::schema::{Field, FieldType, IndexRecordOption, Schema};
+use tantivy::Term;
+
+// Levenshtein automata are only built up to this distance.
+pub const MAX_DISTANCE: u8 = 2;
+
+// Terms of up to 2 characters must match exactly, terms of up to 5 characters may have a typo,
+// and longer terms may have two.
+pub fn auto_distance(text: &str) -> u8 {
+    match text.chars().count() {
+        0..=2 => 0,
+        3..=5 => 1,
+        _ => 2,
+    }
+}
+
+// Matches the term exactly or within the distance. Exact matches are scored as a term query, so
+// they rank above the fuzzy ones, which get a constant score. The fuzzy term query takes its
+// automaton builder from the ones tantivy builds once per process, so no tables are built here.
+pub fn fuzzy_query(
+    term: Term,
+    distance: u8,
+    transposition_cost_one: bool,
+    prefix: bool,
+) -> Box<dyn Query> {
+    let fuzzy_query = if prefix {
+        FuzzyTermQuery::new_prefix(term.clone(), distance, transposition_cost_one)
+    } else {
+        FuzzyTermQuery::new(term.clone(), distance, transposition_cost_one)
+    };
+    Box::new(BooleanQuery::from(vec![
+        (
+            Occur::Should,
+            Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs)) as Box<dyn Query>,
+        ),
+        (Occur::Should, Box::new(fuzzy_query) as Box<dyn Query>),
+    ]))
+}
+
+// Rewrites the terms of text fields in a parsed query into fuzzy queries with a distance chosen
+// by the length of the term. Phrases and other queries are kept as is.
+//
+// The boost queries of the parser hide the queries they wrap, so the query must be parsed without
+// field boosts. The field boosts are applied to the rewritten terms and phrases instead. Terms
+// boosted in the query string itself are kept as is.
+pub fn typo_tolerant_query(
+    schema: &Schema,
+    query: &dyn Query,
+    field_boosts: &[(Field, f32)],
+) -> Box<dyn Query> {
+    if let Some(boolean_query) = query.downcast_ref::<BooleanQuery>() {
+        let subqueries: Vec<(Occur, Box<dyn Query>)> = boolean_query
+            .clauses()
+            .iter()
+            .map(|(occur, subquery)| {
+                (
+                    *occur,
+                    typo_tolerant_query(schema, subquery.as_ref(), field_boosts),
+                )
+            })
+            .collect();
+        return Box::new(BooleanQuery::from(subqueries));
+    }
+
+    let (field, query) = if let Some(term_query) = query.downcast_ref::<TermQuery>() {
+        let term = term_query.term();
+        let distance = match schema.get_field_entry(term.field()).field_type() {
+            FieldType::Str(_) => auto_distance(term.text()),
+            _ => 0,
+        };
+        if distance > 0 {
+            (
+                term.field(),
+                fuzzy_query(term.clone(), distance, true, false),
+            )
+        } else {
+            (term.field(), query.box_clone())
+        }
+    } else if let Some(phrase_query) = query.downcast_ref::<PhraseQuery>() {
+        (phrase_query.field(), query.box_clone())
+    } else {
+        return query.box_clone();
+    };
+    match field_boosts
+        .iter()
+        .find(|(boost_field, _boost)| *boost_field == field)
+    {
+        Some((_field, boost)) if (*boost - 1.0).abs() > f32::EPSILON => {
+            Box::new(BoostQuery::new(query, *boost))
+        }
+        _ => query,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::collector::TopDocs;
+    use tantivy::doc;
+    use tantivy::query::QueryParser;
+    use tantivy::schema::{Schema, STORED, TEXT};
+    use tantivy::Index;
+
+    use super::*;
+
+    #[test]
+    fn test_auto_distance() {
+        assert_eq!(auto_distance("ab"), 0);
+        assert_eq!(auto_distance("abc"), 1);
+        assert_eq!(auto_distance("abcde"), 1);
+        assert_eq!(auto_distance("abcdef"), 2);
+        // characters, not bytes
+        assert_eq!(auto_distance("日本"), 0);
+    }
+
+    #[test]
+    fn test_typo_tolerant_query_with_field_boosts() {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field("title", TEXT | STORED);
+        let text = schema_builder.add_text_field("text", TEXT | STORED);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema.clone());
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+        index_writer.add_document(doc!(title => "rust", text => "search engine"));
+        index_writer.add_document(doc!(title => "search engine", text => "rust"));
+        index_writer.add_document(doc!(title => "rust", text => "web server"));
+        index_writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+
+        let query = QueryParser::for_index(&index, vec![title, text])
+            .parse_query("serch")
+            .unwrap();
+        let query = typo_tolerant_query(&schema, query.as_ref(), &[(title, 3.0), (text, 1.0)]);
+        let top_docs = searcher
+            .search(query.as_ref(), &TopDocs::with_limit(10))
+            .unwrap();
+
+        // both documents with a typo match, and the one matched in the boosted field ranks first
+        assert_eq!(top_docs.len(), 2);
+        assert!(top_docs[0].0 > top_docs[1].0);
+        let doc = searcher.doc(top_docs[0].1).unwrap();
+        assert_eq!(
+            doc.get_first(title).and_then(|value| value.text()),
+            Some("search engine")
+        );
+    }
+}
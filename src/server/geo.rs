@@ -0,0 +1,694 @@
+use std::collections::BTreeSet;
+use std::f64::consts::PI;
+
+use tantivy::collector::{Collector, SegmentCollector};
+use tantivy::fastfield::FastFieldReader;
+use tantivy::query::{BooleanQuery, BoostQuery, Occur, Query, TermQuery};
+use tantivy::schema::{Field, FieldEntry, FieldType, IndexRecordOption, Schema, Value, FAST};
+use tantivy::tokenizer::{Token, Tokenizer};
+use tantivy::{
+    DocAddress, DocId, Document, Index, Score, Searcher, SegmentLocalId, SegmentReader, Term,
+};
+
+use crate::server::analyzer::VecTokenStream;
+
+pub const GEO_POINT_TOKENIZER: &str = "geo_point";
+
+const GEOHASH_BASE32: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const GEOHASH_MAX_PRECISION: usize = 9;
+// A filter is prefiltered with at most this many geohash cells. One character cells are 32 at
+// most, so there is always a precision that fits.
+const GEOHASH_MAX_CELLS: u64 = 32;
+// Points are compared after a lossy encoding, so the covered area is padded a little.
+const GEOHASH_PADDING: f64 = 1e-6;
+const EARTH_RADIUS: f64 = 6_371_008.8;
+const POINT_BITS: u32 = 31;
+
+pub fn parse_point(text: &str) -> Result<(f64, f64), String> {
+    let mut parts = text.split(',');
+    let (lat, lon) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(lat), Some(lon), None) => (lat.trim(), lon.trim()),
+        _ => return Err(format!("invalid geo point: {}", text)),
+    };
+    let lat = lat.parse::<f64>().map_err(|e| e.to_string())?;
+    let lon = lon.parse::<f64>().map_err(|e| e.to_string())?;
+    if lat < -90.0 || lat > 90.0 || lon < -180.0 || lon > 180.0 {
+        return Err(format!("geo point out of range: {}", text));
+    }
+    Ok((lat, lon))
+}
+
+pub fn parse_distance(text: &str) -> Result<f64, String> {
+    let text = text.trim();
+    let (value, unit) = match text.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(pos) => text.split_at(pos),
+        None => (text, "m"),
+    };
+    let value = value.parse::<f64>().map_err(|e| e.to_string())?;
+    if value < 0.0 || value.is_nan() {
+        return Err(format!("invalid distance: {}", text));
+    }
+    match unit {
+        "m" => Ok(value),
+        "km" => Ok(value * 1000.0),
+        "mi" => Ok(value * 1609.344),
+        _ => Err(format!("unknown distance unit: {}", unit)),
+    }
+}
+
+// Parses "top,left,bottom,right", i.e. the latitude and longitude of the top left corner followed
+// by those of the bottom right corner.
+pub fn parse_bounding_box(text: &str) -> Result<(f64, f64, f64, f64), String> {
+    let values = text
+        .split(',')
+        .map(|v| v.trim().parse::<f64>().map_err(|e| e.to_string()))
+        .collect::<Result<Vec<f64>, String>>()?;
+    if values.len() != 4 {
+        return Err(format!("invalid bounding box: {}", text));
+    }
+    let (top, left, bottom, right) = (values[0], values[1], values[2], values[3]);
+    if top > 90.0
+        || bottom < -90.0
+        || left < -180.0
+        || left > 180.0
+        || right < -180.0
+        || right > 180.0
+    {
+        return Err(format!("bounding box out of range: {}", text));
+    }
+    if top < bottom {
+        return Err(format!("bounding box top is below its bottom: {}", text));
+    }
+    Ok((top, left, bottom, right))
+}
+
+pub fn geohash(lat: f64, lon: f64, precision: usize) -> String {
+    let mut lat_range = (-90.0, 90.0);
+    let mut lon_range = (-180.0, 180.0);
+    let mut hash = String::with_capacity(precision);
+    let mut even = true;
+    let mut bits = 0;
+    let mut ch = 0;
+    while hash.len() < precision {
+        let (range, value) = if even {
+            (&mut lon_range, lon)
+        } else {
+            (&mut lat_range, lat)
+        };
+        let mid = (range.0 + range.1) / 2.0;
+        ch <<= 1;
+        if value >= mid {
+            ch |= 1;
+            range.0 = mid;
+        } else {
+            range.1 = mid;
+        }
+        even = !even;
+        bits += 1;
+        if bits == 5 {
+            hash.push(GEOHASH_BASE32[ch] as char);
+            bits = 0;
+            ch = 0;
+        }
+    }
+    hash
+}
+
+// Finds the geohash cells covering the latitudes and the longitude ranges, as fine as possible
+// without exceeding GEOHASH_MAX_CELLS.
+fn covering_cells(bottom: f64, top: f64, lon_ranges: &[(f64, f64)]) -> Vec<String> {
+    let bottom = (bottom - GEOHASH_PADDING).max(-90.0);
+    let top = (top + GEOHASH_PADDING).min(90.0);
+    let lon_ranges: Vec<(f64, f64)> = lon_ranges
+        .iter()
+        .map(|(left, right)| {
+            (
+                (left - GEOHASH_PADDING).max(-180.0),
+                (right + GEOHASH_PADDING).min(180.0),
+            )
+        })
+        .collect();
+    for precision in (1..=GEOHASH_MAX_PRECISION).rev() {
+        let lat_cells = 1u64 << (precision * 5 / 2);
+        let lon_cells = 1u64 << ((precision * 5 + 1) / 2);
+        let cell_height = 180.0 / lat_cells as f64;
+        let cell_width = 360.0 / lon_cells as f64;
+        let index = |value: f64, min: f64, size: f64, cells: u64| {
+            (((value - min) / size).floor() as u64).min(cells - 1)
+        };
+        let rows = (
+            index(bottom, -90.0, cell_height, lat_cells),
+            index(top, -90.0, cell_height, lat_cells),
+        );
+        let columns: Vec<(u64, u64)> = lon_ranges
+            .iter()
+            .map(|(left, right)| {
+                (
+                    index(*left, -180.0, cell_width, lon_cells),
+                    index(*right, -180.0, cell_width, lon_cells),
+                )
+            })
+            .collect();
+        let num_columns: u64 = columns.iter().map(|(first, last)| last - first + 1).sum();
+        if precision > 1 && (rows.1 - rows.0 + 1) * num_columns > GEOHASH_MAX_CELLS {
+            continue;
+        }
+        let mut cells = BTreeSet::new();
+        for row in rows.0..=rows.1 {
+            let lat = -90.0 + (row as f64 + 0.5) * cell_height;
+            for (first, last) in &columns {
+                for column in *first..=*last {
+                    let lon = -180.0 + (column as f64 + 0.5) * cell_width;
+                    cells.insert(geohash(lat, lon, precision));
+                }
+            }
+        }
+        return cells.into_iter().collect();
+    }
+    Vec::new()
+}
+
+pub fn distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS * a.sqrt().asin()
+}
+
+// Points are packed into a u64 fast field. The top bit marks the presence of a point, because
+// documents without one read as 0.
+pub fn encode_point(lat: f64, lon: f64) -> u64 {
+    let max = f64::from((1u32 << POINT_BITS) - 1);
+    let lat = ((lat + 90.0) / 180.0 * max).round() as u64;
+    let lon = ((lon + 180.0) / 360.0 * max).round() as u64;
+    (1 << 63) | (lat << POINT_BITS) | lon
+}
+
+pub fn decode_point(value: u64) -> Option<(f64, f64)> {
+    if value >> 63 == 0 {
+        return None;
+    }
+    let mask = (1u64 << POINT_BITS) - 1;
+    let max = mask as f64;
+    let lat = ((value >> POINT_BITS) & mask) as f64 / max * 180.0 - 90.0;
+    let lon = (value & mask) as f64 / max * 360.0 - 180.0;
+    Some((lat, lon))
+}
+
+// Indexes "lat,lon" as the geohash cells containing it, from the coarsest to the finest, so that
+// a query for a geohash matches the points inside that cell.
+#[derive(Clone)]
+pub struct GeoPointTokenizer;
+
+impl<'a> Tokenizer<'a> for GeoPointTokenizer {
+    type TokenStreamImpl = VecTokenStream;
+
+    fn token_stream(&self, text: &'a str) -> VecTokenStream {
+        let texts = match parse_point(text) {
+            Ok((lat, lon)) => {
+                let hash = geohash(lat, lon, GEOHASH_MAX_PRECISION);
+                (1..=GEOHASH_MAX_PRECISION)
+                    .map(|precision| hash[..precision].to_string())
+                    .collect()
+            }
+            Err(_) => vec![text.trim().to_lowercase()],
+        };
+        let tokens = texts
+            .into_iter()
+            .enumerate()
+            .map(|(position, text)| {
+                let mut token = Token::default();
+                token.position = position;
+                token.text = text;
+                token
+            })
+            .collect();
+        VecTokenStream::new(tokens)
+    }
+}
+
+pub fn register_geo_point_tokenizer(index: &Index) {
+    index
+        .tokenizers()
+        .register(GEO_POINT_TOKENIZER, GeoPointTokenizer);
+}
+
+pub fn is_geo_point_field(field_entry: &FieldEntry) -> bool {
+    if let FieldType::Str(text_field_options) = field_entry.field_type() {
+        if let Some(indexing_options) = text_field_options.get_indexing_options() {
+            return indexing_options.tokenizer() == GEO_POINT_TOKENIZER;
+        }
+    }
+    false
+}
+
+pub fn point_field_name(field_name: &str) -> String {
+    format!("_{}_point", field_name)
+}
+
+pub fn add_point_fields(schema: Schema) -> Schema {
+    let point_field_names: Vec<String> = schema
+        .fields()
+        .filter(|(_field, field_entry)| is_geo_point_field(field_entry))
+        .map(|(_field, field_entry)| point_field_name(field_entry.name()))
+        .filter(|name| schema.get_field(name).is_none())
+        .collect();
+    if point_field_names.is_empty() {
+        return schema;
+    }
+
+    let mut schema_builder = Schema::builder();
+    for (_field, field_entry) in schema.fields() {
+        schema_builder.add_field(field_entry.clone());
+    }
+    for name in point_field_names {
+        schema_builder.add_u64_field(&name, FAST);
+    }
+    schema_builder.build()
+}
+
+pub fn add_point_values(schema: &Schema, doc: &mut Document) {
+    for (field, field_entry) in schema.fields() {
+        if !is_geo_point_field(field_entry) {
+            continue;
+        }
+        let point_field = match schema.get_field(&point_field_name(field_entry.name())) {
+            Some(point_field) => point_field,
+            None => continue,
+        };
+        let point = match doc.get_first(field) {
+            Some(Value::Str(text)) => parse_point(text).ok(),
+            _ => None,
+        };
+        doc.filter_fields(|f| f != point_field);
+        if let Some((lat, lon)) = point {
+            doc.add_u64(point_field, encode_point(lat, lon));
+        }
+    }
+}
+
+pub fn doc_distance(
+    searcher: &Searcher,
+    point_field: Field,
+    doc_address: DocAddress,
+    origin: (f64, f64),
+) -> Option<f64> {
+    let point_reader = searcher
+        .segment_reader(doc_address.segment_ord())
+        .fast_fields()
+        .u64(point_field)?;
+    decode_point(point_reader.get(doc_address.doc()))
+        .map(|(lat, lon)| distance(origin.0, origin.1, lat, lon))
+}
+
+// The geohash field narrows down the candidates, and the points of the candidates are checked
+// against the filter.
+#[derive(Clone)]
+pub struct GeoFilter {
+    pub field: Field,
+    pub point_field: Field,
+    pub origin: Option<(f64, f64)>,
+    pub distance: Option<f64>,
+    pub bounding_box: Option<(f64, f64, f64, f64)>,
+}
+
+impl GeoFilter {
+    pub fn is_empty(&self) -> bool {
+        self.distance.is_none() && self.bounding_box.is_none()
+    }
+
+    // Matches the documents in the geohash cells covering the filter, without affecting scores.
+    pub fn cell_query(&self) -> Box<dyn Query> {
+        let mut areas = Vec::new();
+        if let (Some((lat, lon)), Some(max_distance)) = (self.origin, self.distance) {
+            let angle = (max_distance / EARTH_RADIUS).min(PI);
+            let bottom = lat - angle.to_degrees();
+            let top = lat + angle.to_degrees();
+            if bottom <= -90.0 || top >= 90.0 {
+                areas.push((bottom, top, vec![(-180.0, 180.0)]));
+            } else {
+                let d_lon = (angle.sin() / lat.to_radians().cos()).asin().to_degrees();
+                areas.push((bottom, top, lon_ranges(lon - d_lon, lon + d_lon)));
+            }
+        }
+        if let Some((top, left, bottom, right)) = self.bounding_box {
+            let lon_ranges = if left <= right {
+                vec![(left, right)]
+            } else {
+                vec![(left, 180.0), (-180.0, right)]
+            };
+            areas.push((bottom, top, lon_ranges));
+        }
+        let area_queries: Vec<(Occur, Box<dyn Query>)> = areas
+            .into_iter()
+            .map(|(bottom, top, lon_ranges)| {
+                let cell_queries: Vec<(Occur, Box<dyn Query>)> =
+                    covering_cells(bottom, top, &lon_ranges)
+                        .into_iter()
+                        .map(|cell| {
+                            let term = Term::from_field_text(self.field, &cell);
+                            let query: Box<dyn Query> =
+                                Box::new(TermQuery::new(term, IndexRecordOption::Basic));
+                            (Occur::Should, query)
+                        })
+                        .collect();
+                let query: Box<dyn Query> = Box::new(BooleanQuery::from(cell_queries));
+                (Occur::Must, query)
+            })
+            .collect();
+        Box::new(BoostQuery::new(
+            Box::new(BooleanQuery::from(area_queries)),
+            0.0,
+        ))
+    }
+
+    fn matches(&self, lat: f64, lon: f64) -> bool {
+        if let (Some(origin), Some(max_distance)) = (self.origin, self.distance) {
+            if distance(origin.0, origin.1, lat, lon) > max_distance {
+                return false;
+            }
+        }
+        if let Some((top, left, bottom, right)) = self.bounding_box {
+            if lat > top || lat < bottom {
+                return false;
+            }
+            // a box whose left edge is east of its right edge crosses the antimeridian
+            let in_lon = if left <= right {
+                lon >= left && lon <= right
+            } else {
+                lon >= left || lon <= right
+            };
+            if !in_lon {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// Splits a longitude range that crosses the antimeridian.
+fn lon_ranges(left: f64, right: f64) -> Vec<(f64, f64)> {
+    if right - left >= 360.0 {
+        vec![(-180.0, 180.0)]
+    } else if left < -180.0 {
+        vec![(left + 360.0, 180.0), (-180.0, right)]
+    } else if right > 180.0 {
+        vec![(left, 180.0), (-180.0, right - 360.0)]
+    } else {
+        vec![(left, right)]
+    }
+}
+
+pub struct GeoFilterCollector<'a, C: Collector> {
+    pub filter: &'a GeoFilter,
+    pub collector: &'a C,
+}
+
+impl<'a, C: Collector> Collector for GeoFilterCollector<'a, C> {
+    type Fruit = C::Fruit;
+    type Child = GeoFilterSegmentCollector<C::Child>;
+
+    fn for_segment(
+        &self,
+        segment_local_id: SegmentLocalId,
+        segment: &SegmentReader,
+    ) -> tantivy::Result<Self::Child> {
+        Ok(GeoFilterSegmentCollector {
+            filter: self.filter.clone(),
+            point_reader: segment.fast_fields().u64(self.filter.point_field),
+            collector: self.collector.for_segment(segment_local_id, segment)?,
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        self.collector.requires_scoring()
+    }
+
+    fn merge_fruits(&self, segment_fruits: Vec<Self::Fruit>) -> tantivy::Result<Self::Fruit> {
+        self.collector.merge_fruits(segment_fruits)
+    }
+}
+
+pub struct GeoFilterSegmentCollector<C: SegmentCollector> {
+    filter: GeoFilter,
+    point_reader: Option<FastFieldReader<u64>>,
+    collector: C,
+}
+
+impl<C: SegmentCollector> SegmentCollector for GeoFilterSegmentCollector<C> {
+    type Fruit = C::Fruit;
+
+    fn collect(&mut self, doc: DocId, score: Score) {
+        let point = self
+            .point_reader
+            .as_ref()
+            .and_then(|point_reader| decode_point(point_reader.get(doc)));
+        if let Some((lat, lon)) = point {
+            if self.filter.matches(lat, lon) {
+                self.collector.collect(doc, score);
+            }
+        }
+    }
+
+    fn harvest(self) -> Self::Fruit {
+        self.collector.harvest()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::collector::TopDocs;
+    use tantivy::schema::{TextFieldIndexing, TextOptions, STORED};
+
+    use super::*;
+
+    const TOKYO: (f64, f64) = (35.6812, 139.7671);
+    const OSAKA: (f64, f64) = (34.7025, 135.4959);
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{} is not within {} of {}",
+            actual,
+            tolerance,
+            expected
+        );
+    }
+
+    // the points of a grid over the area, edges included
+    fn grid(bottom: f64, top: f64, left: f64, right: f64) -> Vec<(f64, f64)> {
+        let steps = 20;
+        let mut points = Vec::new();
+        for i in 0..=steps {
+            for j in 0..=steps {
+                let lat = bottom + (top - bottom) * f64::from(i) / f64::from(steps);
+                let lon = left + (right - left) * f64::from(j) / f64::from(steps);
+                points.push((lat, lon));
+            }
+        }
+        points
+    }
+
+    fn is_covered(cells: &[String], lat: f64, lon: f64) -> bool {
+        let hash = geohash(lat, lon, GEOHASH_MAX_PRECISION);
+        cells.iter().any(|cell| hash.starts_with(cell.as_str()))
+    }
+
+    #[test]
+    fn test_parse_point() {
+        assert_eq!(parse_point("35.6812, 139.7671").unwrap(), TOKYO);
+        assert!(parse_point("35.6812").is_err());
+        assert!(parse_point("1,2,3").is_err());
+        assert!(parse_point("91,0").is_err());
+        assert!(parse_point("0,-181").is_err());
+        assert!(parse_point("north,east").is_err());
+    }
+
+    #[test]
+    fn test_parse_distance() {
+        assert_close(parse_distance("250").unwrap(), 250.0, 0.0);
+        assert_close(parse_distance("250m").unwrap(), 250.0, 0.0);
+        assert_close(parse_distance("1.5km").unwrap(), 1500.0, 1e-9);
+        assert_close(parse_distance("2mi").unwrap(), 3218.688, 1e-9);
+        assert!(parse_distance("-1km").is_err());
+        assert!(parse_distance("NaN").is_err());
+        assert!(parse_distance("1ft").is_err());
+    }
+
+    #[test]
+    fn test_parse_bounding_box() {
+        assert_eq!(
+            parse_bounding_box("36,139,35,140").unwrap(),
+            (36.0, 139.0, 35.0, 140.0)
+        );
+        // the left edge may be east of the right edge across the antimeridian
+        assert_eq!(
+            parse_bounding_box("10,170,-10,-170").unwrap(),
+            (10.0, 170.0, -10.0, -170.0)
+        );
+        assert!(parse_bounding_box("35,139,36,140").is_err());
+        assert!(parse_bounding_box("91,139,35,140").is_err());
+        assert!(parse_bounding_box("36,139,35,181").is_err());
+        assert!(parse_bounding_box("36,139,35").is_err());
+    }
+
+    #[test]
+    fn test_geohash() {
+        assert_eq!(geohash(57.64911, 10.40744, 9), "u4pruydqq");
+        assert_eq!(geohash(TOKYO.0, TOKYO.1, 9), "xn76urx61");
+        assert_eq!(geohash(TOKYO.0, TOKYO.1, 4), "xn76");
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_close(distance(TOKYO.0, TOKYO.1, TOKYO.0, TOKYO.1), 0.0, 1e-9);
+        assert_close(distance(TOKYO.0, TOKYO.1, OSAKA.0, OSAKA.1), 403_058.9, 1.0);
+        assert_close(
+            distance(TOKYO.0, TOKYO.1, OSAKA.0, OSAKA.1),
+            distance(OSAKA.0, OSAKA.1, TOKYO.0, TOKYO.1),
+            1e-6,
+        );
+        // one degree along a meridian, and across the antimeridian
+        assert_close(distance(0.0, 0.0, 1.0, 0.0), 111_195.1, 1.0);
+        assert_close(distance(0.0, 179.5, 0.0, -179.5), 111_195.1, 1.0);
+        assert_close(distance(90.0, 0.0, -90.0, 0.0), PI * EARTH_RADIUS, 1e-6);
+    }
+
+    #[test]
+    fn test_encode_point() {
+        for &(lat, lon) in &[TOKYO, OSAKA, (-90.0, -180.0), (90.0, 180.0), (0.0, 0.0)] {
+            let (decoded_lat, decoded_lon) = decode_point(encode_point(lat, lon)).unwrap();
+            assert_close(decoded_lat, lat, 1e-6);
+            assert_close(decoded_lon, lon, 1e-6);
+        }
+        // documents without a point read as 0
+        assert_eq!(decode_point(0), None);
+    }
+
+    #[test]
+    fn test_lon_ranges() {
+        assert_eq!(lon_ranges(130.0, 140.0), vec![(130.0, 140.0)]);
+        assert_eq!(
+            lon_ranges(170.0, 190.0),
+            vec![(170.0, 180.0), (-180.0, -170.0)]
+        );
+        assert_eq!(
+            lon_ranges(-190.0, -170.0),
+            vec![(170.0, 180.0), (-180.0, -170.0)]
+        );
+        assert_eq!(lon_ranges(-200.0, 200.0), vec![(-180.0, 180.0)]);
+    }
+
+    #[test]
+    fn test_covering_cells() {
+        let (bottom, top, left, right) = (35.5, 35.8, 139.5, 140.0);
+        let cells = covering_cells(bottom, top, &[(left, right)]);
+        assert!(!cells.is_empty());
+        assert!(cells.len() as u64 <= GEOHASH_MAX_CELLS);
+        // the cells are finer than the whole world
+        assert!(cells.iter().all(|cell| cell.len() > 1));
+        for (lat, lon) in grid(bottom, top, left, right) {
+            assert!(is_covered(&cells, lat, lon), "{},{} not covered", lat, lon);
+        }
+        assert!(!is_covered(&cells, OSAKA.0, OSAKA.1));
+    }
+
+    #[test]
+    fn test_covering_cells_across_antimeridian() {
+        let cells = covering_cells(-1.0, 1.0, &lon_ranges(179.0, 181.0));
+        assert!(cells.len() as u64 <= GEOHASH_MAX_CELLS);
+        for (lat, lon) in grid(-1.0, 1.0, 179.0, 180.0)
+            .into_iter()
+            .chain(grid(-1.0, 1.0, -180.0, -179.0))
+        {
+            assert!(is_covered(&cells, lat, lon), "{},{} not covered", lat, lon);
+        }
+        assert!(!is_covered(&cells, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_covering_cells_of_the_whole_world() {
+        let cells = covering_cells(-90.0, 90.0, &[(-180.0, 180.0)]);
+        assert_eq!(cells.len(), 32);
+    }
+
+    #[test]
+    fn test_geo_filter() {
+        let mut schema_builder = Schema::builder();
+        let text_options = TextOptions::default()
+            .set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(GEO_POINT_TOKENIZER)
+                    .set_index_option(IndexRecordOption::Basic),
+            )
+            .set_stored();
+        let location = schema_builder.add_text_field("location", text_options);
+        let name = schema_builder.add_text_field("name", STORED);
+        let schema = add_point_fields(schema_builder.build());
+        let point_field = schema.get_field(&point_field_name("location")).unwrap();
+
+        let index = Index::create_in_ram(schema.clone());
+        register_geo_point_tokenizer(&index);
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+        for (place, point) in &[
+            ("tokyo", "35.6812,139.7671"),
+            ("yokohama", "35.4658,139.6223"),
+            ("osaka", "34.7025,135.4959"),
+            ("fiji", "-17.7134,178.0650"),
+            ("samoa", "-13.7590,-172.1046"),
+        ] {
+            let mut doc = Document::default();
+            doc.add_text(name, place);
+            doc.add_text(location, point);
+            add_point_values(&schema, &mut doc);
+            index_writer.add_document(doc);
+        }
+        index_writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+
+        let search = |filter: &GeoFilter| -> Vec<String> {
+            let top_docs = TopDocs::with_limit(10);
+            let collector = GeoFilterCollector {
+                filter,
+                collector: &top_docs,
+            };
+            let mut names: Vec<String> = searcher
+                .search(filter.cell_query().as_ref(), &collector)
+                .unwrap()
+                .into_iter()
+                .map(|(_score, doc_address)| {
+                    let doc = searcher.doc(doc_address).unwrap();
+                    doc.get_first(name).unwrap().text().unwrap().to_string()
+                })
+                .collect();
+            names.sort();
+            names
+        };
+
+        let filter = GeoFilter {
+            field: location,
+            point_field,
+            origin: Some(TOKYO),
+            distance: Some(50_000.0),
+            bounding_box: None,
+        };
+        assert_eq!(search(&filter), vec!["tokyo", "yokohama"]);
+
+        let filter = GeoFilter {
+            distance: Some(500_000.0),
+            ..filter
+        };
+        assert_eq!(search(&filter), vec!["osaka", "tokyo", "yokohama"]);
+
+        // a box across the antimeridian
+        let filter = GeoFilter {
+            field: location,
+            point_field,
+            origin: None,
+            distance: None,
+            bounding_box: Some((0.0, 170.0, -20.0, -170.0)),
+        };
+        assert_eq!(search(&filter), vec!["fiji", "samoa"]);
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use tantivy::query::Query;
+use tantivy::schema::{Document, Field, FieldType, Schema};
+use tantivy::{Searcher, Snippet, SnippetGenerator};
+
+const DEFAULT_FRAGMENT_SIZE: usize = 150;
+
+fn default_fragment_size() -> usize {
+    DEFAULT_FRAGMENT_SIZE
+}
+
+fn default_pre_tag() -> String {
+    String::from("<em>")
+}
+
+fn default_post_tag() -> String {
+    String::from("</em>")
+}
+
+#[derive(Deserialize)]
+pub struct HighlightSetting {
+    pub fields: Vec<String>,
+    #[serde(default = "default_fragment_size")]
+    pub fragment_size: usize,
+    #[serde(default = "default_pre_tag")]
+    pub pre_tag: String,
+    #[serde(default = "default_post_tag")]
+    pub post_tag: String,
+}
+
+// Picks the fragments of the text fields of a document that best match the query, with the
+// matched terms wrapped in the tags.
+pub struct Highlighter {
+    generators: Vec<(String, Field, SnippetGenerator)>,
+    pre_tag: String,
+    post_tag: String,
+}
+
+impl Highlighter {
+    pub fn new(
+        searcher: &Searcher,
+        schema: &Schema,
+        query: &dyn Query,
+        setting: &HighlightSetting,
+    ) -> Result<Highlighter, String> {
+        if setting.fragment_size == 0 {
+            return Err(format!("invalid fragment size: {}", setting.fragment_size));
+        }
+        let mut generators = Vec::new();
+        for field_name in &setting.fields {
+            let field = schema
+                .get_field(field_name)
+                .ok_or_else(|| format!("unknown field: {}", field_name))?;
+            match schema.get_field_entry(field).field_type() {
+                FieldType::Str(options) if options.get_indexing_options().is_some() => {}
+                _ => return Err(format!("not an indexed text field: {}", field_name)),
+            }
+            let mut generator =
+                SnippetGenerator::create(searcher, query, field).map_err(|e| e.to_string())?;
+            generator.set_max_num_chars(setting.fragment_size);
+            generators.push((field_name.clone(), field, generator));
+        }
+        Ok(Highlighter {
+            generators,
+            pre_tag: setting.pre_tag.clone(),
+            post_tag: setting.post_tag.clone(),
+        })
+    }
+
+    // Returns a fragment for each value of a field that matches the query. Fields without a
+    // match are omitted.
+    pub fn highlight(&self, doc: &Document) -> HashMap<String, Vec<String>> {
+        let mut highlight = HashMap::new();
+        for (field_name, field, generator) in &self.generators {
+            let fragments: Vec<String> = doc
+                .get_all(*field)
+                .into_iter()
+                .filter_map(|value| value.text())
+                .map(|text| generator.snippet(text))
+                .filter(|snippet| !snippet.highlighted().is_empty())
+                .map(|snippet| self.render(&snippet))
+                .collect();
+            if !fragments.is_empty() {
+                highlight.insert(field_name.clone(), fragments);
+            }
+        }
+        highlight
+    }
+
+    // The text is HTML escaped, the tags are not.
+    fn render(&self, snippet: &Snippet) -> String {
+        let fragment = snippet.fragments();
+        let mut html = String::new();
+        let mut start_from = 0;
+        for section in snippet.highlighted() {
+            let (start, end) = section.bounds();
+            html.push_str(&escape_html(&fragment[start_from..start]));
+            html.push_str(&self.pre_tag);
+            html.push_str(&escape_html(&fragment[start..end]));
+            html.push_str(&self.post_tag);
+            start_from = end;
+        }
+        html.push_str(&escape_html(&fragment[start_from..]));
+        html
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::doc;
+    use tantivy::query::QueryParser;
+    use tantivy::schema::{Schema, STORED, TEXT};
+    use tantivy::Index;
+
+    use super::*;
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html(r#"<a href="x">Tom & Jerry's</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_highlight() {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field("title", TEXT | STORED);
+        let body = schema_builder.add_text_field("body", TEXT | STORED);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema.clone());
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+        let document = doc!(title => "Rust <fast> & safe", body => "a web server");
+        index_writer.add_document(document.clone());
+        index_writer.commit().unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let query = QueryParser::for_index(&index, vec![title, body])
+            .parse_query("rust")
+            .unwrap();
+
+        let setting: HighlightSetting = serde_json::from_str(
+            r#"{"fields": ["title", "body"], "pre_tag": "<b>", "post_tag": "</b>"}"#,
+        )
+        .unwrap();
+        let highlighter = Highlighter::new(&searcher, &schema, query.as_ref(), &setting).unwrap();
+        let highlight = highlighter.highlight(&document);
+
+        // the text is escaped but the tags are not, and fields without a match are omitted
+        assert_eq!(highlight.len(), 1);
+        assert_eq!(
+            highlight["title"],
+            vec![String::from("<b>Rust</b> &lt;fast&gt; &amp; safe")]
+        );
+    }
+
+    #[test]
+    fn test_highlight_fields_must_be_indexed_text() {
+        let mut schema_builder = Schema::builder();
+        let title = schema_builder.add_text_field("title", TEXT);
+        schema_builder.add_text_field("stored", STORED);
+        let schema = schema_builder.build();
+        let index = Index::create_in_ram(schema.clone());
+        let searcher = index.reader().unwrap().searcher();
+        let query = QueryParser::for_index(&index, vec![title])
+            .parse_query("rust")
+            .unwrap();
+
+        for (fields, err) in &[
+            (r#"["stored"]"#, "not an indexed text field: stored"),
+            (r#"["unknown"]"#, "unknown field: unknown"),
+        ] {
+            let setting: HighlightSetting =
+                serde_json::from_str(&format!(r#"{{"fields": {}}}"#, fields)).unwrap();
+            let result = Highlighter::new(&searcher, &schema, query.as_ref(), &setting);
+            assert_eq!(result.err(), Some(err.to_string()));
+        }
+    }
+}
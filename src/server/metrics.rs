@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 
-use prometheus::{CounterVec, Encoder, Opts, Registry, TextEncoder};
+use prometheus::{Counter, CounterVec, Encoder, Opts, Registry, TextEncoder};
 
 pub struct Metrics {
     registry: Registry,
     request_counter: CounterVec,
+    search_cache_hit_counter: Counter,
+    search_cache_miss_counter: Counter,
 }
 
 impl Metrics {
@@ -12,15 +14,35 @@ impl Metrics {
         let request_counter_opts = Opts::new("bayard_requests_total", "Total number of requests.")
             .const_label("id", &id.to_string());
         let request_counter = CounterVec::new(request_counter_opts, &["request_type"]).unwrap();
+        let search_cache_hit_counter_opts = Opts::new(
+            "bayard_search_cache_hits_total",
+            "Total number of search cache hits.",
+        )
+        .const_label("id", &id.to_string());
+        let search_cache_hit_counter = Counter::with_opts(search_cache_hit_counter_opts).unwrap();
+        let search_cache_miss_counter_opts = Opts::new(
+            "bayard_search_cache_misses_total",
+            "Total number of search cache misses.",
+        )
+        .const_label("id", &id.to_string());
+        let search_cache_miss_counter = Counter::with_opts(search_cache_miss_counter_opts).unwrap();
 
         let registry = Registry::new();
         registry
             .register(Box::new(request_counter.clone()))
             .unwrap();
+        registry
+            .register(Box::new(search_cache_hit_counter.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(search_cache_miss_counter.clone()))
+            .unwrap();
 
         Metrics {
             registry,
             request_counter,
+            search_cache_hit_counter,
+            search_cache_miss_counter,
         }
     }
 
@@ -31,6 +53,14 @@ impl Metrics {
         self.request_counter.with(&labels).inc();
     }
 
+    pub fn inc_search_cache_hit_count(&mut self) {
+        self.search_cache_hit_counter.inc();
+    }
+
+    pub fn inc_search_cache_miss_count(&mut self) {
+        self.search_cache_miss_counter.inc();
+    }
+
     pub fn get_metrics(&mut self) -> String {
         let mut buffer = Vec::<u8>::new();
         let encoder = TextEncoder::new();
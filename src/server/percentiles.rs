@@ -0,0 +1,219 @@
+use serde_json::{json, Value};
+
+use crate::server::aggregation::ValueSource;
+
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+pub const DEFAULT_PERCENTS: [f64; 7] = [1.0, 5.0, 25.0, 50.0, 75.0, 95.0, 99.0];
+
+// Values are buffered and merged into the centroids in batches.
+const BUFFER_FACTOR: f64 = 5.0;
+
+#[derive(Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+// A merging t-digest. Centroids near the tails are kept small, so extreme percentiles stay
+// accurate, while the number of centroids is bounded by about the compression.
+#[derive(Clone)]
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    unmerged: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    pub fn new(compression: f64) -> TDigest {
+        TDigest {
+            compression,
+            centroids: Vec::new(),
+            unmerged: Vec::new(),
+            min: std::f64::INFINITY,
+            max: std::f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn add(&mut self, value: f64) {
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.unmerged.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        if self.unmerged.len() as f64 >= BUFFER_FACTOR * self.compression {
+            self.compress();
+        }
+    }
+
+    pub fn merge(&mut self, other: &TDigest) {
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.unmerged.extend_from_slice(&other.centroids);
+        self.unmerged.extend_from_slice(&other.unmerged);
+        self.compress();
+    }
+
+    fn compress(&mut self) {
+        if self.unmerged.is_empty() {
+            return;
+        }
+        let mut centroids = std::mem::replace(&mut self.centroids, Vec::new());
+        centroids.append(&mut self.unmerged);
+        centroids.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap_or(std::cmp::Ordering::Equal));
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+
+        let mut weight_so_far = 0.0;
+        let mut current = centroids[0];
+        for centroid in centroids.into_iter().skip(1) {
+            let proposed = current.weight + centroid.weight;
+            let q = (weight_so_far + proposed / 2.0) / total;
+            let limit = 4.0 * total * q * (1.0 - q) / self.compression;
+            if proposed <= limit.max(1.0) {
+                current.mean += (centroid.mean - current.mean) * centroid.weight / proposed;
+                current.weight = proposed;
+            } else {
+                weight_so_far += current.weight;
+                self.centroids.push(current);
+                current = centroid;
+            }
+        }
+        self.centroids.push(current);
+    }
+
+    // Interpolates between the centers of the centroids, and between the min and the max at the
+    // tails. The digest must have been compressed.
+    fn quantile(&self, q: f64) -> Option<f64> {
+        let centroids = &self.centroids;
+        if centroids.is_empty() {
+            return None;
+        }
+        if centroids.len() == 1 {
+            return Some(centroids[0].mean);
+        }
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let rank = q.max(0.0).min(1.0) * total;
+
+        let first = centroids[0];
+        if rank < first.weight / 2.0 {
+            return Some(self.min + (first.mean - self.min) * rank / (first.weight / 2.0));
+        }
+        let mut cumulative = 0.0;
+        for pair in centroids.windows(2) {
+            let left = cumulative + pair[0].weight / 2.0;
+            let right = cumulative + pair[0].weight + pair[1].weight / 2.0;
+            if rank <= right {
+                let t = (rank - left) / (right - left);
+                return Some(pair[0].mean + t * (pair[1].mean - pair[0].mean));
+            }
+            cumulative += pair[0].weight;
+        }
+        let last = centroids[centroids.len() - 1];
+        let left = total - last.weight / 2.0;
+        let t = ((rank - left) / (last.weight / 2.0)).min(1.0);
+        Some(last.mean + t * (self.max - last.mean))
+    }
+
+    // Returns the values at the given percents, from 0 to 100.
+    pub fn percentiles(&mut self, percents: &[f64]) -> Vec<Option<f64>> {
+        self.compress();
+        percents
+            .iter()
+            .map(|percent| self.quantile(percent / 100.0))
+            .collect()
+    }
+}
+
+// Percentiles of no documents are null.
+pub fn percentiles_result(source: ValueSource, percents: &[f64], digest: &mut TDigest) -> Value {
+    let values: Vec<Value> = percents
+        .iter()
+        .zip(digest.percentiles(percents))
+        .map(|(percent, value)| {
+            json!({
+                "key": percent,
+                "value": value.map_or(Value::Null, |value| source.to_json(value)),
+            })
+        })
+        .collect();
+    json!({ "values": values })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERCENTS: [f64; 10] = [0.0, 1.0, 5.0, 25.0, 50.0, 75.0, 95.0, 99.0, 99.9, 100.0];
+
+    // 0 to 9999 in a scrambled order
+    fn values() -> impl Iterator<Item = f64> {
+        (0..10_000u32).map(|i| f64::from(i * 7919 % 10_000))
+    }
+
+    fn assert_percentiles(digest: &mut TDigest) {
+        for (percent, value) in PERCENTS.iter().zip(digest.percentiles(&PERCENTS)) {
+            let expected = percent / 100.0 * 9999.0;
+            let value = value.unwrap();
+            assert!(
+                (value - expected).abs() <= 10.0,
+                "{}th percentile is {}, expected about {}",
+                percent,
+                value,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_percentiles() {
+        let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+        for value in values() {
+            digest.add(value);
+        }
+        assert_percentiles(&mut digest);
+        // the extremes are exact
+        assert_eq!(
+            digest.percentiles(&[0.0, 100.0]),
+            vec![Some(0.0), Some(9999.0)]
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut low = TDigest::new(DEFAULT_COMPRESSION);
+        let mut high = TDigest::new(DEFAULT_COMPRESSION);
+        for value in values() {
+            if value < 5000.0 {
+                low.add(value);
+            } else {
+                high.add(value);
+            }
+        }
+        let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+        digest.merge(&low);
+        digest.merge(&high);
+        assert_percentiles(&mut digest);
+    }
+
+    #[test]
+    fn test_few_values() {
+        let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+        assert_eq!(digest.percentiles(&[50.0]), vec![None]);
+
+        digest.add(5.0);
+        assert_eq!(
+            digest.percentiles(&[1.0, 50.0, 99.0]),
+            vec![Some(5.0), Some(5.0), Some(5.0)]
+        );
+
+        let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+        digest.add(3.0);
+        digest.add(1.0);
+        assert_eq!(
+            digest.percentiles(&[0.0, 50.0, 100.0]),
+            vec![Some(1.0), Some(2.0), Some(3.0)]
+        );
+    }
+}
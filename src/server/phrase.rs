@@ -0,0 +1,288 @@
+use std::collections::BTreeSet;
+
+use tantivy::postings::SegmentPostings;
+use tantivy::query::{
+    BooleanQuery, EmptyScorer, Explanation, Occur, Query, Scorer, TermQuery, Weight,
+};
+use tantivy::schema::IndexRecordOption;
+use tantivy::{
+    DocId, DocSet, Postings, Score, Searcher, SegmentReader, SkipResult, TantivyError, Term,
+};
+
+// Matches the documents in which the terms of a phrase appear within slop positions of where the
+// phrase puts them, in any order. The terms are given with their offsets in the phrase. Matching
+// documents are scored as the conjunction of the terms.
+#[derive(Clone, Debug)]
+pub struct SloppyPhraseQuery {
+    terms: Vec<(usize, Term)>,
+    slop: u32,
+}
+
+impl SloppyPhraseQuery {
+    pub fn new(terms: Vec<(usize, Term)>, slop: u32) -> SloppyPhraseQuery {
+        SloppyPhraseQuery { terms, slop }
+    }
+}
+
+impl Query for SloppyPhraseQuery {
+    fn weight(
+        &self,
+        searcher: &Searcher,
+        scoring_enabled: bool,
+    ) -> tantivy::Result<Box<dyn Weight>> {
+        let conjunction = BooleanQuery::from(
+            self.terms
+                .iter()
+                .map(|(_offset, term)| {
+                    let query: Box<dyn Query> =
+                        Box::new(TermQuery::new(term.clone(), IndexRecordOption::WithFreqs));
+                    (Occur::Must, query)
+                })
+                .collect::<Vec<(Occur, Box<dyn Query>)>>(),
+        );
+        Ok(Box::new(SloppyPhraseWeight {
+            terms: self.terms.clone(),
+            slop: self.slop,
+            conjunction: conjunction.weight(searcher, scoring_enabled)?,
+        }))
+    }
+
+    fn query_terms(&self, term_set: &mut BTreeSet<Term>) {
+        for (_offset, term) in &self.terms {
+            term_set.insert(term.clone());
+        }
+    }
+}
+
+struct SloppyPhraseWeight {
+    terms: Vec<(usize, Term)>,
+    slop: u32,
+    conjunction: Box<dyn Weight>,
+}
+
+impl SloppyPhraseWeight {
+    // Returns None if a term does not appear in the segment.
+    fn phrase_scorer(&self, reader: &SegmentReader) -> tantivy::Result<Option<SloppyPhraseScorer>> {
+        let mut postings = Vec::new();
+        for (offset, term) in &self.terms {
+            match reader
+                .inverted_index(term.field())
+                .read_postings(term, IndexRecordOption::WithFreqsAndPositions)
+            {
+                Some(term_postings) => postings.push((*offset as i64, term_postings)),
+                None => return Ok(None),
+            }
+        }
+        // the slots of each term that appears more than once, in phrase order
+        let mut repeated: Vec<Vec<usize>> = Vec::new();
+        for (i, (_offset, term)) in self.terms.iter().enumerate() {
+            if self.terms[..i].iter().any(|(_offset, other)| other == term) {
+                continue;
+            }
+            let mut slots: Vec<usize> = (i..self.terms.len())
+                .filter(|j| self.terms[*j].1 == *term)
+                .collect();
+            if slots.len() > 1 {
+                slots.sort_by_key(|j| self.terms[*j].0);
+                repeated.push(slots);
+            }
+        }
+        Ok(Some(SloppyPhraseScorer {
+            conjunction: self.conjunction.scorer(reader)?,
+            postings,
+            repeated,
+            slop: i64::from(self.slop),
+        }))
+    }
+}
+
+impl Weight for SloppyPhraseWeight {
+    fn scorer(&self, reader: &SegmentReader) -> tantivy::Result<Box<dyn Scorer>> {
+        match self.phrase_scorer(reader)? {
+            Some(scorer) => Ok(Box::new(scorer)),
+            None => Ok(Box::new(EmptyScorer)),
+        }
+    }
+
+    fn explain(&self, reader: &SegmentReader, doc: DocId) -> tantivy::Result<Explanation> {
+        let not_matched =
+            || TantivyError::InvalidArgument(format!("document not matched: {}", doc));
+        let mut scorer = self.phrase_scorer(reader)?.ok_or_else(not_matched)?;
+        if scorer.skip_next(doc) != SkipResult::Reached {
+            return Err(not_matched());
+        }
+        Ok(Explanation::new("SloppyPhraseScorer", scorer.score()))
+    }
+}
+
+struct SloppyPhraseScorer {
+    conjunction: Box<dyn Scorer>,
+    postings: Vec<(i64, SegmentPostings)>,
+    repeated: Vec<Vec<usize>>,
+    slop: i64,
+}
+
+impl SloppyPhraseScorer {
+    // A term repeated in the phrase needs a distinct position for each of its slots. The slots
+    // all see the window shifted by their offsets, so taking them in phrase order, each at the
+    // first position after the previous one, finds such positions if there are any.
+    fn has_distinct_positions(&self, window: &[(i64, usize)]) -> bool {
+        self.repeated.iter().all(|slots| {
+            let mut last: Option<i64> = None;
+            slots.iter().all(|slot| {
+                let offset = self.postings[*slot].0;
+                last = window
+                    .iter()
+                    .filter(|(_position, term)| term == slot)
+                    .map(|(position, _term)| position + offset)
+                    .find(|position| last.map_or(true, |last| *position > last));
+                last.is_some()
+            })
+        })
+    }
+
+    // Looks for the smallest window of phrase positions that holds every term. The postings only
+    // move forward, since the conjunction returns the documents in order.
+    fn phrase_match(&mut self, doc: DocId) -> bool {
+        let mut positions: Vec<(i64, usize)> = Vec::new();
+        let mut term_positions: Vec<u32> = Vec::new();
+        for (i, (offset, postings)) in self.postings.iter_mut().enumerate() {
+            if postings.skip_next(doc) != SkipResult::Reached {
+                return false;
+            }
+            term_positions.clear();
+            postings.positions(&mut term_positions);
+            positions.extend(
+                term_positions
+                    .iter()
+                    .map(|position| (i64::from(*position) - *offset, i)),
+            );
+        }
+        positions.sort();
+
+        let mut counts = vec![0; self.postings.len()];
+        let mut covered = 0;
+        let mut start = 0;
+        for (end, &(end_position, end_term)) in positions.iter().enumerate() {
+            if counts[end_term] == 0 {
+                covered += 1;
+            }
+            counts[end_term] += 1;
+            while covered == self.postings.len() {
+                let (start_position, start_term) = positions[start];
+                if end_position - start_position <= self.slop {
+                    // a smaller window has fewer positions to choose from
+                    if self.has_distinct_positions(&positions[start..=end]) {
+                        return true;
+                    }
+                    break;
+                }
+                counts[start_term] -= 1;
+                if counts[start_term] == 0 {
+                    covered -= 1;
+                }
+                start += 1;
+            }
+        }
+        false
+    }
+}
+
+impl DocSet for SloppyPhraseScorer {
+    fn advance(&mut self) -> bool {
+        while self.conjunction.advance() {
+            let doc = self.conjunction.doc();
+            if self.phrase_match(doc) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn doc(&self) -> DocId {
+        self.conjunction.doc()
+    }
+
+    fn size_hint(&self) -> u32 {
+        self.conjunction.size_hint()
+    }
+}
+
+impl Scorer for SloppyPhraseScorer {
+    fn score(&mut self) -> Score {
+        self.conjunction.score()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tantivy::collector::TopDocs;
+    use tantivy::doc;
+    use tantivy::schema::{Field, Schema, TEXT};
+    use tantivy::Index;
+
+    use super::*;
+
+    fn create_index(texts: &[&str]) -> (Index, Field) {
+        let mut schema_builder = Schema::builder();
+        let text = schema_builder.add_text_field("text", TEXT);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+        for value in texts {
+            index_writer.add_document(doc!(text => *value));
+        }
+        index_writer.commit().unwrap();
+        (index, text)
+    }
+
+    // Returns the ids of the matching documents, in the order they were added.
+    fn search(index: &Index, field: Field, words: &[&str], slop: u32) -> Vec<DocId> {
+        let terms = words
+            .iter()
+            .enumerate()
+            .map(|(offset, word)| (offset, Term::from_field_text(field, word)))
+            .collect();
+        let query = SloppyPhraseQuery::new(terms, slop);
+        let searcher = index.reader().unwrap().searcher();
+        let mut docs: Vec<DocId> = searcher
+            .search(&query, &TopDocs::with_limit(10))
+            .unwrap()
+            .into_iter()
+            .map(|(_score, doc_address)| doc_address.doc())
+            .collect();
+        docs.sort();
+        docs
+    }
+
+    #[test]
+    fn test_slop() {
+        let (index, text) = create_index(&["a b", "b c a", "b x x a", "a"]);
+        assert_eq!(search(&index, text, &["a", "b"], 1), vec![0]);
+        assert_eq!(search(&index, text, &["b", "a"], 1), vec![1]);
+        // swapping the terms takes two moves
+        assert_eq!(search(&index, text, &["b", "a"], 2), vec![0, 1, 2]);
+        assert_eq!(search(&index, text, &["a", "b"], 3), vec![0, 1]);
+        assert_eq!(search(&index, text, &["a", "b"], 4), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_repeated_terms() {
+        let (index, text) = create_index(&["a", "a b", "a x a", "a a", "b a a c", "a b a"]);
+        // a single "a" does not fill both slots
+        assert_eq!(search(&index, text, &["a", "a"], 0), vec![3, 4]);
+        assert_eq!(search(&index, text, &["a", "a"], 1), vec![2, 3, 4, 5]);
+        assert_eq!(search(&index, text, &["a", "a"], 10), vec![2, 3, 4, 5]);
+        assert_eq!(search(&index, text, &["a", "b", "a"], 0), vec![5]);
+        assert_eq!(search(&index, text, &["a", "b", "a"], 2), vec![4, 5]);
+        assert_eq!(
+            search(&index, text, &["a", "a", "a"], 10),
+            Vec::<DocId>::new()
+        );
+    }
+
+    #[test]
+    fn test_missing_term() {
+        let (index, text) = create_index(&["a b"]);
+        assert_eq!(search(&index, text, &["a", "c"], 10), Vec::<DocId>::new());
+    }
+}
@@ -0,0 +1,658 @@
+use std::ops::Bound;
+
+use chrono::{FixedOffset, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use tantivy::query::{
+    AllQuery, BooleanQuery, BoostQuery, Occur, PhraseQuery, Query, RangeQuery, TermQuery,
+};
+use tantivy::schema::{Facet, Field, FieldType, IndexRecordOption, Schema, Type};
+use tantivy::{Index, Term};
+
+use crate::server::date_math::{parse_date_math, parse_time_zone};
+use crate::server::fuzzy::{auto_distance, fuzzy_query, MAX_DISTANCE};
+use crate::server::phrase::SloppyPhraseQuery;
+
+fn default_transposition_cost_one() -> bool {
+    true
+}
+
+// A query in JSON format, such as {"term": {"field": "category", "value": "/category/search"}}.
+// Each query may have a boost that multiplies its score.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryDsl {
+    Bool {
+        #[serde(default)]
+        must: Vec<QueryDsl>,
+        #[serde(default)]
+        should: Vec<QueryDsl>,
+        #[serde(default)]
+        must_not: Vec<QueryDsl>,
+        #[serde(default)]
+        filter: Vec<QueryDsl>,
+        #[serde(default)]
+        boost: Option<f32>,
+    },
+    Term {
+        field: String,
+        value: Value,
+        #[serde(default)]
+        boost: Option<f32>,
+    },
+    Terms {
+        field: String,
+        values: Vec<Value>,
+        #[serde(default)]
+        boost: Option<f32>,
+    },
+    Phrase {
+        field: String,
+        text: String,
+        #[serde(default)]
+        slop: u32,
+        #[serde(default)]
+        boost: Option<f32>,
+    },
+    Range {
+        field: String,
+        #[serde(default)]
+        gt: Option<Value>,
+        #[serde(default)]
+        gte: Option<Value>,
+        #[serde(default)]
+        lt: Option<Value>,
+        #[serde(default)]
+        lte: Option<Value>,
+        #[serde(default)]
+        boost: Option<f32>,
+    },
+    Prefix {
+        field: String,
+        value: String,
+        #[serde(default)]
+        boost: Option<f32>,
+    },
+    Exists {
+        field: String,
+        #[serde(default)]
+        boost: Option<f32>,
+    },
+    // The distance is chosen by the length of the value if not given.
+    Fuzzy {
+        field: String,
+        value: String,
+        #[serde(default)]
+        distance: Option<u8>,
+        #[serde(default = "default_transposition_cost_one")]
+        transposition_cost_one: bool,
+        #[serde(default)]
+        prefix: bool,
+        #[serde(default)]
+        boost: Option<f32>,
+    },
+    MatchAll {
+        #[serde(default)]
+        boost: Option<f32>,
+    },
+}
+
+// Builds tantivy queries from the query DSL. Dates are date math expressions evaluated in the
+// time zone.
+pub struct QueryBuilder<'a> {
+    index: &'a Index,
+    schema: Schema,
+    now: chrono::DateTime<Utc>,
+    tz: FixedOffset,
+}
+
+impl<'a> QueryBuilder<'a> {
+    pub fn new(index: &'a Index, time_zone: &str) -> Result<QueryBuilder<'a>, String> {
+        Ok(QueryBuilder {
+            index,
+            schema: index.schema(),
+            now: Utc::now(),
+            tz: parse_time_zone(time_zone)?,
+        })
+    }
+
+    pub fn build(&self, query_dsl: &QueryDsl) -> Result<Box<dyn Query>, String> {
+        let (query, boost): (Box<dyn Query>, &Option<f32>) = match query_dsl {
+            QueryDsl::Bool {
+                must,
+                should,
+                must_not,
+                filter,
+                boost,
+            } => {
+                let mut subqueries: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+                for query in must {
+                    subqueries.push((Occur::Must, self.build(query)?));
+                }
+                for query in should {
+                    subqueries.push((Occur::Should, self.build(query)?));
+                }
+                for query in must_not {
+                    subqueries.push((Occur::MustNot, self.build(query)?));
+                }
+                // filters must match but do not count for the score
+                for query in filter {
+                    subqueries.push((
+                        Occur::Must,
+                        Box::new(BoostQuery::new(self.build(query)?, 0.0)),
+                    ));
+                }
+                (Box::new(BooleanQuery::from(subqueries)), boost)
+            }
+            QueryDsl::Term {
+                field,
+                value,
+                boost,
+            } => {
+                let field = self.field(field)?;
+                (self.term_query(self.term(field, value)?), boost)
+            }
+            QueryDsl::Terms {
+                field,
+                values,
+                boost,
+            } => {
+                let field = self.field(field)?;
+                let mut subqueries: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+                for value in values {
+                    subqueries.push((Occur::Should, self.term_query(self.term(field, value)?)));
+                }
+                (Box::new(BooleanQuery::from(subqueries)), boost)
+            }
+            QueryDsl::Phrase {
+                field,
+                text,
+                slop,
+                boost,
+            } => (self.phrase_query(field, text, *slop)?, boost),
+            QueryDsl::Range {
+                field,
+                gt,
+                gte,
+                lt,
+                lte,
+                boost,
+            } => {
+                let field = self.field(field)?;
+                let lower_bound = match (gt, gte) {
+                    (Some(_), Some(_)) => return Err(String::from("both gt and gte are given")),
+                    (Some(gt), None) => Bound::Excluded(self.term(field, gt)?),
+                    (None, Some(gte)) => Bound::Included(self.term(field, gte)?),
+                    (None, None) => Bound::Unbounded,
+                };
+                let upper_bound = match (lt, lte) {
+                    (Some(_), Some(_)) => return Err(String::from("both lt and lte are given")),
+                    (Some(lt), None) => Bound::Excluded(self.term(field, lt)?),
+                    (None, Some(lte)) => Bound::Included(self.term(field, lte)?),
+                    (None, None) => Bound::Unbounded,
+                };
+                let query = RangeQuery::new_term_bounds(
+                    field,
+                    self.value_type(field)?,
+                    &lower_bound,
+                    &upper_bound,
+                );
+                (Box::new(query), boost)
+            }
+            QueryDsl::Prefix {
+                field: field_name,
+                value,
+                boost,
+            } => {
+                let field = self.field(field_name)?;
+                match self.schema.get_field_entry(field).field_type() {
+                    FieldType::Str(_) => {}
+                    _ => return Err(format!("not a text field: {}", field_name)),
+                }
+                // U+10FFFF is the greatest character, so the range holds the terms with the prefix
+                let query = RangeQuery::new_term_bounds(
+                    field,
+                    Type::Str,
+                    &Bound::Included(Term::from_field_text(field, value)),
+                    &Bound::Excluded(Term::from_field_text(
+                        field,
+                        &format!("{}\u{10ffff}", value),
+                    )),
+                );
+                (Box::new(query), boost)
+            }
+            QueryDsl::Exists { field, boost } => {
+                // matches the documents with any term in the field
+                let field = self.field(field)?;
+                let query = RangeQuery::new_term_bounds(
+                    field,
+                    self.value_type(field)?,
+                    &Bound::Unbounded,
+                    &Bound::Unbounded,
+                );
+                (Box::new(query), boost)
+            }
+            QueryDsl::Fuzzy {
+                field: field_name,
+                value,
+                distance,
+                transposition_cost_one,
+                prefix,
+                boost,
+            } => {
+                let field = self.field(field_name)?;
+                match self.schema.get_field_entry(field).field_type() {
+                    FieldType::Str(_) => {}
+                    _ => return Err(format!("not a text field: {}", field_name)),
+                }
+                let distance = distance.unwrap_or_else(|| auto_distance(value));
+                if distance > MAX_DISTANCE {
+                    return Err(format!(
+                        "distance must be at most {}: {}",
+                        MAX_DISTANCE, distance
+                    ));
+                }
+                let term = Term::from_field_text(field, value);
+                (
+                    fuzzy_query(term, distance, *transposition_cost_one, *prefix),
+                    boost,
+                )
+            }
+            QueryDsl::MatchAll { boost } => (Box::new(AllQuery), boost),
+        };
+        match boost {
+            Some(boost) => Ok(Box::new(BoostQuery::new(query, *boost))),
+            None => Ok(query),
+        }
+    }
+
+    fn field(&self, field_name: &str) -> Result<Field, String> {
+        let field = self
+            .schema
+            .get_field(field_name)
+            .ok_or_else(|| format!("unknown field: {}", field_name))?;
+        if !self.schema.get_field_entry(field).is_indexed() {
+            return Err(format!("not an indexed field: {}", field_name));
+        }
+        Ok(field)
+    }
+
+    fn value_type(&self, field: Field) -> Result<Type, String> {
+        let field_entry = self.schema.get_field_entry(field);
+        match field_entry.field_type() {
+            FieldType::Str(_) => Ok(Type::Str),
+            FieldType::U64(_) => Ok(Type::U64),
+            FieldType::I64(_) => Ok(Type::I64),
+            FieldType::F64(_) => Ok(Type::F64),
+            FieldType::Date(_) => Ok(Type::Date),
+            FieldType::HierarchicalFacet => Ok(Type::HierarchicalFacet),
+            _ => Err(format!("unsupported field: {}", field_entry.name())),
+        }
+    }
+
+    // Text values are not analyzed. Numbers may be given as JSON numbers or strings.
+    fn term(&self, field: Field, value: &Value) -> Result<Term, String> {
+        let field_entry = self.schema.get_field_entry(field);
+        let text = match value {
+            Value::String(text) => text.clone(),
+            Value::Number(number) => number.to_string(),
+            Value::Bool(_) | Value::Null | Value::Array(_) | Value::Object(_) => {
+                return Err(format!(
+                    "invalid value for {}: {}",
+                    field_entry.name(),
+                    value
+                ));
+            }
+        };
+        let invalid =
+            |e: String| format!("invalid value for {}: {}: {}", field_entry.name(), text, e);
+        match field_entry.field_type() {
+            FieldType::Str(_) => Ok(Term::from_field_text(field, &text)),
+            FieldType::U64(_) => text
+                .parse::<u64>()
+                .map(|value| Term::from_field_u64(field, value))
+                .map_err(|e| invalid(e.to_string())),
+            FieldType::I64(_) => text
+                .parse::<i64>()
+                .map(|value| Term::from_field_i64(field, value))
+                .map_err(|e| invalid(e.to_string())),
+            FieldType::F64(_) => text
+                .parse::<f64>()
+                .map(|value| Term::from_field_f64(field, value))
+                .map_err(|e| invalid(e.to_string())),
+            FieldType::Date(_) => parse_date_math(&text, self.now, &self.tz)
+                .map(|date| Term::from_field_date(field, &date))
+                .map_err(invalid),
+            FieldType::HierarchicalFacet if text.starts_with('/') => {
+                Ok(Term::from_facet(field, &Facet::from_text(&text)))
+            }
+            _ => Err(invalid(String::from("unsupported value"))),
+        }
+    }
+
+    fn term_query(&self, term: Term) -> Box<dyn Query> {
+        Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs))
+    }
+
+    // The text is analyzed with the tokenizer of the field. A phrase of a single term is a term
+    // query.
+    fn phrase_query(
+        &self,
+        field_name: &str,
+        text: &str,
+        slop: u32,
+    ) -> Result<Box<dyn Query>, String> {
+        let field = self.field(field_name)?;
+        match self.schema.get_field_entry(field).field_type() {
+            FieldType::Str(options) => match options.get_indexing_options() {
+                Some(indexing) if indexing.index_option().has_positions() => {}
+                _ => return Err(format!("not indexed with positions: {}", field_name)),
+            },
+            _ => return Err(format!("not a text field: {}", field_name)),
+        }
+        let tokenizer = self
+            .index
+            .tokenizer_for_field(field)
+            .map_err(|e| e.to_string())?;
+        let mut terms: Vec<(usize, Term)> = Vec::new();
+        let mut token_stream = tokenizer.token_stream(text);
+        while token_stream.advance() {
+            let token = token_stream.token();
+            terms.push((token.position, Term::from_field_text(field, &token.text)));
+        }
+        match terms.len() {
+            0 => Err(format!("no terms in phrase: {}", text)),
+            1 => Ok(self.term_query(terms.pop().unwrap().1)),
+            _ if slop == 0 => Ok(Box::new(PhraseQuery::new(
+                terms.into_iter().map(|(_position, term)| term).collect(),
+            ))),
+            _ => Ok(Box::new(SloppyPhraseQuery::new(terms, slop))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use tantivy::collector::TopDocs;
+    use tantivy::schema::{Document, FAST, INDEXED, STORED, STRING, TEXT};
+
+    use super::*;
+
+    fn create_index() -> Index {
+        let mut schema_builder = Schema::builder();
+        let id = schema_builder.add_text_field("id", STRING | STORED);
+        let title = schema_builder.add_text_field("title", TEXT);
+        let category = schema_builder.add_facet_field("category");
+        let price = schema_builder.add_i64_field("price", INDEXED | FAST);
+        let timestamp = schema_builder.add_date_field("timestamp", INDEXED);
+        schema_builder.add_i64_field("stock", FAST);
+        let index = Index::create_in_ram(schema_builder.build());
+        let mut index_writer = index.writer_with_num_threads(1, 10_000_000).unwrap();
+        for (doc_id, text, facet, value, month) in &[
+            ("1", "rust search engine", "/lang/rust", Some(10), 1),
+            ("2", "search engine in go", "/lang/go", Some(20), 2),
+            ("3", "engine of search", "/lang/rust", Some(30), 3),
+            ("4", "web server", "/lang/go", None, 4),
+        ] {
+            let mut doc = Document::default();
+            doc.add_text(id, doc_id);
+            doc.add_text(title, text);
+            doc.add_facet(category, *facet);
+            if let Some(value) = value {
+                doc.add_i64(price, *value);
+            }
+            doc.add_date(timestamp, &Utc.ymd(2020, *month, 1).and_hms(0, 0, 0));
+            index_writer.add_document(doc);
+        }
+        index_writer.commit().unwrap();
+        index
+    }
+
+    // Returns the ids of the matching documents, from the best.
+    fn search_in(index: &Index, query_dsl: &str, time_zone: &str) -> Result<Vec<String>, String> {
+        let query_dsl: QueryDsl = serde_json::from_str(query_dsl).map_err(|e| e.to_string())?;
+        let query = QueryBuilder::new(index, time_zone)?.build(&query_dsl)?;
+        let id = index.schema().get_field("id").unwrap();
+        let searcher = index.reader().unwrap().searcher();
+        let top_docs = searcher
+            .search(query.as_ref(), &TopDocs::with_limit(10))
+            .unwrap();
+        Ok(top_docs
+            .into_iter()
+            .map(|(_score, doc_address)| {
+                let doc = searcher.doc(doc_address).unwrap();
+                doc.get_first(id).unwrap().text().unwrap().to_string()
+            })
+            .collect())
+    }
+
+    fn search(index: &Index, query_dsl: &str) -> Result<Vec<String>, String> {
+        search_in(index, query_dsl, "")
+    }
+
+    fn sorted(ids: Result<Vec<String>, String>) -> Vec<String> {
+        let mut ids = ids.unwrap();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_term() {
+        let index = create_index();
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"term": {"field": "title", "value": "search"}}"#
+            )),
+            vec!["1", "2", "3"]
+        );
+        // values are not analyzed
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"term": {"field": "title", "value": "Search"}}"#
+            )),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"term": {"field": "price", "value": 20}}"#
+            )),
+            vec!["2"]
+        );
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"term": {"field": "price", "value": "20"}}"#
+            )),
+            vec!["2"]
+        );
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"term": {"field": "category", "value": "/lang/rust"}}"#
+            )),
+            vec!["1", "3"]
+        );
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"terms": {"field": "category", "values": ["/lang/go", "/lang/java"]}}"#
+            )),
+            vec!["2", "4"]
+        );
+    }
+
+    #[test]
+    fn test_bool() {
+        let index = create_index();
+        let query_dsl = r#"{"bool": {
+            "must": [{"term": {"field": "title", "value": "engine"}}],
+            "must_not": [{"term": {"field": "category", "value": "/lang/go"}}],
+            "filter": [{"range": {"field": "price", "gte": 20}}]
+        }}"#;
+        assert_eq!(sorted(search(&index, query_dsl)), vec!["3"]);
+
+        let query_dsl = r#"{"bool": {"should": [
+            {"term": {"field": "title", "value": "rust"}},
+            {"term": {"field": "title", "value": "go", "boost": 10.0}}
+        ]}}"#;
+        assert_eq!(search(&index, query_dsl).unwrap(), vec!["2", "1"]);
+    }
+
+    #[test]
+    fn test_range() {
+        let index = create_index();
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"range": {"field": "price", "gt": 10, "lte": 30}}"#
+            )),
+            vec!["2", "3"]
+        );
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"range": {"field": "timestamp", "gte": "2020-02-01", "lt": "2020-03-01||+1d"}}"#
+            )),
+            vec!["2", "3"]
+        );
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"range": {"field": "timestamp", "gte": "2020-02-15||/M"}}"#
+            )),
+            vec!["2", "3", "4"]
+        );
+    }
+
+    #[test]
+    fn test_range_with_time_zone() {
+        let index = create_index();
+        let query_dsl = r#"{"range": {"field": "timestamp", "lt": "2020-03-01"}}"#;
+        // midnight in Tokyo is before midnight in UTC, and midnight in New York after it
+        assert_eq!(
+            sorted(search_in(&index, query_dsl, "+09:00")),
+            vec!["1", "2"]
+        );
+        assert_eq!(
+            sorted(search_in(&index, query_dsl, "-05:00")),
+            vec!["1", "2", "3"]
+        );
+        assert!(search_in(&index, query_dsl, "America/New_York").is_err());
+    }
+
+    #[test]
+    fn test_prefix_exists_and_match_all() {
+        let index = create_index();
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"prefix": {"field": "title", "value": "serv"}}"#
+            )),
+            vec!["4"]
+        );
+        assert_eq!(
+            sorted(search(&index, r#"{"exists": {"field": "price"}}"#)),
+            vec!["1", "2", "3"]
+        );
+        assert_eq!(
+            sorted(search(&index, r#"{"match_all": {}}"#)),
+            vec!["1", "2", "3", "4"]
+        );
+    }
+
+    #[test]
+    fn test_fuzzy() {
+        let index = create_index();
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"fuzzy": {"field": "title", "value": "serch"}}"#
+            )),
+            vec!["1", "2", "3"]
+        );
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"fuzzy": {"field": "title", "value": "serch", "distance": 0}}"#
+            )),
+            Vec::<String>::new()
+        );
+        assert!(search(
+            &index,
+            r#"{"fuzzy": {"field": "title", "value": "serch", "distance": 3}}"#
+        )
+        .is_err());
+        assert!(search(&index, r#"{"fuzzy": {"field": "price", "value": "10"}}"#).is_err());
+    }
+
+    #[test]
+    fn test_phrase() {
+        let index = create_index();
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"phrase": {"field": "title", "text": "Search Engine"}}"#
+            )),
+            vec!["1", "2"]
+        );
+        // "engine of search" needs three moves
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"phrase": {"field": "title", "text": "search engine", "slop": 2}}"#
+            )),
+            vec!["1", "2"]
+        );
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"phrase": {"field": "title", "text": "search engine", "slop": 3}}"#
+            )),
+            vec!["1", "2", "3"]
+        );
+        // a single term is a term query
+        assert_eq!(
+            sorted(search(
+                &index,
+                r#"{"phrase": {"field": "title", "text": "Web"}}"#
+            )),
+            vec!["4"]
+        );
+        assert!(search(&index, r#"{"phrase": {"field": "title", "text": ""}}"#).is_err());
+        assert!(search(&index, r#"{"phrase": {"field": "id", "text": "1 2"}}"#).is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        let index = create_index();
+        let error = search(&index, r#"{"term": {"field": "name", "value": "rust"}}"#).unwrap_err();
+        assert!(error.starts_with("unknown field"), "{}", error);
+        // not indexed
+        assert!(search(&index, r#"{"term": {"field": "stock", "value": 1}}"#).is_err());
+        assert!(search(&index, r#"{"term": {"field": "price", "value": "ten"}}"#).is_err());
+        assert!(search(&index, r#"{"term": {"field": "price", "value": true}}"#).is_err());
+        assert!(search(
+            &index,
+            r#"{"term": {"field": "category", "value": "rust"}}"#
+        )
+        .is_err());
+        assert!(search(
+            &index,
+            r#"{"range": {"field": "price", "gt": 10, "gte": 10}}"#
+        )
+        .is_err());
+        assert!(search(
+            &index,
+            r#"{"range": {"field": "timestamp", "gte": "yesterday"}}"#
+        )
+        .is_err());
+        assert!(search(&index, r#"{"prefix": {"field": "price", "value": "1"}}"#).is_err());
+        // malformed queries are rejected when they are parsed
+        assert!(search(&index, r#"{"match": {"field": "title", "value": "rust"}}"#).is_err());
+        assert!(search(&index, r#"{"term": {"field": "title"}}"#).is_err());
+    }
+}
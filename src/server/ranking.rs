@@ -0,0 +1,163 @@
+use serde_json::Value;
+use tantivy::fastfield::{FastFieldReader, FastValue};
+use tantivy::schema::{Field, FieldType, Schema};
+use tantivy::{DateTime, DocId, Score, SegmentReader};
+
+use crate::server::function_score::{FunctionScore, SegmentFunctionScore};
+use crate::server::geo::{self, decode_point};
+
+#[derive(Clone, Copy)]
+enum SortType {
+    U64,
+    I64,
+    Date,
+}
+
+// A fast field to sort by, such as "popularity desc" or "timestamp asc".
+#[derive(Clone, Copy)]
+pub struct SortClause {
+    field: Field,
+    sort_type: SortType,
+    descending: bool,
+}
+
+impl SortClause {
+    pub fn parse(schema: &Schema, clause: &str) -> Result<SortClause, String> {
+        let mut parts = clause.split_whitespace();
+        let field_name = parts
+            .next()
+            .ok_or_else(|| format!("empty sort clause: {:?}", clause))?;
+        let descending = match parts.next().map(|order| order.to_lowercase()) {
+            None => false,
+            Some(ref order) if order == "asc" => false,
+            Some(ref order) if order == "desc" => true,
+            Some(order) => return Err(format!("unknown sort order: {}", order)),
+        };
+        if parts.next().is_some() {
+            return Err(format!("invalid sort clause: {:?}", clause));
+        }
+        let field = schema
+            .get_field(field_name)
+            .ok_or_else(|| format!("unknown field: {}", field_name))?;
+        let sort_type = match schema.get_field_entry(field).field_type() {
+            FieldType::U64(options) if options.is_fast() => SortType::U64,
+            FieldType::I64(options) if options.is_fast() => SortType::I64,
+            FieldType::Date(options) if options.is_fast() => SortType::Date,
+            _ => return Err(format!("not a u64, i64 or date fast field: {}", field_name)),
+        };
+        Ok(SortClause {
+            field,
+            sort_type,
+            descending,
+        })
+    }
+
+    // Fast fields store i64 and date values as order preserving u64, so the raw values can be
+    // compared directly. They are inverted for ascending order, since greater ranks come first.
+    fn key(self, raw: u64) -> u64 {
+        if self.descending {
+            raw
+        } else {
+            !raw
+        }
+    }
+
+    fn value(self, key: u64) -> Value {
+        let raw = self.key(key);
+        match self.sort_type {
+            SortType::U64 => Value::from(raw),
+            SortType::I64 => Value::from(i64::from_u64(raw)),
+            SortType::Date => Value::from(DateTime::from_u64(raw).to_rfc3339()),
+        }
+    }
+}
+
+// The rank of a document. Ranks are compared by the sort keys first, then by the proximity to
+// the origin when sorting by distance, and then by the score.
+#[derive(Clone, PartialEq, PartialOrd)]
+pub struct DocRank {
+    pub sort_keys: Vec<u64>,
+    pub proximity: f64,
+    pub score: Score,
+}
+
+// Ranks documents by the sort clauses, by the distance from a point if sorting by distance, and
+// then by the score tweaked by the function score if any. Greater ranks come first.
+#[derive(Clone, Default)]
+pub struct DocRanker {
+    pub sort: Vec<SortClause>,
+    pub distance_origin: Option<(Field, (f64, f64))>,
+    pub function_score: Option<FunctionScore>,
+}
+
+impl DocRanker {
+    pub fn is_default(&self) -> bool {
+        self.sort.is_empty() && self.distance_origin.is_none() && self.function_score.is_none()
+    }
+
+    // Returns the values of the sort fields a rank was made from.
+    pub fn sort_values(&self, rank: &DocRank) -> Vec<Value> {
+        self.sort
+            .iter()
+            .zip(rank.sort_keys.iter())
+            .map(|(clause, key)| clause.value(*key))
+            .collect()
+    }
+
+    pub fn segment_ranker(&self, segment_reader: &SegmentReader) -> SegmentDocRanker {
+        SegmentDocRanker {
+            sort_readers: self
+                .sort
+                .iter()
+                .map(|clause| {
+                    (*clause, segment_reader.fast_fields().u64_lenient(clause.field))
+                })
+                .collect(),
+            distance_origin: self.distance_origin,
+            point_reader: self
+                .distance_origin
+                .and_then(|(point_field, _origin)| segment_reader.fast_fields().u64(point_field)),
+            function_score: self
+                .function_score
+                .as_ref()
+                .map(|function_score| function_score.segment_scorer(segment_reader)),
+        }
+    }
+}
+
+pub struct SegmentDocRanker {
+    sort_readers: Vec<(SortClause, Option<FastFieldReader<u64>>)>,
+    distance_origin: Option<(Field, (f64, f64))>,
+    point_reader: Option<FastFieldReader<u64>>,
+    function_score: Option<SegmentFunctionScore>,
+}
+
+impl SegmentDocRanker {
+    pub fn rank(&self, doc: DocId, score: Score) -> DocRank {
+        let sort_keys = self
+            .sort_readers
+            .iter()
+            .map(|(clause, reader)| {
+                clause.key(reader.as_ref().map(|reader| reader.get(doc)).unwrap_or(0))
+            })
+            .collect();
+        let score = match &self.function_score {
+            Some(function_score) => function_score.score(doc, score),
+            None => score,
+        };
+        let distance = match self.distance_origin {
+            Some((_point_field, origin)) => self
+                .point_reader
+                .as_ref()
+                .and_then(|point_reader| decode_point(point_reader.get(doc)))
+                .map(|(lat, lon)| geo::distance(origin.0, origin.1, lat, lon))
+                .unwrap_or(std::f64::INFINITY),
+            None => 0.0,
+        };
+        DocRank {
+            sort_keys,
+            proximity: -distance,
+            score,
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use std::{fs, thread};
-use std::collections::HashMap;
+use std::{fs, str, thread};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+use std::ops::Bound;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::sync::mpsc::{self, Receiver, SyncSender};
@@ -7,31 +9,75 @@ use std::time::Duration;
 
 use crossbeam_channel::select;
 use async_std::task::block_on;
+use chrono::Utc;
 use futures::Future;
 use grpcio::{ChannelBuilder, EnvBuilder, Environment, RpcContext, ServerBuilder, UnarySink};
 use log::*;
 use protobuf::Message;
 use raft::eraftpb::{ConfChange, ConfChangeType, Entry, EntryType, Message as RaftMessage};
-use tantivy::{Document, Index, IndexWriter, Term};
-use tantivy::collector::{Count, FacetCollector, MultiCollector, TopDocs};
-use tantivy::query::{QueryParser, TermQuery};
-use tantivy::schema::{Field, FieldType, IndexRecordOption, Schema};
+use serde_json::{json, Map, Value};
+use tantivy::{
+    DocAddress, DocId, DocSet, Document, Index, IndexReader, IndexWriter, ReloadPolicy, Score,
+    Searcher, SegmentReader, TantivyError, Term,
+};
+use tantivy::collector::{Collector, Count, MultiCollector, TopDocs};
+use tantivy::query::{
+    AllQuery, BooleanQuery, Occur, Query, QueryParser, QueryParserError, RangeQuery, TermQuery,
+};
+use tantivy::schema::{
+    DocParsingError, Field, FieldType, IndexRecordOption, Schema, Type, Value as FieldValue, FAST,
+    STORED,
+};
+use tantivy::termdict::TermMerger;
 
 use crate::client::client::{Clerk, create_client};
 use crate::proto::indexpb_grpc::{self, Index as IndexService, IndexClient};
 use crate::proto::indexrpcpb::{
-    ApplyReq, CommitResp, ConfChangeReq, DeleteResp, GetReq, GetResp, JoinReq, LeaveReq, MergeResp,
-    MetricsReq, MetricsResp, PeersReq, PeersResp, ProbeReq, ProbeResp, PutResp, RaftDone, ReqType,
-    RespErr, RollbackResp, SchemaReq, SchemaResp, SearchReq, SearchResp,
+    ApplyReq, CommitResp, ConfChangeReq, DeleteByQueryResp, DeleteResp, GetReq, GetResp, JoinReq,
+    LeaveReq, MergeResp, MetricsReq, MetricsResp, MultiGetReq, MultiGetResp, PeersReq, PeersResp,
+    ProbeReq, ProbeResp, PutResp, RaftDone, ReqType, RespErr, RollbackResp, SchemaReq, SchemaResp,
+    SearchReq, SearchResp, SuggestReq, SuggestResp, UpdateReq, UpdateResp,
 };
 use crate::server::{peer, util};
+use crate::server::aggregation::{AggregationCollector, AggregationSetting};
+use crate::server::analyzer::{check_tokenizers, register_analyzers, ANALYZER_FILE_NAME};
+use crate::server::budget::{search_with_budget, BudgetCollector, SearchBudget};
+use crate::server::cache::{is_cacheable, search_cache_key, searcher_generation, SearchCache};
+use crate::server::collapse::CollapseCollector;
+use crate::server::date_math::{parse_date_math, parse_time_zone};
+use crate::server::facet::parse_facet_settings;
+use crate::server::function_score::{FunctionScore, FunctionScoreSetting};
+use crate::server::fuzzy::typo_tolerant_query;
+use crate::server::geo::{
+    add_point_fields, add_point_values, doc_distance, is_geo_point_field, parse_bounding_box,
+    parse_distance, parse_point, point_field_name, register_geo_point_tokenizer, GeoFilter,
+    GeoFilterCollector,
+};
+use crate::server::highlight::{HighlightSetting, Highlighter};
 use crate::server::metrics::Metrics;
 use crate::server::peer::PeerMessage;
-use crate::util::search_result::{ScoredNamedFieldDocument, SearchResult};
+use crate::server::query_dsl::{QueryBuilder, QueryDsl};
+use crate::server::ranking::{DocRanker, SortClause};
+use crate::server::spelling::SpellingCorrector;
+use crate::util::search_result::{OrderedCounts, ScoredNamedFieldDocument, SearchResult};
 use crate::util::signal::sigterm_channel;
 
+const VERSION_FIELD_NAME: &str = "_version";
+const MORE_LIKE_THIS_MAX_QUERY_TERMS: usize = 25;
+const DID_YOU_MEAN_MAX_HITS: i64 = 5;
+const APPLY_TIMEOUT: Duration = Duration::from_millis(1000);
+const COMMIT_APPLY_TIMEOUT: Duration = Duration::from_secs(60);
+
 struct NotifyArgs(u64, String, RespErr);
 
+// The latest write of a document. Only the stored fields are kept, as they are what can be read
+// back from the index.
+#[derive(Clone)]
+struct VersionedDoc {
+    version: u64,
+    doc: Document,
+}
+
 #[derive(Clone)]
 pub struct IndexServer {
     id: u64,
@@ -40,8 +86,14 @@ pub struct IndexServer {
     rf_message_ch: SyncSender<PeerMessage>,
     notify_ch_map: Arc<Mutex<HashMap<u64, SyncSender<NotifyArgs>>>>,
     index: Arc<Index>,
+    index_reader: IndexReader,
     unique_key_field_name: String,
     index_writer: Arc<Mutex<IndexWriter>>,
+    pending: Arc<Mutex<HashMap<String, Option<VersionedDoc>>>>,
+    search_timeout: Duration,
+    search_cache: Option<Arc<Mutex<SearchCache>>>,
+    default_fields: Vec<(Field, f32)>,
+    spelling_corrector: Arc<SpellingCorrector>,
     metrics: Arc<Mutex<Metrics>>,
 }
 
@@ -54,6 +106,10 @@ impl IndexServer {
         data_directory: &str,
         schema_file: &str,
         unique_key_field_name: &str,
+        analyzer_file: &str,
+        search_timeout: u64,
+        search_cache_size: usize,
+        default_fields: &str,
     ) {
         let mut peers = HashMap::new();
         peers.insert(id, create_client(&format!("{}:{}", host, port)));
@@ -71,10 +127,48 @@ impl IndexServer {
             let schema_content = fs::read_to_string(schema_file).unwrap();
             let schema: Schema =
                 serde_json::from_str(&schema_content).expect("error while reading json");
+            let schema = Self::add_version_field(add_point_fields(schema));
             fs::create_dir_all(&index_path).unwrap_or_default();
             Index::create_in_dir(index_path.to_str().unwrap(), schema).unwrap()
         };
 
+        // Analyzers are not persisted by tantivy, so they are registered every time the index
+        // opens. The analyzer file is copied into the index directory to be used when no file is
+        // given.
+        register_geo_point_tokenizer(&index);
+        let index_analyzer_file = index_path.join(ANALYZER_FILE_NAME);
+        if !analyzer_file.is_empty() {
+            register_analyzers(&index, analyzer_file).unwrap_or_else(|e| {
+                panic!("register analyzers error: {}", e);
+            });
+            fs::copy(analyzer_file, &index_analyzer_file).unwrap_or_else(|e| {
+                panic!("copy analyzer file error: {}", e);
+            });
+        } else if index_analyzer_file.exists() {
+            register_analyzers(&index, index_analyzer_file.to_str().unwrap()).unwrap_or_else(|e| {
+                panic!("register analyzers error: {}", e);
+            });
+        }
+        check_tokenizers(&index).unwrap_or_else(|e| {
+            panic!("check tokenizers error: {}", e);
+        });
+        Self::check_version_field(&index.schema()).unwrap_or_else(|e| {
+            panic!("check version field error: {}", e);
+        });
+
+        let default_fields = Self::parse_default_fields(&index.schema(), default_fields)
+            .unwrap_or_else(|e| {
+                panic!("parse default fields error: {}", e);
+            });
+
+        // Searches see the index as of the last commit. The applier reloads the reader whenever
+        // the committed segments change.
+        let index_reader = index
+            .reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .unwrap();
+
         let num_threads = 1;
         let buffer_size_per_thread = 50_000_000;
         let index_writer = if num_threads > 0 {
@@ -98,8 +192,18 @@ impl IndexServer {
             rf_message_ch: rf_sender,
             notify_ch_map: Arc::new(Mutex::new(HashMap::new())),
             index: Arc::new(index),
+            index_reader,
             unique_key_field_name: unique_key_field_name.to_string(),
             index_writer: Arc::new(Mutex::new(index_writer)),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            search_timeout: Duration::from_millis(search_timeout),
+            search_cache: if search_cache_size > 0 {
+                Some(Arc::new(Mutex::new(SearchCache::new(search_cache_size))))
+            } else {
+                None
+            },
+            default_fields,
+            spelling_corrector: Arc::new(SpellingCorrector::new()),
             metrics: Arc::new(Mutex::new(Metrics::new(id))),
         };
 
@@ -146,6 +250,38 @@ impl IndexServer {
         }
     }
 
+    fn add_version_field(schema: Schema) -> Schema {
+        if schema.get_field(VERSION_FIELD_NAME).is_some() {
+            return schema;
+        }
+
+        let mut schema_builder = Schema::builder();
+        for (_field, field_entry) in schema.fields() {
+            schema_builder.add_field(field_entry.clone());
+        }
+        schema_builder.add_u64_field(VERSION_FIELD_NAME, STORED | FAST);
+        schema_builder.build()
+    }
+
+    // The version field is only added when the index is created, so an index created before
+    // versions were introduced cannot check `if_version` and has to be rebuilt.
+    fn check_version_field(schema: &Schema) -> Result<(), String> {
+        let field = schema.get_field(VERSION_FIELD_NAME).ok_or_else(|| {
+            format!(
+                "{} field not found: the index was created without document versions and must be \
+                 rebuilt in a new data directory",
+                VERSION_FIELD_NAME
+            )
+        })?;
+        match schema.get_field_entry(field).field_type() {
+            FieldType::U64(options) if options.is_stored() && options.is_fast() => Ok(()),
+            _ => Err(format!(
+                "{} field must be a stored u64 fast field",
+                VERSION_FIELD_NAME
+            )),
+        }
+    }
+
     fn async_rpc_sender(&mut self, receiver: Receiver<RaftMessage>) {
         let l = self.peers.clone();
         thread::spawn(move || loop {
@@ -183,7 +319,12 @@ impl IndexServer {
             .unwrap_or_else(|e| {
                 error!("send propose to raft error: {}", e);
             });
-        match rh.recv_timeout(Duration::from_millis(1000)) {
+        // a commit waits for the pending writes, so it is given longer than the other operations
+        let timeout = match req.get_req_type() {
+            ReqType::DeleteByQuery | ReqType::Commit | ReqType::Merge => COMMIT_APPLY_TIMEOUT,
+            _ => APPLY_TIMEOUT,
+        };
+        match rh.recv_timeout(timeout) {
             Ok(args) => {
                 return (args.2, args.1);
             }
@@ -197,14 +338,484 @@ impl IndexServer {
         }
     }
 
+    fn text_fields(schema: &Schema) -> Vec<Field> {
+        schema
+            .fields()
+            .flat_map(|(field, field_entry)| {
+                if let FieldType::Str(text_field_options) = field_entry.field_type() {
+                    if text_field_options.get_indexing_options().is_some()
+                        && !is_geo_point_field(field_entry)
+                    {
+                        return Some(field);
+                    }
+                }
+                None
+            })
+            .collect()
+    }
+
+    // Uses every indexed text field with no boost if no default field is given.
+    fn create_query_parser(index: &Index, default_fields: &[(Field, f32)]) -> QueryParser {
+        if default_fields.is_empty() {
+            return QueryParser::for_index(index, Self::text_fields(&index.schema()));
+        }
+
+        let mut query_parser = QueryParser::for_index(
+            index,
+            default_fields.iter().map(|(field, _boost)| *field).collect(),
+        );
+        for (field, boost) in default_fields {
+            // the parser wraps the queries on fields with a boost other than 1
+            if (*boost - 1.0).abs() > f32::EPSILON {
+                query_parser.set_field_boost(*field, *boost);
+            }
+        }
+        query_parser
+    }
+
+    // Parses default fields with boosts such as "title^3 description", separated by spaces or
+    // commas. The boost defaults to 1.
+    fn parse_default_fields(schema: &Schema, spec: &str) -> Result<Vec<(Field, f32)>, String> {
+        let mut default_fields = Vec::new();
+        for field_spec in spec.split(|c: char| c == ',' || c.is_whitespace()) {
+            if field_spec.is_empty() {
+                continue;
+            }
+            let mut parts = field_spec.splitn(2, '^');
+            let field_name = parts.next().unwrap_or("");
+            let boost = match parts.next() {
+                Some(boost) => boost
+                    .parse::<f32>()
+                    .map_err(|e| format!("invalid boost: {}: {}", field_spec, e))?,
+                None => 1.0,
+            };
+            let field = schema
+                .get_field(field_name)
+                .ok_or_else(|| format!("unknown field: {}", field_name))?;
+            if !schema.get_field_entry(field).is_indexed() {
+                return Err(format!("not an indexed field: {}", field_name));
+            }
+            default_fields.push((field, boost));
+        }
+        Ok(default_fields)
+    }
+
+    // Picks the terms with the highest tf-idf from the source document or text. BM25 weighs the
+    // selected terms by their idf again when the boolean query is scored.
+    fn more_like_this_query(
+        index: &Index,
+        unique_key_field_name: &str,
+        searcher: &Searcher,
+        doc_id: &str,
+        text: &str,
+    ) -> Result<BooleanQuery, (RespErr, String)> {
+        let schema = index.schema();
+        let unique_key_field = schema.get_field(unique_key_field_name).unwrap();
+        let text_fields: Vec<Field> = Self::text_fields(&schema)
+            .into_iter()
+            .filter(|field| *field != unique_key_field)
+            .collect();
+
+        let mut texts: Vec<(Field, String)> = Vec::new();
+        if !doc_id.is_empty() {
+            let doc = Self::get_document(index, unique_key_field_name, searcher, doc_id)
+                .map_err(Self::internal_error)?
+                .ok_or_else(|| (RespErr::ErrNoKey, format!("document not found: {}", doc_id)))?;
+            for field in &text_fields {
+                for value in doc.get_all(*field) {
+                    if let FieldValue::Str(s) = value {
+                        texts.push((*field, s.clone()));
+                    }
+                }
+            }
+        }
+        if !text.is_empty() {
+            for field in &text_fields {
+                texts.push((*field, text.to_string()));
+            }
+        }
+
+        let mut term_freqs: HashMap<Term, u64> = HashMap::new();
+        for (field, text) in texts {
+            let tokenizer = index
+                .tokenizer_for_field(field)
+                .map_err(Self::internal_error)?;
+            let mut token_stream = tokenizer.token_stream(&text);
+            while token_stream.advance() {
+                let term = Term::from_field_text(field, &token_stream.token().text);
+                *term_freqs.entry(term).or_insert(0) += 1;
+            }
+        }
+
+        let num_docs = searcher.num_docs() as f32;
+        let mut scored_terms: Vec<(f32, Term)> = term_freqs
+            .into_iter()
+            .flat_map(|(term, term_freq)| {
+                let doc_freq = searcher.doc_freq(&term);
+                if doc_freq == 0 {
+                    return None;
+                }
+                let idf = 1.0 + (num_docs / (doc_freq as f32 + 1.0)).ln();
+                Some((term_freq as f32 * idf, term))
+            })
+            .collect();
+        scored_terms.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+        scored_terms.truncate(MORE_LIKE_THIS_MAX_QUERY_TERMS);
+
+        let mut subqueries: Vec<(Occur, Box<dyn Query>)> = scored_terms
+            .into_iter()
+            .map(|(_score, term)| {
+                let tq: Box<dyn Query> =
+                    Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs));
+                (Occur::Should, tq)
+            })
+            .collect();
+        if !doc_id.is_empty() {
+            let t = Term::from_field_text(unique_key_field, doc_id);
+            subqueries.push((
+                Occur::MustNot,
+                Box::new(TermQuery::new(t, IndexRecordOption::Basic)),
+            ));
+        }
+        Ok(BooleanQuery::from(subqueries))
+    }
+
+    fn get_document(
+        index: &Index,
+        unique_key_field_name: &str,
+        searcher: &Searcher,
+        doc_id: &str,
+    ) -> tantivy::Result<Option<Document>> {
+        let t = Term::from_field_text(
+            index.schema().get_field(unique_key_field_name).unwrap(),
+            doc_id,
+        );
+        let tq = TermQuery::new(t, IndexRecordOption::Basic);
+        let top_docs = searcher.search(&tq, &TopDocs::with_limit(1))?;
+        match top_docs.first() {
+            Some((_score, doc_address)) => Ok(Some(searcher.doc(*doc_address)?)),
+            None => Ok(None),
+        }
+    }
+
+    // The lower bound is inclusive and the upper bound is exclusive, so that rounded bounds such
+    // as "now-1d/d" and "now/d" cover whole days.
+    fn date_range_query(
+        schema: &Schema,
+        field_name: &str,
+        from: &str,
+        to: &str,
+        time_zone: &str,
+    ) -> Result<RangeQuery, String> {
+        let field = schema
+            .get_field(field_name)
+            .ok_or_else(|| format!("unknown field: {}", field_name))?;
+        let tz = parse_time_zone(time_zone)?;
+        let now = Utc::now();
+        let to_term = |expr: &str| -> Result<Term, String> {
+            Ok(Term::from_field_date(field, &parse_date_math(expr, now, &tz)?))
+        };
+
+        let lower_bound = if from.is_empty() {
+            Bound::Unbounded
+        } else {
+            Bound::Included(to_term(from)?)
+        };
+        let upper_bound = if to.is_empty() {
+            Bound::Unbounded
+        } else {
+            Bound::Excluded(to_term(to)?)
+        };
+        Ok(RangeQuery::new_term_bounds(
+            field,
+            Type::Date,
+            &lower_bound,
+            &upper_bound,
+        ))
+    }
+
+    fn search_with_filter<C: Collector>(
+        searcher: &Searcher,
+        query: &dyn Query,
+        collector: &C,
+        geo_filter: &Option<GeoFilter>,
+        budget: &Arc<SearchBudget>,
+    ) -> tantivy::Result<C::Fruit> {
+        let collector = BudgetCollector::new(budget, collector);
+        match geo_filter {
+            Some(filter) if !filter.is_empty() => {
+                let collector = GeoFilterCollector {
+                    filter,
+                    collector: &collector,
+                };
+                let query = BooleanQuery::from(vec![
+                    (Occur::Must, query.box_clone()),
+                    (Occur::Must, filter.cell_query()),
+                ]);
+                search_with_budget(searcher, &query, &collector, budget)
+            }
+            _ => search_with_budget(searcher, query, &collector, budget),
+        }
+    }
+
+    // Walks every document matched by the query, with no limit on their number.
+    fn matched_doc_ids(
+        searcher: &Searcher,
+        query: &dyn Query,
+        unique_key_field: Field,
+    ) -> tantivy::Result<Vec<String>> {
+        let weight = query.weight(searcher, false)?;
+        let mut doc_ids = Vec::new();
+        for segment_reader in searcher.segment_readers() {
+            let store_reader = segment_reader.get_store_reader();
+            let mut scorer = weight.scorer(segment_reader)?;
+            while scorer.advance() {
+                let doc = scorer.doc();
+                if segment_reader.is_deleted(doc) {
+                    continue;
+                }
+                let stored_doc = store_reader.get(doc)?;
+                if let Some(doc_id) = stored_doc
+                    .get_first(unique_key_field)
+                    .and_then(|v| v.text())
+                {
+                    doc_ids.push(doc_id.to_string());
+                }
+            }
+        }
+        Ok(doc_ids)
+    }
+
+    // Every document is written with a version, so a missing one is an error rather than 0.
+    fn document_version(index: &Index, doc: &Document, doc_id: &str) -> tantivy::Result<u64> {
+        let missing = || TantivyError::SchemaError(format!("document has no version: {}", doc_id));
+        let field = index
+            .schema()
+            .get_field(VERSION_FIELD_NAME)
+            .ok_or_else(missing)?;
+        match doc.get_first(field) {
+            Some(FieldValue::U64(version)) => Ok(*version),
+            _ => Err(missing()),
+        }
+    }
+
+    // Uncommitted writes are not visible to the searcher, so they are kept in `pending` until
+    // the next commit or rollback. A pending delete is None.
+    fn current_version(
+        index: &Index,
+        index_reader: &IndexReader,
+        unique_key_field_name: &str,
+        pending: &HashMap<String, Option<VersionedDoc>>,
+        doc_id: &str,
+    ) -> tantivy::Result<Option<u64>> {
+        if let Some(pending_doc) = pending.get(doc_id) {
+            return Ok(pending_doc.as_ref().map(|pending_doc| pending_doc.version));
+        }
+        let searcher = index_reader.searcher();
+        match Self::get_document(index, unique_key_field_name, &searcher, doc_id)? {
+            Some(doc) => Ok(Some(Self::document_version(index, &doc, doc_id)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn current_document(
+        index: &Index,
+        index_reader: &IndexReader,
+        unique_key_field_name: &str,
+        pending: &HashMap<String, Option<VersionedDoc>>,
+        doc_id: &str,
+    ) -> tantivy::Result<Option<VersionedDoc>> {
+        if let Some(pending_doc) = pending.get(doc_id) {
+            return Ok(pending_doc.clone());
+        }
+        let searcher = index_reader.searcher();
+        match Self::get_document(index, unique_key_field_name, &searcher, doc_id)? {
+            Some(doc) => Ok(Some(VersionedDoc {
+                version: Self::document_version(index, &doc, doc_id)?,
+                doc,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    // Every replica applies the same entry, so a failure to apply it is not retried by the client.
+    fn index_error(term: u64, e: TantivyError) -> NotifyArgs {
+        error!("index error: {:?}", e);
+
+        let (err, message) = Self::internal_error(e);
+        NotifyArgs(term, Self::error_value(err, &message), err)
+    }
+
+    fn not_found(term: u64, doc_id: &str) -> NotifyArgs {
+        let mut ret = HashMap::new();
+        ret.insert("error", format!("document not found: {}", doc_id));
+
+        NotifyArgs(term, serde_json::to_string(&ret).unwrap(), RespErr::ErrNoKey)
+    }
+
+    fn version_conflict(term: u64, doc_id: &str, current_version: Option<u64>) -> NotifyArgs {
+        let ret = json!({
+            "error": format!("version conflict: {}", doc_id),
+            "version": current_version,
+        });
+
+        NotifyArgs(term, ret.to_string(), RespErr::ErrConflict)
+    }
+
+    fn add_document(
+        index: &Index,
+        unique_key_field_name: &str,
+        index_writer: &Mutex<IndexWriter>,
+        pending: &mut HashMap<String, Option<VersionedDoc>>,
+        doc_id: &str,
+        mut doc: Document,
+        version: u64,
+    ) -> String {
+        let schema = index.schema();
+        let field = schema.get_field(unique_key_field_name).unwrap();
+        doc.add_text(field, doc_id);
+        if let Some(version_field) = schema.get_field(VERSION_FIELD_NAME) {
+            doc.filter_fields(|f| f != version_field);
+            doc.add_u64(version_field, version);
+        }
+        add_point_values(&schema, &mut doc);
+        index_writer
+            .lock()
+            .unwrap()
+            .delete_term(Term::from_field_text(field, doc_id));
+        let mut stored_doc = doc.clone();
+        stored_doc.filter_fields(|f| schema.get_field_entry(f).is_stored());
+        let opstamp = index_writer.lock().unwrap().add_document(doc);
+        pending.insert(
+            doc_id.to_string(),
+            Some(VersionedDoc {
+                version,
+                doc: stored_doc,
+            }),
+        );
+
+        let mut ret = HashMap::new();
+        ret.insert("opstamp", opstamp);
+        ret.insert("version", version);
+
+        serde_json::to_string(&ret).unwrap()
+    }
+
+    fn to_values(value: Value) -> Vec<Value> {
+        match value {
+            Value::Array(values) => values,
+            value => vec![value],
+        }
+    }
+
+    fn update_fields(
+        fields: &mut Map<String, Value>,
+        update_req: &UpdateReq,
+    ) -> Result<(), String> {
+        if !update_req.get_set().is_empty() {
+            let set: Map<String, Value> =
+                serde_json::from_str(update_req.get_set()).map_err(|e| e.to_string())?;
+            for (name, value) in set {
+                if value.is_null() {
+                    fields.remove(&name);
+                } else {
+                    fields.insert(name, Value::Array(Self::to_values(value)));
+                }
+            }
+        }
+
+        if !update_req.get_append().is_empty() {
+            let append: Map<String, Value> =
+                serde_json::from_str(update_req.get_append()).map_err(|e| e.to_string())?;
+            for (name, value) in append {
+                let mut values = fields
+                    .remove(&name)
+                    .map(Self::to_values)
+                    .unwrap_or_default();
+                values.extend(Self::to_values(value));
+                fields.insert(name, Value::Array(values));
+            }
+        }
+
+        if !update_req.get_remove().is_empty() {
+            let remove: Map<String, Value> =
+                serde_json::from_str(update_req.get_remove()).map_err(|e| e.to_string())?;
+            for (name, value) in remove {
+                if value.is_null() {
+                    fields.remove(&name);
+                    continue;
+                }
+                let removed_values = Self::to_values(value);
+                if let Some(current) = fields.remove(&name) {
+                    let values: Vec<Value> = Self::to_values(current)
+                        .into_iter()
+                        .filter(|v| !removed_values.contains(v))
+                        .collect();
+                    if !values.is_empty() {
+                        fields.insert(name, Value::Array(values));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Rejects the documents and queries that would fail to apply before they are proposed, so
+    // that they are not written to the log.
+    fn validate(index: &Index, req: &ApplyReq) -> Result<(), (RespErr, String)> {
+        let schema = index.schema();
+        match req.req_type {
+            ReqType::Put => {
+                schema
+                    .parse_document(req.get_put_req().get_fields())
+                    .map_err(Self::document_error)?;
+            }
+            ReqType::Update => {
+                let update_req = req.get_update_req();
+                let invalid = |e: serde_json::Error| {
+                    (
+                        RespErr::ErrInvalidDocument,
+                        format!("invalid document: {}", e),
+                    )
+                };
+                // the values to be set or appended must be valid for their fields
+                let mut fields = Map::new();
+                for update in &[update_req.get_set(), update_req.get_append()] {
+                    if !update.is_empty() {
+                        let values: Map<String, Value> =
+                            serde_json::from_str(update).map_err(invalid)?;
+                        fields.extend(values.into_iter().filter(|(_name, value)| !value.is_null()));
+                    }
+                }
+                if !update_req.get_remove().is_empty() {
+                    serde_json::from_str::<Map<String, Value>>(update_req.get_remove())
+                        .map_err(invalid)?;
+                }
+                schema
+                    .parse_document(&serde_json::to_string(&fields).unwrap())
+                    .map_err(Self::document_error)?;
+            }
+            ReqType::DeleteByQuery => {
+                Self::create_query_parser(index, &[])
+                    .parse_query(req.get_delete_by_query_req().get_query())
+                    .map_err(Self::query_parser_error)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     // TODO: check duplicate request.
     fn async_applier(&mut self, apply_receiver: Receiver<Entry>) {
         let notify_ch_map = self.notify_ch_map.clone();
         let peers = self.peers.clone();
         let peers_addr = self.peers_addr.clone();
         let index = self.index.clone();
+        let index_reader = self.index_reader.clone();
         let unique_key_field_name = self.unique_key_field_name.clone();
         let index_writer = self.index_writer.clone();
+        let pending = self.pending.clone();
         let metrics = self.metrics.clone();
 
         thread::spawn(move || loop {
@@ -221,8 +832,10 @@ impl IndexServer {
                                 peers.clone(),
                                 peers_addr.clone(),
                                 index.clone(),
+                                &index_reader,
                                 unique_key_field_name.as_str(),
                                 index_writer.clone(),
+                                pending.clone(),
                                 metrics.clone(),
                             );
                             debug!("{:?}: {:?}", result.2, req);
@@ -261,8 +874,10 @@ impl IndexServer {
         peers: Arc<Mutex<HashMap<u64, IndexClient>>>,
         peers_addr: Arc<Mutex<HashMap<u64, String>>>,
         index: Arc<Index>,
+        index_reader: &IndexReader,
         unique_key_field_name: &str,
         index_writer: Arc<Mutex<IndexWriter>>,
+        pending: Arc<Mutex<HashMap<String, Option<VersionedDoc>>>>,
         metrics: Arc<Mutex<Metrics>>,
     ) -> NotifyArgs {
         debug!("{:?}", &req);
@@ -297,45 +912,205 @@ impl IndexServer {
             ReqType::Put => {
                 metrics.lock().unwrap().inc_request_count("put");
 
-                let mut doc = index
-                    .schema()
-                    .parse_document(req.get_put_req().get_fields())
-                    .unwrap();
-                let field = index.schema().get_field(unique_key_field_name).unwrap();
-                doc.add_text(field, req.get_put_req().get_doc_id());
-                index_writer
-                    .lock()
-                    .unwrap()
-                    .delete_term(Term::from_field_text(field, req.get_put_req().get_doc_id()));
-                let opstamp = index_writer.lock().unwrap().add_document(doc);
+                let put_req = req.get_put_req();
+                let doc_id = put_req.get_doc_id();
+                let mut pending = pending.lock().unwrap();
+                let current_version = match Self::current_version(
+                    &index,
+                    index_reader,
+                    unique_key_field_name,
+                    &pending,
+                    doc_id,
+                ) {
+                    Ok(current_version) => current_version,
+                    Err(e) => return Self::index_error(term, e),
+                };
+                if put_req.get_if_absent() && current_version.is_some() {
+                    return Self::version_conflict(term, doc_id, current_version);
+                }
+                if put_req.get_if_version() > 0 && current_version != Some(put_req.get_if_version()) {
+                    return Self::version_conflict(term, doc_id, current_version);
+                }
+                let version = if put_req.get_version() > 0 {
+                    // external version must be greater than the current one
+                    if current_version.map_or(false, |v| put_req.get_version() <= v) {
+                        return Self::version_conflict(term, doc_id, current_version);
+                    }
+                    put_req.get_version()
+                } else {
+                    current_version.unwrap_or(0) + 1
+                };
 
-                let mut ret = HashMap::new();
-                ret.insert("opstamp", opstamp);
+                let doc = match index.schema().parse_document(put_req.get_fields()) {
+                    Ok(doc) => doc,
+                    Err(e) => {
+                        let (err, message) = Self::document_error(e);
+                        return NotifyArgs(term, Self::error_value(err, &message), err);
+                    }
+                };
+                let ret = Self::add_document(
+                    &index,
+                    unique_key_field_name,
+                    &index_writer,
+                    &mut pending,
+                    doc_id,
+                    doc,
+                    version,
+                );
 
-                NotifyArgs(term, serde_json::to_string(&ret).unwrap(), RespErr::OK)
+                NotifyArgs(term, ret, RespErr::OK)
+            }
+            ReqType::Update => {
+                metrics.lock().unwrap().inc_request_count("update");
+
+                let schema = index.schema();
+                let doc_id = req.get_update_req().get_doc_id();
+
+                // The update applies to the latest write, which may not be committed yet.
+                let mut pending = pending.lock().unwrap();
+                let current_doc = match Self::current_document(
+                    &index,
+                    index_reader,
+                    unique_key_field_name,
+                    &pending,
+                    doc_id,
+                ) {
+                    Ok(Some(current_doc)) => current_doc,
+                    Ok(None) => return Self::not_found(term, doc_id),
+                    Err(e) => return Self::index_error(term, e),
+                };
+
+                let mut fields: Map<String, Value> =
+                    serde_json::from_str(&schema.to_json(&current_doc.doc)).unwrap();
+                if let Err(e) = Self::update_fields(&mut fields, req.get_update_req()) {
+                    let message = format!("invalid document: {}", e);
+                    return NotifyArgs(
+                        term,
+                        Self::error_value(RespErr::ErrInvalidDocument, &message),
+                        RespErr::ErrInvalidDocument,
+                    );
+                }
+                fields.remove(unique_key_field_name);
+                fields.remove(VERSION_FIELD_NAME);
+
+                let doc = match schema.parse_document(&serde_json::to_string(&fields).unwrap()) {
+                    Ok(doc) => doc,
+                    Err(e) => {
+                        let (err, message) = Self::document_error(e);
+                        return NotifyArgs(term, Self::error_value(err, &message), err);
+                    }
+                };
+                let version = current_doc.version + 1;
+                let ret = Self::add_document(
+                    &index,
+                    unique_key_field_name,
+                    &index_writer,
+                    &mut pending,
+                    doc_id,
+                    doc,
+                    version,
+                );
+
+                NotifyArgs(term, ret, RespErr::OK)
             }
             ReqType::Delete => {
                 metrics.lock().unwrap().inc_request_count("delete");
 
+                let delete_req = req.get_delete_req();
+                let doc_id = delete_req.get_doc_id();
+                let mut pending = pending.lock().unwrap();
+                if delete_req.get_if_version() > 0 {
+                    let current_version = match Self::current_version(
+                        &index,
+                        index_reader,
+                        unique_key_field_name,
+                        &pending,
+                        doc_id,
+                    ) {
+                        Ok(current_version) => current_version,
+                        Err(e) => return Self::index_error(term, e),
+                    };
+                    if current_version != Some(delete_req.get_if_version()) {
+                        return Self::version_conflict(term, doc_id, current_version);
+                    }
+                }
+
                 let opstamp = index_writer
                     .lock()
                     .unwrap()
                     .delete_term(Term::from_field_text(
                         index.schema().get_field(unique_key_field_name).unwrap(),
-                        req.get_delete_req().get_doc_id(),
+                        doc_id,
                     ));
+                pending.insert(doc_id.to_string(), None);
 
                 let mut ret = HashMap::new();
                 ret.insert("opstamp", opstamp);
 
                 NotifyArgs(term, serde_json::to_string(&ret).unwrap(), RespErr::OK)
             }
+            ReqType::DeleteByQuery => {
+                metrics.lock().unwrap().inc_request_count("delete_by_query");
+
+                let schema = index.schema();
+                let field = schema.get_field(unique_key_field_name).unwrap();
+                // The default fields of the servers may differ, so every replica parses the
+                // query over all text fields to delete the same documents.
+                let query = match Self::create_query_parser(&index, &[])
+                    .parse_query(req.get_delete_by_query_req().get_query())
+                {
+                    Ok(query) => query,
+                    Err(e) => {
+                        let (err, message) = Self::query_parser_error(e);
+                        return NotifyArgs(term, Self::error_value(err, &message), err);
+                    }
+                };
+
+                // Uncommitted writes are not visible to the searcher, so they are committed before
+                // the query is evaluated. Otherwise the uncommitted documents would not be
+                // considered, and deleting the ids of the matched documents would also delete
+                // their newer versions. Every replica commits at the same entry of the log, so the
+                // same documents are deleted on each of them.
+                let mut index_writer = index_writer.lock().unwrap();
+                let mut pending = pending.lock().unwrap();
+                if let Err(e) = index_writer.commit() {
+                    error!("commit failed: {:?}", e);
+                    return Self::index_error(term, e);
+                }
+                pending.clear();
+                if let Err(e) = index_reader.reload() {
+                    return Self::index_error(term, e);
+                }
+
+                let searcher = index_reader.searcher();
+                match Self::matched_doc_ids(&searcher, query.as_ref(), field) {
+                    Ok(doc_ids) => {
+                        let mut opstamp = 0;
+                        for doc_id in &doc_ids {
+                            opstamp =
+                                index_writer.delete_term(Term::from_field_text(field, doc_id));
+                            pending.insert(doc_id.to_string(), None);
+                        }
+
+                        let mut ret = HashMap::new();
+                        ret.insert("opstamp", opstamp);
+                        ret.insert("deleted", doc_ids.len() as u64);
+
+                        NotifyArgs(term, serde_json::to_string(&ret).unwrap(), RespErr::OK)
+                    }
+                    Err(e) => Self::index_error(term, e),
+                }
+            }
             ReqType::Commit => {
                 metrics.lock().unwrap().inc_request_count("commit");
 
                 match index_writer.lock().unwrap().commit() {
                     Ok(opstamp) => {
                         info!("commit succeeded");
+                        pending.lock().unwrap().clear();
+                        if let Err(e) = index_reader.reload() {
+                            return Self::index_error(term, e);
+                        }
 
                         let mut ret = HashMap::new();
                         ret.insert("opstamp", opstamp);
@@ -347,14 +1122,7 @@ impl IndexServer {
 
                         // TODO: rollback index
 
-                        let mut ret = HashMap::new();
-                        ret.insert("error", format!("{:?}", e));
-
-                        NotifyArgs(
-                            term,
-                            serde_json::to_string(&ret).unwrap(),
-                            RespErr::ErrWrongLeader,
-                        )
+                        Self::index_error(term, e)
                     }
                 }
             }
@@ -364,6 +1132,7 @@ impl IndexServer {
                 match index_writer.lock().unwrap().rollback() {
                     Ok(opstamp) => {
                         info!("rollback succeed");
+                        pending.lock().unwrap().clear();
 
                         let mut ret = HashMap::new();
                         ret.insert("opstamp", opstamp);
@@ -373,14 +1142,7 @@ impl IndexServer {
                     Err(e) => {
                         error!("rollback failed: {:?}", e);
 
-                        let mut ret = HashMap::new();
-                        ret.insert("error", format!("{:?}", e));
-
-                        NotifyArgs(
-                            term,
-                            serde_json::to_string(&ret).unwrap(),
-                            RespErr::ErrWrongLeader,
-                        )
+                        Self::index_error(term, e)
                     }
                 }
             }
@@ -403,6 +1165,9 @@ impl IndexServer {
                 {
                     Ok(segment_meta) => {
                         info!("merge succeed: {:?}", segment_meta);
+                        if let Err(e) = index_reader.reload() {
+                            return Self::index_error(term, e);
+                        }
 
                         let mut ret = HashMap::new();
                         ret.insert("segment_meta", segment_meta);
@@ -411,18 +1176,625 @@ impl IndexServer {
                     Err(e) => {
                         error!("merge failed: {:?}", e);
 
-                        let mut ret = HashMap::new();
-                        ret.insert("error", format!("{:?}", e));
+                        Self::index_error(term, e)
+                    }
+                }
+            }
+        }
+    }
+
+    // Errors in user input are returned to the client with a type instead of failing the request.
+    fn invalid_query(message: String) -> (RespErr, String) {
+        if message.starts_with("unknown field") {
+            (RespErr::ErrUnknownField, message)
+        } else {
+            (RespErr::ErrInvalidQuery, message)
+        }
+    }
+
+    fn query_parser_error(e: QueryParserError) -> (RespErr, String) {
+        match e {
+            QueryParserError::FieldDoesNotExist(field_name) => (
+                RespErr::ErrUnknownField,
+                format!("unknown field: {}", field_name),
+            ),
+            e => (RespErr::ErrInvalidQuery, format!("invalid query: {:?}", e)),
+        }
+    }
 
-                        NotifyArgs(
-                            term,
-                            serde_json::to_string(&ret).unwrap(),
-                            RespErr::ErrWrongLeader,
+    fn document_error(e: DocParsingError) -> (RespErr, String) {
+        match e {
+            DocParsingError::NotJSON(message) => (
+                RespErr::ErrInvalidDocument,
+                format!("invalid document: {}", message),
+            ),
+            DocParsingError::NoSuchFieldInSchema(field_name) => (
+                RespErr::ErrUnknownField,
+                format!("unknown field: {}", field_name),
+            ),
+            DocParsingError::ValueError(field_name, e) => (
+                RespErr::ErrSchemaMismatch,
+                format!("invalid value for {}: {:?}", field_name, e),
+            ),
+        }
+    }
+
+    fn internal_error(e: TantivyError) -> (RespErr, String) {
+        (RespErr::ErrInternal, format!("internal error: {:?}", e))
+    }
+
+    fn error_value(err: RespErr, message: &str) -> String {
+        let error_type = match err {
+            RespErr::ErrNoKey => "not_found",
+            RespErr::ErrInvalidQuery => "invalid_query",
+            RespErr::ErrInvalidDocument => "invalid_document",
+            RespErr::ErrUnknownField => "unknown_field",
+            RespErr::ErrSchemaMismatch => "schema_mismatch",
+            RespErr::ErrInternal => "internal",
+            _ => "error",
+        };
+        serde_json::to_string(&json!({ "error": message, "type": error_type })).unwrap()
+    }
+
+    fn search_result(
+        &self,
+        req: &SearchReq,
+        searcher: &Searcher,
+    ) -> Result<SearchResult, (RespErr, String)> {
+        let timeout = if req.get_timeout() > 0 {
+            Duration::from_millis(req.get_timeout())
+        } else {
+            self.search_timeout
+        };
+        let budget = Arc::new(SearchBudget::new(timeout, req.get_terminate_after()));
+
+        let schema = self.index.schema();
+
+        let limit = req.get_from() + req.get_limit();
+
+        let default_fields = if req.get_default_fields().is_empty() {
+            self.default_fields.clone()
+        } else {
+            Self::parse_default_fields(&schema, req.get_default_fields())
+                .map_err(Self::invalid_query)?
+        };
+        let query_parser = Self::create_query_parser(&self.index, &default_fields);
+        // the query string, the query DSL and more like this must all match
+        let mut subqueries: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+        if !req.query.is_empty() {
+            let text_query = if req.get_typo_tolerance() {
+                // The boost queries of the parser hide the terms they wrap, so the query is parsed
+                // without field boosts, which are applied when the terms are rewritten.
+                let unboosted_fields: Vec<(Field, f32)> = default_fields
+                    .iter()
+                    .map(|(field, _boost)| (*field, 1.0))
+                    .collect();
+                let text_query = Self::create_query_parser(&self.index, &unboosted_fields)
+                    .parse_query(req.query.as_str())
+                    .map_err(Self::query_parser_error)?;
+                typo_tolerant_query(&schema, text_query.as_ref(), &default_fields)
+            } else {
+                query_parser
+                    .parse_query(req.query.as_str())
+                    .map_err(Self::query_parser_error)?
+            };
+            subqueries.push((Occur::Must, text_query));
+        }
+        if !req.get_query_dsl().is_empty() {
+            let query_dsl: QueryDsl = serde_json::from_str(req.get_query_dsl())
+                .map_err(|e| Self::invalid_query(format!("invalid query DSL: {}", e)))?;
+            let query_builder =
+                QueryBuilder::new(&self.index, req.get_time_zone()).map_err(Self::invalid_query)?;
+            subqueries.push((
+                Occur::Must,
+                query_builder
+                    .build(&query_dsl)
+                    .map_err(Self::invalid_query)?,
+            ));
+        }
+        if !req.get_more_like_this_doc_id().is_empty() || !req.get_more_like_this_text().is_empty() {
+            let more_like_this_query = Box::new(Self::more_like_this_query(
+                &self.index,
+                &self.unique_key_field_name,
+                searcher,
+                req.get_more_like_this_doc_id(),
+                req.get_more_like_this_text(),
+            )?);
+            subqueries.push((Occur::Must, more_like_this_query as Box<dyn Query>));
+        }
+        let query: Box<dyn Query> = match subqueries.len() {
+            0 => Box::new(AllQuery),
+            1 => subqueries.pop().unwrap().1,
+            _ => Box::new(BooleanQuery::from(subqueries)),
+        };
+        let query: Box<dyn Query> = if req.get_date_field().is_empty() {
+            query
+        } else {
+            let date_range_query = Self::date_range_query(
+                &schema,
+                req.get_date_field(),
+                req.get_date_from(),
+                req.get_date_to(),
+                req.get_time_zone(),
+            )
+            .map_err(Self::invalid_query)?;
+            Box::new(BooleanQuery::from(vec![
+                (Occur::Must, query),
+                (Occur::Must, Box::new(date_range_query) as Box<dyn Query>),
+            ]))
+        };
+        let highlighter = if req.get_highlight().is_empty() || req.get_exclude_docs() {
+            None
+        } else {
+            let setting: HighlightSetting = serde_json::from_str(req.get_highlight())
+                .map_err(|e| Self::invalid_query(format!("invalid highlight: {}", e)))?;
+            Some(
+                Highlighter::new(searcher, &schema, query.as_ref(), &setting)
+                    .map_err(Self::invalid_query)?,
+            )
+        };
+        let post_filter = if req.get_post_filter().is_empty() {
+            None
+        } else {
+            Some(
+                query_parser
+                    .parse_query(req.get_post_filter())
+                    .map_err(Self::query_parser_error)?,
+            )
+        };
+        let geo_origin = if req.get_geo_origin().is_empty() {
+            None
+        } else {
+            Some(parse_point(req.get_geo_origin()).map_err(Self::invalid_query)?)
+        };
+        if !req.get_geo_distance().is_empty() && geo_origin.is_none() {
+            return Err(Self::invalid_query(String::from(
+                "geo_distance requires geo_origin",
+            )));
+        }
+        let geo_filter = if req.get_geo_field().is_empty() {
+            None
+        } else {
+            Some(GeoFilter {
+                field: schema.get_field(req.get_geo_field()).ok_or_else(|| {
+                    Self::invalid_query(format!("unknown field: {}", req.get_geo_field()))
+                })?,
+                point_field: schema
+                    .get_field(&point_field_name(req.get_geo_field()))
+                    .ok_or_else(|| {
+                        Self::invalid_query(format!("unknown field: {}", req.get_geo_field()))
+                    })?,
+                origin: geo_origin,
+                distance: if req.get_geo_distance().is_empty() {
+                    None
+                } else {
+                    Some(parse_distance(req.get_geo_distance()).map_err(Self::invalid_query)?)
+                },
+                bounding_box: if req.get_geo_bounding_box().is_empty() {
+                    None
+                } else {
+                    Some(
+                        parse_bounding_box(req.get_geo_bounding_box())
+                            .map_err(Self::invalid_query)?,
+                    )
+                },
+            })
+        };
+        let sort_by_distance = req.get_geo_sort() && geo_filter.is_some() && geo_origin.is_some();
+        let function_score = if req.get_function_score().is_empty() {
+            None
+        } else {
+            let setting: FunctionScoreSetting = serde_json::from_str(req.get_function_score())
+                .map_err(|e| Self::invalid_query(format!("invalid function score: {}", e)))?;
+            Some(
+                FunctionScore::new(&schema, &setting, req.get_time_zone())
+                    .map_err(Self::invalid_query)?,
+            )
+        };
+        let sort = req
+            .get_sort()
+            .iter()
+            .map(|clause| SortClause::parse(&schema, clause))
+            .collect::<Result<Vec<SortClause>, String>>()
+            .map_err(Self::invalid_query)?;
+        let ranker = DocRanker {
+            sort,
+            distance_origin: if sort_by_distance {
+                Some((geo_filter.as_ref().unwrap().point_field, geo_origin.unwrap()))
+            } else {
+                None
+            },
+            function_score,
+        };
+        let mut multi_collector = MultiCollector::new();
+        // facets are counted against the query without the post filter
+        let mut facet_multi_collector = MultiCollector::new();
+        // the hit count decides whether spelling suggestions are needed
+        let count_handle = if req.get_exclude_count() && !req.get_did_you_mean() {
+            None
+        } else {
+            Some(multi_collector.add_collector(Count))
+        };
+        let collapse = !req.get_collapse_field().is_empty();
+        let top_docs_handle = if req.get_exclude_docs() || collapse || !ranker.is_default() {
+            None
+        } else {
+            Some(multi_collector.add_collector(TopDocs::with_limit(limit as usize)))
+        };
+        let ranked_top_docs_handle = if req.get_exclude_docs() || collapse || ranker.is_default() {
+            None
+        } else {
+            let ranker = ranker.clone();
+            let top_docs = TopDocs::with_limit(limit as usize).tweak_score(
+                move |segment_reader: &SegmentReader| {
+                    let segment_ranker = ranker.segment_ranker(segment_reader);
+                    move |doc: DocId, score: Score| segment_ranker.rank(doc, score)
+                },
+            );
+            Some(multi_collector.add_collector(top_docs))
+        };
+        let collapse_handle = if req.get_exclude_docs() || !collapse {
+            None
+        } else {
+            let collapse_collector =
+                CollapseCollector::new(&schema, req.get_collapse_field(), ranker.clone())
+                    .map_err(Self::invalid_query)?;
+            Some(multi_collector.add_collector(collapse_collector))
+        };
+        // The facet parameters predate aggregations. They are deprecated, and their counts in
+        // `facet` are derived from facet aggregations.
+        let facet_settings = parse_facet_settings(
+            &schema,
+            req.get_facet_field(),
+            req.get_facet_prefixes(),
+            req.get_facets(),
+            req.get_facet_limits(),
+            req.get_facet_sorts(),
+        )
+        .map_err(Self::invalid_query)?;
+        let mut facet_handles = Vec::new();
+        for facet_setting in facet_settings {
+            let facet_collector = facet_setting
+                .collector(&schema)
+                .map_err(Self::invalid_query)?;
+            let facet_handle = if post_filter.is_some() {
+                facet_multi_collector.add_collector(facet_collector.clone())
+            } else {
+                multi_collector.add_collector(facet_collector.clone())
+            };
+            facet_handles.push((facet_setting, facet_collector, facet_handle));
+        }
+        // aggregations are computed against the query without the post filter, like facets
+        let aggregation_settings: BTreeMap<String, AggregationSetting> =
+            if req.get_aggregations().is_empty() {
+                BTreeMap::new()
+            } else {
+                serde_json::from_str(req.get_aggregations())
+                    .map_err(|e| Self::invalid_query(format!("invalid aggregations: {}", e)))?
+            };
+        let aggregation_collector = if aggregation_settings.is_empty() {
+            None
+        } else {
+            Some(
+                AggregationCollector::new(&schema, &aggregation_settings, req.get_time_zone())
+                    .map_err(Self::invalid_query)?,
+            )
+        };
+        let aggregation_handle = match &aggregation_collector {
+            Some(aggregation_collector) if post_filter.is_some() => {
+                Some(facet_multi_collector.add_collector(aggregation_collector.clone()))
+            }
+            Some(aggregation_collector) => {
+                Some(multi_collector.add_collector(aggregation_collector.clone()))
+            }
+            None => None,
+        };
+
+        // search index
+        let mut facet_fruit = None;
+        let mut multi_fruit = match post_filter {
+            Some(post_filter) => {
+                if !facet_handles.is_empty() || aggregation_handle.is_some() {
+                    facet_fruit = Some(
+                        Self::search_with_filter(
+                            searcher,
+                            query.as_ref(),
+                            &facet_multi_collector,
+                            &geo_filter,
+                            &budget,
                         )
+                        .map_err(Self::internal_error)?,
+                    );
+                }
+                let filtered_query = BooleanQuery::from(vec![
+                    (Occur::Must, query.box_clone()),
+                    (Occur::Must, post_filter),
+                ]);
+                Self::search_with_filter(
+                    searcher,
+                    &filtered_query,
+                    &multi_collector,
+                    &geo_filter,
+                    &budget,
+                )
+            }
+            None => Self::search_with_filter(
+                searcher,
+                query.as_ref(),
+                &multi_collector,
+                &geo_filter,
+                &budget,
+            ),
+        }
+        .map_err(Self::internal_error)?;
+
+        // count
+        let mut count: i64 = -1;
+        if let Some(ch) = count_handle {
+            count = ch.extract(&mut multi_fruit) as i64;
+        }
+
+        // docs
+        let mut top_docs: Vec<(Score, DocAddress, Option<u64>, Vec<Value>)> = Vec::new();
+        if let Some(tdh) = top_docs_handle {
+            top_docs = tdh
+                .extract(&mut multi_fruit)
+                .into_iter()
+                .map(|(score, doc_address)| (score, doc_address, None, Vec::new()))
+                .collect();
+        }
+        if let Some(rtdh) = ranked_top_docs_handle {
+            top_docs = rtdh
+                .extract(&mut multi_fruit)
+                .into_iter()
+                .map(|(rank, doc_address)| {
+                    (rank.score, doc_address, None, ranker.sort_values(&rank))
+                })
+                .collect();
+        }
+        if let Some(ch) = collapse_handle {
+            top_docs = CollapseCollector::sorted_groups(ch.extract(&mut multi_fruit))
+                .into_iter()
+                .take(limit as usize)
+                .map(|group| {
+                    let sort_values = ranker.sort_values(&group.rank);
+                    (group.rank.score, group.doc_address, Some(group.size), sort_values)
+                })
+                .collect();
+        }
+
+        // facet
+        let mut facet: HashMap<String, OrderedCounts> = HashMap::new();
+        for (facet_setting, facet_collector, facet_handle) in facet_handles {
+            let states = match facet_fruit.as_mut() {
+                Some(facet_fruit) => facet_handle.extract(facet_fruit),
+                None => facet_handle.extract(&mut multi_fruit),
+            };
+            let results = facet_collector
+                .results(states)
+                .map_err(Self::invalid_query)?;
+            let counts = facet_setting.counts(&results);
+            for (facet_key, facet_value) in &counts {
+                debug!("{:?}={}", facet_key, facet_value);
+            }
+            facet.insert(facet_setting.field_name, OrderedCounts(counts));
+        }
+
+        // aggregations
+        let mut aggregations = HashMap::new();
+        if let (Some(aggregation_collector), Some(aggregation_handle)) =
+            (&aggregation_collector, aggregation_handle)
+        {
+            let states = match facet_fruit.as_mut() {
+                Some(facet_fruit) => aggregation_handle.extract(facet_fruit),
+                None => aggregation_handle.extract(&mut multi_fruit),
+            };
+            aggregations = aggregation_collector
+                .results(states)
+                .map_err(Self::invalid_query)?;
+        }
+
+        let mut docs: Vec<ScoredNamedFieldDocument> = Vec::new();
+        let mut doc_pos: u64 = 0;
+        for (score, doc_address, group_size, sort) in top_docs {
+            if doc_pos >= req.get_from() {
+                let doc = searcher.doc(doc_address).map_err(Self::internal_error)?;
+                let named_doc = schema.to_named_doc(&doc);
+                let highlight = match &highlighter {
+                    Some(highlighter) => highlighter.highlight(&doc),
+                    None => HashMap::new(),
+                };
+                debug!(
+                    "score: {:?} doc: {:?}",
+                    score,
+                    serde_json::to_string(&named_doc).unwrap()
+                );
+
+                let distance = match (&geo_filter, geo_origin) {
+                    (Some(filter), Some(origin)) => {
+                        doc_distance(searcher, filter.point_field, doc_address, origin)
                     }
+                    _ => None,
+                };
+
+                let scored_doc = ScoredNamedFieldDocument {
+                    fields: named_doc,
+                    score,
+                    distance,
+                    group_size,
+                    sort,
+                    highlight,
+                };
+                docs.push(scored_doc);
+            }
+            doc_pos += 1;
+        }
+
+        // spelling suggestions
+        let mut suggestions = Vec::new();
+        if req.get_did_you_mean() && count < DID_YOU_MEAN_MAX_HITS {
+            // the fields that the terms of the query string were searched in, with their boosts
+            let spelling_fields: Vec<(Field, f32)> = if default_fields.is_empty() {
+                Self::text_fields(&schema)
+                    .into_iter()
+                    .map(|field| (field, 1.0))
+                    .collect()
+            } else {
+                default_fields
+            };
+            suggestions =
+                self.spelling_corrector
+                    .suggest(searcher, query.as_ref(), &spelling_fields);
+        }
+        if req.get_exclude_count() {
+            count = -1;
+        }
+
+        Ok(SearchResult {
+            docs,
+            count,
+            facet,
+            aggregations,
+            suggestions,
+            timed_out: budget.timed_out(),
+            terminated_early: budget.terminated_early(),
+        })
+    }
+
+    // Walks the term dictionaries instead of searching, so that completions stay cheap. Every term
+    // with the prefix is read, but only `limit` suggestions are kept in memory.
+    fn suggest_result(
+        &self,
+        req: &SuggestReq,
+        searcher: &Searcher,
+    ) -> Result<Vec<Value>, (RespErr, String)> {
+        let schema = self.index.schema();
+        let field = schema
+            .get_field(req.get_field())
+            .ok_or_else(|| Self::invalid_query(format!("unknown field: {}", req.get_field())))?;
+        // only indexed text fields have terms to complete
+        match schema.get_field_entry(field).field_type() {
+            FieldType::Str(options) if options.get_indexing_options().is_some() => {}
+            _ => {
+                return Err(Self::invalid_query(format!(
+                    "not an indexed text field: {}",
+                    req.get_field()
+                )))
+            }
+        }
+        let weight_field = if req.get_weight_field().is_empty() {
+            None
+        } else {
+            let weight_field_name = req.get_weight_field();
+            let weight_field = schema.get_field(weight_field_name).ok_or_else(|| {
+                Self::invalid_query(format!("unknown field: {}", weight_field_name))
+            })?;
+            match schema.get_field_entry(weight_field).field_type() {
+                FieldType::U64(options) if options.is_fast() => Some(weight_field),
+                _ => {
+                    return Err(Self::invalid_query(format!(
+                        "not a u64 fast field: {}",
+                        weight_field_name
+                    )))
                 }
             }
+        };
+
+        let limit = if req.get_limit() > 0 {
+            req.get_limit() as usize
+        } else {
+            10
+        };
+        let prefix = req.get_prefix().as_bytes();
+        let segment_readers = searcher.segment_readers();
+        let inverted_indexes: Vec<_> = segment_readers
+            .iter()
+            .map(|segment_reader| segment_reader.inverted_index(field))
+            .collect();
+        let mut weight_readers = Vec::with_capacity(segment_readers.len());
+        for segment_reader in segment_readers {
+            weight_readers.push(match weight_field {
+                Some(weight_field) => Some(
+                    segment_reader
+                        .fast_fields()
+                        .u64(weight_field)
+                        .ok_or_else(|| {
+                            Self::invalid_query(format!(
+                                "not a u64 fast field: {}",
+                                req.get_weight_field()
+                            ))
+                        })?,
+                ),
+                None => None,
+            });
+        }
+
+        // The terms of all segments are merged in order, so that each term is counted once over
+        // the whole index. Only the best suggestions so far are kept, the worst on top.
+        let mut term_merger = TermMerger::new(
+            inverted_indexes
+                .iter()
+                .map(|inverted_index| inverted_index.terms().range().ge(prefix).into_stream())
+                .collect(),
+        );
+        let mut suggestions: BinaryHeap<Reverse<(u64, u64, Reverse<String>)>> = BinaryHeap::new();
+        while term_merger.advance() {
+            let key = term_merger.key();
+            if !key.starts_with(prefix) {
+                break;
+            }
+            let mut doc_freq = 0;
+            let mut max_weight = None;
+            for heap_item in term_merger.current_kvs() {
+                let segment_ord = heap_item.segment_ord;
+                doc_freq += u64::from(heap_item.streamer.value().doc_freq);
+                if let Some(weight_reader) = &weight_readers[segment_ord] {
+                    let term = Term::from_field_bytes(field, key);
+                    if let Some(mut postings) =
+                        inverted_indexes[segment_ord].read_postings(&term, IndexRecordOption::Basic)
+                    {
+                        while postings.advance() {
+                            let doc = postings.doc();
+                            if !segment_readers[segment_ord].is_deleted(doc) {
+                                max_weight = max_weight.max(Some(weight_reader.get(doc)));
+                            }
+                        }
+                    }
+                }
+            }
+            // terms of deleted documents only are not suggested
+            if weight_field.is_some() && max_weight.is_none() {
+                continue;
+            }
+            let weight = max_weight.unwrap_or(0);
+            if suggestions.len() >= limit {
+                if let Some(Reverse((worst_weight, worst_doc_freq, _))) = suggestions.peek() {
+                    if (weight, doc_freq) < (*worst_weight, *worst_doc_freq) {
+                        continue;
+                    }
+                }
+            }
+            let text = match str::from_utf8(key) {
+                Ok(text) => text.to_string(),
+                Err(_) => continue,
+            };
+            // ranked by weight, then by doc_freq, then by term
+            suggestions.push(Reverse((weight, doc_freq, Reverse(text))));
+            if suggestions.len() > limit {
+                suggestions.pop();
+            }
         }
+
+        Ok(suggestions
+            .into_sorted_vec()
+            .into_iter()
+            .map(
+                |Reverse((weight, doc_freq, Reverse(term)))| match weight_field {
+                    Some(_) => json!({"term": term, "doc_freq": doc_freq, "weight": weight}),
+                    None => json!({"term": term, "doc_freq": doc_freq}),
+                },
+            )
+            .collect())
     }
 }
 
@@ -537,25 +1909,63 @@ impl IndexService for IndexServer {
     fn get(&mut self, ctx: RpcContext, req: GetReq, sink: UnarySink<GetResp>) {
         self.metrics.lock().unwrap().inc_request_count("get");
 
-        let t = Term::from_field_text(
-            self.index
-                .schema()
-                .get_field(&self.unique_key_field_name)
-                .unwrap(),
+        let searcher = self.index_reader.searcher();
+
+        let mut resp = GetResp::new();
+        match Self::get_document(
+            &self.index,
+            &self.unique_key_field_name,
+            &searcher,
             req.get_doc_id(),
-        );
-        let tq = TermQuery::new(t, IndexRecordOption::Basic);
-        let searcher = self.index.reader().unwrap().searcher();
-        let top_docs = searcher.search(&tq, &TopDocs::with_limit(10)).unwrap();
-        let mut doc = Document::default();
-        for (_score, doc_address) in top_docs {
-            doc = searcher.doc(doc_address).unwrap();
+        ) {
+            Ok(Some(doc)) => {
+                let named_doc = self.index.schema().to_named_doc(&doc);
+                resp.set_err(RespErr::OK);
+                resp.set_value(serde_json::to_string(&named_doc).unwrap());
+            }
+            Ok(None) => {
+                let message = format!("document not found: {}", req.get_doc_id());
+                resp.set_err(RespErr::ErrNoKey);
+                resp.set_value(Self::error_value(RespErr::ErrNoKey, &message));
+            }
+            Err(e) => {
+                let (err, message) = Self::internal_error(e);
+                resp.set_err(err);
+                resp.set_value(Self::error_value(err, &message));
+            }
         }
-        let named_doc = self.index.schema().to_named_doc(&doc);
+        ctx.spawn(
+            sink.success(resp)
+                .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e)),
+        )
+    }
 
-        let mut resp = GetResp::new();
-        resp.set_err(RespErr::OK);
-        resp.set_value(serde_json::to_string(&named_doc).unwrap());
+    fn multi_get(&mut self, ctx: RpcContext, req: MultiGetReq, sink: UnarySink<MultiGetResp>) {
+        self.metrics.lock().unwrap().inc_request_count("multi_get");
+
+        let schema = self.index.schema();
+        let searcher = self.index_reader.searcher();
+        let named_docs: tantivy::Result<Vec<_>> = req
+            .get_doc_ids()
+            .iter()
+            .map(|doc_id| {
+                Self::get_document(&self.index, &self.unique_key_field_name, &searcher, doc_id)
+                    .map(|doc| doc.map(|doc| schema.to_named_doc(&doc)))
+            })
+            .collect();
+
+        let mut resp = MultiGetResp::new();
+        match named_docs {
+            Ok(named_docs) => {
+                resp.set_err(RespErr::OK);
+                resp.set_value(serde_json::to_string(&named_docs).unwrap());
+            }
+            Err(e) => {
+                let (err, message) = Self::internal_error(e);
+                resp.set_err(err);
+                resp.set_value(Self::error_value(err, &message));
+            }
+        }
         ctx.spawn(
             sink.success(resp)
                 .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e)),
@@ -563,7 +1973,10 @@ impl IndexService for IndexServer {
     }
 
     fn put(&mut self, ctx: RpcContext, req: ApplyReq, sink: UnarySink<PutResp>) {
-        let (err, ret) = Self::start_op(self, &req);
+        let (err, ret) = match Self::validate(&self.index, &req) {
+            Ok(()) => Self::start_op(self, &req),
+            Err((err, message)) => (err, Self::error_value(err, &message)),
+        };
         let mut resp = PutResp::new();
         resp.set_err(err);
         resp.set_value(ret);
@@ -573,6 +1986,20 @@ impl IndexService for IndexServer {
         )
     }
 
+    fn update(&mut self, ctx: RpcContext, req: ApplyReq, sink: UnarySink<UpdateResp>) {
+        let (err, ret) = match Self::validate(&self.index, &req) {
+            Ok(()) => Self::start_op(self, &req),
+            Err((err, message)) => (err, Self::error_value(err, &message)),
+        };
+        let mut resp = UpdateResp::new();
+        resp.set_err(err);
+        resp.set_value(ret);
+        ctx.spawn(
+            sink.success(resp)
+                .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e)),
+        )
+    }
+
     fn delete(&mut self, ctx: RpcContext, req: ApplyReq, sink: UnarySink<DeleteResp>) {
         let (err, ret) = Self::start_op(self, &req);
         let mut resp = DeleteResp::new();
@@ -584,6 +2011,25 @@ impl IndexService for IndexServer {
         )
     }
 
+    fn delete_by_query(
+        &mut self,
+        ctx: RpcContext,
+        req: ApplyReq,
+        sink: UnarySink<DeleteByQueryResp>,
+    ) {
+        let (err, ret) = match Self::validate(&self.index, &req) {
+            Ok(()) => Self::start_op(self, &req),
+            Err((err, message)) => (err, Self::error_value(err, &message)),
+        };
+        let mut resp = DeleteByQueryResp::new();
+        resp.set_err(err);
+        resp.set_value(ret);
+        ctx.spawn(
+            sink.success(resp)
+                .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e)),
+        )
+    }
+
     fn commit(&mut self, ctx: RpcContext, req: ApplyReq, sink: UnarySink<CommitResp>) {
         let (err, ret) = Self::start_op(self, &req);
         let mut resp = CommitResp::new();
@@ -620,149 +2066,83 @@ impl IndexService for IndexServer {
     fn search(&mut self, ctx: RpcContext, req: SearchReq, sink: UnarySink<SearchResp>) {
         self.metrics.lock().unwrap().inc_request_count("search");
 
-        let schema = self.index.schema();
-        let default_fields: Vec<Field> = schema
-            .fields()
-            .flat_map(|(field, field_entry)| {
-                if let FieldType::Str(text_field_options) = field_entry.field_type() {
-                    if text_field_options.get_indexing_options().is_some() {
-                        return Some(field);
-                    }
-                }
-                None
-            })
-            .collect();
+        let searcher = self.index_reader.searcher();
 
-        let limit = req.get_from() + req.get_limit();
-
-        let query_parser = QueryParser::for_index(&self.index, default_fields);
-        let query = query_parser.parse_query(req.query.as_str()).unwrap();
-        let searcher = self.index.reader().unwrap().searcher();
-        let mut multi_collector = MultiCollector::new();
-        let count_handle = if req.get_exclude_count() {
-            None
+        let generation = searcher_generation(&searcher);
+        let cache_key = search_cache_key(&req);
+        let search_cache = if is_cacheable(&req) {
+            self.search_cache.as_ref()
         } else {
-            Some(multi_collector.add_collector(Count))
-        };
-        let top_docs_handle = if req.get_exclude_docs() {
             None
-        } else {
-            Some(multi_collector.add_collector(TopDocs::with_limit(limit as usize)))
         };
-        let facet_handle = if req.get_facet_field().is_empty() {
-            None
-        } else {
-            let mut facet_collector = FacetCollector::for_field(schema.get_field(req.get_facet_field()).unwrap());
-            for facet_prefix in req.get_facet_prefixes() {
-                facet_collector.add_facet(facet_prefix);
+        if let Some(search_cache) = search_cache {
+            let cached = search_cache.lock().unwrap().get(generation, &cache_key);
+            match cached {
+                Some(value) => {
+                    self.metrics.lock().unwrap().inc_search_cache_hit_count();
+                    let mut resp = SearchResp::new();
+                    resp.set_err(RespErr::OK);
+                    resp.set_value(value);
+                    ctx.spawn(
+                        sink.success(resp)
+                            .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e)),
+                    );
+                    return;
+                }
+                None => self.metrics.lock().unwrap().inc_search_cache_miss_count(),
             }
-            Some(multi_collector.add_collector(facet_collector))
-        };
-
-//        // single field facet
-//        let mut facet_collector = FacetCollector::for_field(schema.get_field(req.get_facet_field()).unwrap());
-//        for facet_prefix in req.get_facet_prefixes() {
-//            facet_collector.add_facet(facet_prefix);
-//        }
-//        let facet_handle = multi_collector.add_collector(facet_collector);
-
-//        // multi field facet
-//        let mut facet_data: HashMap<&str, Vec<String>> = HashMap::new();
-//        for f in req.get_facets() {
-//            let mut parts = f.split(':');
-//            let field_name = parts.next().unwrap();
-//            let field_value = parts.next().unwrap();
-//            let mut field_values: Vec<String> = Vec::new();
-//            if facet_data.contains_key(field_name) {
-//                field_values = facet_data.get(field_name).unwrap().to_vec();
-//            }
-//            field_values.push(field_value.to_string());
-//            facet_data.insert(field_name, field_values);
-//        }
-//        debug!("facet_data: {:?}", facet_data);
-//        let mut facet_handles = HashMap::new();
-//        for field_name in facet_data.keys() {
-//            let field = schema.get_field(*field_name).unwrap();
-//            let mut facet_collector = FacetCollector::for_field(field);
-//            for field_value in facet_data.get(*field_name).unwrap() {
-//                facet_collector.add_facet(field_value);
-//            }
-//            let facet_handle = multi_collector.add_collector(facet_collector);
-//            facet_handles.insert(*field_name, facet_handle);
-//        }
-
-        // search index
-        let mut multi_fruit = searcher.search(&query, &multi_collector).unwrap();
-
-        // count
-        let mut count: i64 = -1;
-        if let Some(ch) = count_handle {
-            count = ch.extract(&mut multi_fruit) as i64;
         }
 
-        // docs
-        let mut top_docs = Vec::new();
-        if let Some(tdh) = top_docs_handle {
-            top_docs = tdh.extract(&mut multi_fruit);
+        match self.search_result(&req, &searcher) {
+            Ok(sr) => {
+                let value = serde_json::to_string(&sr).unwrap();
+
+                // Partial results must not be served to later searches that could complete.
+                if !sr.timed_out {
+                    if let Some(search_cache) = search_cache {
+                        search_cache
+                            .lock()
+                            .unwrap()
+                            .put(generation, cache_key, value.clone());
+                    }
+                }
+
+                let mut resp = SearchResp::new();
+                resp.set_err(RespErr::OK);
+                resp.set_value(value);
+                ctx.spawn(
+                    sink.success(resp)
+                        .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e)),
+                )
+            }
+            Err((err, message)) => {
+                let mut resp = SearchResp::new();
+                resp.set_err(err);
+                resp.set_value(Self::error_value(err, &message));
+                ctx.spawn(
+                    sink.success(resp)
+                        .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e)),
+                )
+            }
         }
+    }
 
-        // facet
-        let mut facet: HashMap<String, HashMap<String, u64>> = HashMap::new();
-        if let Some(fh) = facet_handle {
-            let facet_counts = fh.extract(&mut multi_fruit);
-            let mut facet_kv: HashMap<String, u64> = HashMap::new();
-            for facet_prefix in req.get_facet_prefixes() {
-                for (facet_key, facet_value) in facet_counts.get(facet_prefix) {
-                    debug!("{:?}={}", facet_key.to_string(), facet_value);
-                    facet_kv.insert(facet_key.to_string(), facet_value);
-                }
-            }
-            facet.insert(req.get_facet_field().to_string(), facet_kv);
-        }
-
-//        // single field facet
-//        let facet_counts = facet_handle.extract(&mut multi_fruit);
-//        for facet_prefix in req.get_facet_prefixes() {
-//            for (facet_value, facet_count) in facet_counts.get(facet_prefix) {
-//                debug!("{:?}={}", facet_value.to_string(), facet_count);
-//            }
-//        }
-
-//        // multi field facet
-//        for field_name in facet_handles.keys() {
-//            let facet_counts = facet_handles.get(*field_name).unwrap().extract(&mut multi_fruit);
-//            for facet_value in facet_data.get(*field_name).unwrap().to_vec() {
-//                debug!("facet_value: {}", facet_value);
-//                let facet_count = facet_counts.get(&facet_value);
-//            }
-//        }
+    fn suggest(&mut self, ctx: RpcContext, req: SuggestReq, sink: UnarySink<SuggestResp>) {
+        self.metrics.lock().unwrap().inc_request_count("suggest");
 
-        let mut docs: Vec<ScoredNamedFieldDocument> = Vec::new();
-        let mut doc_pos: u64 = 0;
-        for (score, doc_address) in top_docs {
-            if doc_pos >= req.get_from() {
-                let doc = searcher.doc(doc_address).unwrap();
-                let named_doc = schema.to_named_doc(&doc);
-                debug!(
-                    "score: {:?} doc: {:?}",
-                    score,
-                    serde_json::to_string(&named_doc).unwrap()
-                );
+        let searcher = self.index_reader.searcher();
 
-                let scored_doc = ScoredNamedFieldDocument {
-                    fields: named_doc,
-                    score,
-                };
-                docs.push(scored_doc);
+        let mut resp = SuggestResp::new();
+        match self.suggest_result(&req, &searcher) {
+            Ok(suggestions) => {
+                resp.set_err(RespErr::OK);
+                resp.set_value(serde_json::to_string(&suggestions).unwrap());
+            }
+            Err((err, message)) => {
+                resp.set_err(err);
+                resp.set_value(Self::error_value(err, &message));
             }
-            doc_pos += 1;
         }
-
-        let sr = SearchResult { docs, count, facet };
-
-        let mut resp = SearchResp::new();
-        resp.set_err(RespErr::OK);
-        resp.set_value(serde_json::to_string(&sr).unwrap());
         ctx.spawn(
             sink.success(resp)
                 .map_err(move |e| error!("failed to reply {:?}: {:?}", req, e)),
@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use std::str;
+
+use levenshtein_automata::LevenshteinAutomatonBuilder;
+use tantivy::query::Query;
+use tantivy::schema::Field;
+use tantivy::{Searcher, Term};
+
+use crate::util::search_result::SpellingSuggestion;
+
+const MAX_EDIT_DISTANCE: u8 = 2;
+const MAX_CANDIDATES: usize = 5;
+
+// Building the tables of the Levenshtein automata is expensive, so they are built once and shared
+// by the searches.
+pub struct SpellingCorrector {
+    automaton_builder: LevenshteinAutomatonBuilder,
+}
+
+impl Default for SpellingCorrector {
+    fn default() -> SpellingCorrector {
+        SpellingCorrector::new()
+    }
+}
+
+impl SpellingCorrector {
+    pub fn new() -> SpellingCorrector {
+        SpellingCorrector {
+            automaton_builder: LevenshteinAutomatonBuilder::new(MAX_EDIT_DISTANCE, true),
+        }
+    }
+
+    // Suggests terms within a small edit distance of the query terms that occur in more documents
+    // than the query terms themselves. Only the terms of the given fields are corrected, and the
+    // document frequencies are multiplied by the boosts of the fields.
+    pub fn suggest(
+        &self,
+        searcher: &Searcher,
+        query: &dyn Query,
+        fields: &[(Field, f32)],
+    ) -> Vec<SpellingSuggestion> {
+        let mut query_terms = BTreeSet::new();
+        query.query_terms(&mut query_terms);
+
+        let mut words: Vec<(String, f64)> = Vec::new();
+        for term in query_terms.iter() {
+            if !fields.iter().any(|(field, _boost)| *field == term.field()) {
+                continue;
+            }
+            let text = term.text().to_string();
+            if words.iter().any(|(word, _)| *word == text) {
+                continue;
+            }
+            let doc_freq: f64 = fields
+                .iter()
+                .map(|(field, boost)| {
+                    searcher.doc_freq(&Term::from_field_text(*field, &text)) as f64
+                        * f64::from(*boost)
+                })
+                .sum();
+            words.push((text, doc_freq));
+        }
+
+        // Only the terms accepted by the automaton of each word are read from the term
+        // dictionaries.
+        let mut candidates: Vec<HashMap<String, f64>> = vec![HashMap::new(); words.len()];
+        for (i, (word, _)) in words.iter().enumerate() {
+            for segment_reader in searcher.segment_readers() {
+                for (field, boost) in fields {
+                    let inverted_index = segment_reader.inverted_index(*field);
+                    let mut term_stream = inverted_index
+                        .terms()
+                        .search(self.automaton_builder.build_dfa(word))
+                        .into_stream();
+                    while term_stream.advance() {
+                        let text = match str::from_utf8(term_stream.key()) {
+                            Ok(text) => text,
+                            Err(_) => continue,
+                        };
+                        if text == word.as_str() {
+                            continue;
+                        }
+                        *candidates[i].entry(text.to_string()).or_insert(0.0) +=
+                            f64::from(term_stream.value().doc_freq) * f64::from(*boost);
+                    }
+                }
+            }
+        }
+
+        words
+            .into_iter()
+            .zip(candidates.into_iter())
+            .flat_map(|((word, doc_freq), candidates)| {
+                let mut candidates: Vec<(String, f64)> = candidates
+                    .into_iter()
+                    .filter(|(_, candidate_doc_freq)| *candidate_doc_freq > doc_freq)
+                    .collect();
+                if candidates.is_empty() {
+                    return None;
+                }
+                candidates.sort_by(|a, b| {
+                    b.1.partial_cmp(&a.1)
+                        .unwrap_or(Ordering::Equal)
+                        .then_with(|| a.0.cmp(&b.0))
+                });
+                candidates.truncate(MAX_CANDIDATES);
+                Some(SpellingSuggestion {
+                    term: word,
+                    candidates: candidates.into_iter().map(|(text, _)| text).collect(),
+                })
+            })
+            .collect()
+    }
+}
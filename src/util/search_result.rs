@@ -1,17 +1,54 @@
 use std::collections::HashMap;
 
-use serde::Serialize;
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
 use tantivy::schema::NamedFieldDocument;
 
 #[derive(Serialize)]
 pub struct ScoredNamedFieldDocument {
     pub fields: NamedFieldDocument,
     pub score: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_size: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sort: Vec<Value>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub highlight: HashMap<String, Vec<String>>,
+}
+
+#[derive(Serialize)]
+pub struct SpellingSuggestion {
+    pub term: String,
+    pub candidates: Vec<String>,
+}
+
+// Counts keyed by value, serialized as a JSON object that keeps the order of the entries.
+pub struct OrderedCounts(pub Vec<(String, u64)>);
+
+impl Serialize for OrderedCounts {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (key, count) in &self.0 {
+            map.serialize_entry(key, count)?;
+        }
+        map.end()
+    }
 }
 
 #[derive(Serialize)]
 pub struct SearchResult {
     pub count: i64,
     pub docs: Vec<ScoredNamedFieldDocument>,
-    pub facet: HashMap<String, HashMap<String, u64>>,
+    // The counts of the deprecated facet parameters, derived from facet aggregations. Use the
+    // facet aggregation in `aggregations` instead.
+    pub facet: HashMap<String, OrderedCounts>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub aggregations: HashMap<String, Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<SpellingSuggestion>,
+    pub timed_out: bool,
+    pub terminated_early: bool,
 }